@@ -0,0 +1,152 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::CostModel;
+use crate::analysis::equity_curve::EquityCurve;
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use crate::position::Position;
+use std::sync::Arc;
+
+/// CashFlow：逐 bar 资金曲线指标（对应 ta4j CashFlow）
+///
+/// - 起始值为 1，持仓期间按收盘价计值，未平仓持仓按最后一根 bar 计值
+/// - 已计入交易成本（成交净价）与持仓成本（`get_holding_cost_with_final` 按 bar 平摊）
+/// - 构造时基于交易记录快照一次性计算，之后记录的变化不会反映到指标中
+pub struct CashFlow<T, S>
+where
+    T: TrNum + 'static,
+    S: BarSeries<T> + 'static,
+{
+    series: BarSeriesRef<S>,
+    curve: Arc<EquityCurve<T>>,
+}
+
+impl<T, S> Clone for CashFlow<T, S>
+where
+    T: TrNum + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            series: self.series.clone(),
+            curve: Arc::clone(&self.curve),
+        }
+    }
+}
+
+impl<T, S> CashFlow<T, S>
+where
+    T: TrNum + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 由交易记录构建，计算到序列最后一根 bar
+    pub fn new<CM, HM>(
+        series_ref: BarSeriesRef<S>,
+        record: &dyn TradingRecord<T, CM, HM, S>,
+    ) -> Result<Self, IndicatorError>
+    where
+        CM: CostModel<T> + Clone,
+        HM: CostModel<T> + Clone,
+    {
+        let curve = series_ref.with_ref(|s| EquityCurve::new(s, record))?;
+        Ok(Self::from_curve(series_ref, curve))
+    }
+
+    /// 由交易记录构建，计算到 final_index（包含）
+    pub fn with_final_index<CM, HM>(
+        series_ref: BarSeriesRef<S>,
+        record: &dyn TradingRecord<T, CM, HM, S>,
+        final_index: usize,
+    ) -> Result<Self, IndicatorError>
+    where
+        CM: CostModel<T> + Clone,
+        HM: CostModel<T> + Clone,
+    {
+        let curve =
+            series_ref.with_ref(|s| EquityCurve::with_final_index(s, record, final_index))?;
+        Ok(Self::from_curve(series_ref, curve))
+    }
+
+    /// 由单个持仓构建
+    pub fn from_position<CM, HM>(
+        series_ref: BarSeriesRef<S>,
+        position: &Position<T, CM, HM, S>,
+    ) -> Result<Self, IndicatorError>
+    where
+        CM: CostModel<T> + Clone,
+        HM: CostModel<T> + Clone,
+    {
+        let curve = series_ref.with_ref(|s| EquityCurve::from_position(s, position))?;
+        Ok(Self::from_curve(series_ref, curve))
+    }
+
+    /// 由已计算的资金曲线构建
+    pub fn from_curve(series_ref: BarSeriesRef<S>, curve: EquityCurve<T>) -> Self {
+        Self {
+            series: series_ref,
+            curve: Arc::new(curve),
+        }
+    }
+
+    /// 底层资金曲线
+    pub fn curve(&self) -> &EquityCurve<T> {
+        &self.curve
+    }
+
+    /// 资金曲线的值个数
+    pub fn size(&self) -> usize {
+        self.curve.values().len()
+    }
+}
+
+impl<T, S> Indicator for CashFlow<T, S>
+where
+    T: TrNum + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<Self::Output, IndicatorError> {
+        self.curve
+            .value(index)
+            .cloned()
+            .ok_or(IndicatorError::OutOfBounds { index })
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.series.clone()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        0
+    }
+}
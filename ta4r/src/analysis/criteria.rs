@@ -0,0 +1,126 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+pub mod average_profit_criterion;
+pub mod calmar_ratio_criterion;
+pub mod expectancy_criterion;
+pub mod expected_shortfall_criterion;
+pub mod gross_return_criterion;
+pub mod maximum_drawdown_criterion;
+pub mod maximum_drawdown_duration_criterion;
+pub mod number_of_positions_criterion;
+pub mod positions_ratio_criterion;
+pub mod profit_factor_criterion;
+pub mod profit_loss_criterion;
+pub mod return_over_max_drawdown_criterion;
+pub mod sharpe_ratio_criterion;
+pub mod sortino_ratio_criterion;
+pub mod ulcer_index_criterion;
+pub mod value_at_risk_criterion;
+
+use crate::analysis::CostModel;
+use crate::bar::types::BarSeries;
+use crate::num::TrNum;
+use crate::position::Position;
+
+/// 持仓过滤器：按盈利或亏损筛选已关闭持仓
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionFilter {
+    /// 盈利持仓
+    Profit,
+    /// 亏损持仓
+    Loss,
+}
+
+impl PositionFilter {
+    /// 持仓是否满足过滤条件（未关闭的持仓始终不满足）
+    pub fn accepts<T, CM, HM, S>(&self, position: &Position<T, CM, HM, S>) -> bool
+    where
+        T: TrNum + 'static,
+        CM: CostModel<T> + Clone,
+        HM: CostModel<T> + Clone,
+        S: BarSeries<T>,
+    {
+        match self {
+            PositionFilter::Profit => position.has_profit(),
+            PositionFilter::Loss => position.has_loss(),
+        }
+    }
+}
+
+/// 统计满足过滤条件的持仓：返回 (利润总和, 持仓数量)
+pub(crate) fn filtered_profit<'a, T, CM, HM, S, P>(
+    positions: P,
+    filter: PositionFilter,
+) -> (T, usize)
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone + 'a,
+    HM: CostModel<T> + Clone + 'a,
+    S: BarSeries<T> + 'a,
+    P: IntoIterator<Item = &'a Position<T, CM, HM, S>>,
+{
+    positions
+        .into_iter()
+        .filter(|p| filter.accepts(*p))
+        .fold((T::zero(), 0), |(sum, count), p| {
+            (sum.plus(&p.get_profit()), count + 1)
+        })
+}
+
+/// 算术平均值，空序列返回 0
+pub(crate) fn mean<T: TrNum>(values: &[T]) -> T {
+    if values.is_empty() {
+        return T::zero();
+    }
+    let sum = values.iter().fold(T::zero(), |sum, v| sum.plus(v));
+    T::from_usize(values.len())
+        .and_then(|n| sum.divided_by(&n).ok())
+        .unwrap_or_else(T::zero)
+}
+
+/// 样本标准差（除以 n - 1），少于两个值时返回 0
+pub(crate) fn sample_standard_deviation<T: TrNum>(values: &[T]) -> T {
+    if values.len() < 2 {
+        return T::zero();
+    }
+    let mean = mean(values);
+    let sum_of_squares = values.iter().fold(T::zero(), |sum, v| {
+        let deviation = v.minus(&mean);
+        sum.plus(&deviation.multiplied_by(&deviation))
+    });
+    T::from_usize(values.len() - 1)
+        .and_then(|n| sum_of_squares.divided_by(&n).ok())
+        .and_then(|variance| variance.sqrt().ok())
+        .unwrap_or_else(T::zero)
+}
+
+/// 按 sqrt(bars_per_year) 年化比率（如 Sharpe / Sortino）
+pub(crate) fn annualize<T: TrNum>(ratio: T, bars_per_year: Option<f64>) -> T {
+    match bars_per_year.and_then(|bars| T::from_f64(bars.sqrt())) {
+        Some(factor) => ratio.multiplied_by(&factor),
+        None => ratio,
+    }
+}
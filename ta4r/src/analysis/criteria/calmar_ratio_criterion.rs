@@ -0,0 +1,108 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::criteria::maximum_drawdown_criterion::max_drawdown;
+use crate::analysis::equity_curve::EquityCurve;
+use crate::analysis::{AnalysisCriterion, CostModel};
+use crate::bar::types::{Bar, BarSeries};
+use crate::num::{NumFactory, TrNum};
+use crate::position::Position;
+
+/// 一年的秒数（365.25 天）
+const SECONDS_PER_YEAR: f64 = 365.25 * 24.0 * 60.0 * 60.0;
+
+/// 卡玛比率：年化复合收益率 / 最大回撤，越大越好
+///
+/// - 年化周期由资金曲线首尾 bar 的时间跨度推算
+/// - 没有回撤时直接返回年化收益率
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CalmarRatioCriterion;
+
+impl CalmarRatioCriterion {
+    /// 计算资金曲线的卡玛比率
+    pub fn calculate_curve<T, S>(series: &S, curve: &EquityCurve<T>) -> T
+    where
+        T: TrNum + 'static,
+        S: BarSeries<T> + 'static,
+    {
+        let annual_return = Self::annualized_return(series, curve);
+        let max_drawdown = max_drawdown(curve.values());
+        if max_drawdown.is_zero() {
+            return annual_return;
+        }
+        annual_return
+            .divided_by(&max_drawdown)
+            .unwrap_or_else(|_| T::zero())
+    }
+
+    /// 年化复合收益率：total_return^(1 / years) - 1
+    fn annualized_return<T, S>(series: &S, curve: &EquityCurve<T>) -> T
+    where
+        T: TrNum + 'static,
+        S: BarSeries<T> + 'static,
+    {
+        let (Some(first), Some(last)) = (
+            series.get_bar(curve.begin_index()),
+            series.get_bar(curve.end_index()),
+        ) else {
+            return T::zero();
+        };
+        let seconds = (last.get_end_time() - first.get_begin_time()).as_seconds_f64();
+        if seconds <= 0.0 {
+            return T::zero();
+        }
+
+        let total_return = curve.total_return();
+        if !total_return.is_positive() {
+            return T::one().neg();
+        }
+        let exponent = series.num_factory().num_of_f64(SECONDS_PER_YEAR / seconds);
+        total_return
+            .pow_num(&exponent)
+            .map(|growth| growth - T::one())
+            .unwrap_or_else(|_| T::zero())
+    }
+}
+
+impl<T, CM, HM, S> AnalysisCriterion<T, CM, HM, S> for CalmarRatioCriterion
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    fn calculate_position(&self, series: &S, position: &Position<T, CM, HM, S>) -> T {
+        Self::calculate_curve(series, &EquityCurve::from_position(series, position))
+    }
+
+    fn calculate_record(&self, series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> T {
+        Self::calculate_curve(series, &EquityCurve::new(series, record))
+    }
+
+    fn better_than(&self, criterion_value1: &T, criterion_value2: &T) -> bool {
+        criterion_value1.is_greater_than(criterion_value2)
+    }
+}
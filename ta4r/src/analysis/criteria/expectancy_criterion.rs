@@ -0,0 +1,101 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::criteria::profit_factor_criterion::ratio_or_default;
+use crate::analysis::criteria::{PositionFilter, filtered_profit};
+use crate::analysis::{AnalysisCriterion, CostModel};
+use crate::bar::types::BarSeries;
+use crate::num::{NumFactory, TrNum};
+use crate::position::Position;
+
+/// 期望值（对应 ta4j ExpectancyCriterion）
+///
+/// `(1 + 平均盈利 / 平均亏损) * 胜率 - 1`，没有已关闭持仓时返回 0
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExpectancyCriterion;
+
+impl ExpectancyCriterion {
+    fn expectancy<'a, T, CM, HM, S, P>(series: &S, positions: P) -> T
+    where
+        T: TrNum + 'static,
+        CM: CostModel<T> + Clone + 'a,
+        HM: CostModel<T> + Clone + 'a,
+        S: BarSeries<T> + 'static,
+        P: IntoIterator<Item = &'a Position<T, CM, HM, S>> + Clone,
+    {
+        let total = positions
+            .clone()
+            .into_iter()
+            .filter(|p| p.is_closed())
+            .count();
+        if total == 0 {
+            return T::zero();
+        }
+
+        let factory = series.num_factory();
+        let average = |(sum, count): (T, usize)| {
+            if count == 0 {
+                T::zero()
+            } else {
+                sum.divided_by(&factory.num_of_usize(count))
+                    .unwrap_or_else(|_| T::zero())
+            }
+        };
+
+        let winning = filtered_profit(positions.clone(), PositionFilter::Profit);
+        let winning_count = winning.1;
+        let average_profit = average(winning);
+        let average_loss = average(filtered_profit(positions, PositionFilter::Loss));
+        let profit_loss_ratio = ratio_or_default(&average_profit, &average_loss);
+
+        let win_rate = factory
+            .num_of_usize(winning_count)
+            .divided_by(&factory.num_of_usize(total))
+            .unwrap_or_else(|_| T::zero());
+
+        T::one().plus(&profit_loss_ratio).multiplied_by(&win_rate) - T::one()
+    }
+}
+
+impl<T, CM, HM, S> AnalysisCriterion<T, CM, HM, S> for ExpectancyCriterion
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    fn calculate_position(&self, series: &S, position: &Position<T, CM, HM, S>) -> T {
+        Self::expectancy(series, std::iter::once(position))
+    }
+
+    fn calculate_record(&self, series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> T {
+        Self::expectancy(series, record.positions().iter().map(|p| p.as_ref()))
+    }
+
+    fn better_than(&self, criterion_value1: &T, criterion_value2: &T) -> bool {
+        criterion_value1.is_greater_than(criterion_value2)
+    }
+}
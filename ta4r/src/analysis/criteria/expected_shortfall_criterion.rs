@@ -0,0 +1,92 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::criteria::mean;
+use crate::analysis::criteria::value_at_risk_criterion::{
+    check_confidence, sorted_returns, tail_index,
+};
+use crate::analysis::equity_curve::EquityCurve;
+use crate::analysis::{AnalysisCriterion, CostModel};
+use crate::bar::types::BarSeries;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use crate::position::Position;
+
+/// 期望损失（Expected Shortfall / CVaR）：不高于 VaR 的逐 bar 对数收益率的平均值
+///
+/// 结果为非正数，越大（越接近 0）越好
+#[derive(Debug, Clone, Copy)]
+pub struct ExpectedShortfallCriterion {
+    confidence: f64,
+}
+
+impl Default for ExpectedShortfallCriterion {
+    fn default() -> Self {
+        Self { confidence: 0.95 }
+    }
+}
+
+impl ExpectedShortfallCriterion {
+    /// confidence 取值 (0, 1)，如 0.95
+    pub fn new(confidence: f64) -> Result<Self, IndicatorError> {
+        check_confidence(confidence)?;
+        Ok(Self { confidence })
+    }
+
+    pub fn confidence(&self) -> f64 {
+        self.confidence
+    }
+
+    /// 计算资金曲线的期望损失
+    pub fn calculate_curve<T: TrNum + 'static>(&self, curve: &EquityCurve<T>) -> T {
+        let returns = sorted_returns(curve);
+        if returns.is_empty() {
+            return T::zero();
+        }
+        let tail = &returns[..=tail_index(returns.len(), self.confidence)];
+        mean(tail).min(&T::zero())
+    }
+}
+
+impl<T, CM, HM, S> AnalysisCriterion<T, CM, HM, S> for ExpectedShortfallCriterion
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    fn calculate_position(&self, series: &S, position: &Position<T, CM, HM, S>) -> T {
+        self.calculate_curve(&EquityCurve::from_position(series, position))
+    }
+
+    fn calculate_record(&self, series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> T {
+        self.calculate_curve(&EquityCurve::new(series, record))
+    }
+
+    fn better_than(&self, criterion_value1: &T, criterion_value2: &T) -> bool {
+        criterion_value1.is_greater_than(criterion_value2)
+    }
+}
@@ -0,0 +1,67 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::{AnalysisCriterion, CostModel};
+use crate::bar::types::BarSeries;
+use crate::num::TrNum;
+use crate::position::Position;
+
+/// 毛收益率（不含成本），以比率表示：1.1 表示盈利 10%
+///
+/// - 单个持仓：出场价 / 入场价（做空取倒数），未关闭的持仓计为 1
+/// - 交易记录：所有持仓毛收益率的乘积
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GrossReturnCriterion;
+
+impl<T, CM, HM, S> AnalysisCriterion<T, CM, HM, S> for GrossReturnCriterion
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    fn calculate_position(&self, _series: &S, position: &Position<T, CM, HM, S>) -> T {
+        match (position.entry(), position.exit()) {
+            (Some(entry), Some(exit)) => position
+                .get_gross_return(
+                    entry.get_price_per_asset().clone(),
+                    exit.get_price_per_asset().clone(),
+                )
+                .unwrap_or_else(|_| T::one()),
+            _ => T::one(),
+        }
+    }
+
+    fn calculate_record(&self, series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> T {
+        record.positions().iter().fold(T::one(), |product, p| {
+            product.multiplied_by(&self.calculate_position(series, p))
+        })
+    }
+
+    fn better_than(&self, criterion_value1: &T, criterion_value2: &T) -> bool {
+        criterion_value1.is_greater_than(criterion_value2)
+    }
+}
@@ -0,0 +1,80 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::equity_curve::EquityCurve;
+use crate::analysis::{AnalysisCriterion, CostModel};
+use crate::bar::types::BarSeries;
+use crate::num::TrNum;
+use crate::position::Position;
+
+/// 最大回撤持续时间：资金曲线从峰值到重新创出新高所经历的最长 bar 数，越小越好
+///
+/// 回撤尚未恢复时计算到资金曲线的最后一根 bar
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MaximumDrawdownDurationCriterion;
+
+impl MaximumDrawdownDurationCriterion {
+    /// 计算资金曲线的最大回撤持续 bar 数
+    pub fn calculate_curve<T: TrNum + 'static>(curve: &EquityCurve<T>) -> usize {
+        let values = curve.values();
+        let Some(mut peak) = values.first().cloned() else {
+            return 0;
+        };
+        let mut peak_index = 0;
+        let mut longest = 0;
+        for (index, value) in values.iter().enumerate() {
+            if value.is_greater_than_or_equal(&peak) {
+                peak = value.clone();
+                peak_index = index;
+            } else {
+                longest = longest.max(index - peak_index);
+            }
+        }
+        longest
+    }
+}
+
+impl<T, CM, HM, S> AnalysisCriterion<T, CM, HM, S> for MaximumDrawdownDurationCriterion
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    fn calculate_position(&self, series: &S, position: &Position<T, CM, HM, S>) -> T {
+        let duration = Self::calculate_curve(&EquityCurve::from_position(series, position));
+        T::from_usize(duration).unwrap_or_else(T::zero)
+    }
+
+    fn calculate_record(&self, series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> T {
+        let duration = Self::calculate_curve(&EquityCurve::new(series, record));
+        T::from_usize(duration).unwrap_or_else(T::zero)
+    }
+
+    fn better_than(&self, criterion_value1: &T, criterion_value2: &T) -> bool {
+        criterion_value1.is_less_than(criterion_value2)
+    }
+}
@@ -0,0 +1,101 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::criteria::PositionFilter;
+use crate::analysis::{AnalysisCriterion, CostModel};
+use crate::bar::types::BarSeries;
+use crate::num::{NumFactory, TrNum};
+use crate::position::Position;
+
+/// 盈利（或亏损）持仓占全部已关闭持仓的比例
+///
+/// - `PositionFilter::Profit`：胜率，越高越好
+/// - `PositionFilter::Loss`：败率，越低越好
+#[derive(Debug, Clone, Copy)]
+pub struct PositionsRatioCriterion {
+    filter: PositionFilter,
+}
+
+impl PositionsRatioCriterion {
+    pub fn new(filter: PositionFilter) -> Self {
+        Self { filter }
+    }
+
+    /// 盈利持仓比例（胜率）
+    pub fn winning() -> Self {
+        Self::new(PositionFilter::Profit)
+    }
+
+    /// 亏损持仓比例
+    pub fn losing() -> Self {
+        Self::new(PositionFilter::Loss)
+    }
+
+    pub fn filter(&self) -> PositionFilter {
+        self.filter
+    }
+}
+
+impl<T, CM, HM, S> AnalysisCriterion<T, CM, HM, S> for PositionsRatioCriterion
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    fn calculate_position(&self, _series: &S, position: &Position<T, CM, HM, S>) -> T {
+        if self.filter.accepts(position) {
+            T::one()
+        } else {
+            T::zero()
+        }
+    }
+
+    fn calculate_record(&self, series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> T {
+        let total = record.position_count();
+        if total == 0 {
+            return T::zero();
+        }
+        let matched = record
+            .positions()
+            .iter()
+            .filter(|p| self.filter.accepts(p.as_ref()))
+            .count();
+
+        let factory = series.num_factory();
+        factory
+            .num_of_usize(matched)
+            .divided_by(&factory.num_of_usize(total))
+            .unwrap_or_else(|_| T::zero())
+    }
+
+    fn better_than(&self, criterion_value1: &T, criterion_value2: &T) -> bool {
+        match self.filter {
+            PositionFilter::Profit => criterion_value1.is_greater_than(criterion_value2),
+            PositionFilter::Loss => criterion_value1.is_less_than(criterion_value2),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::criteria::{PositionFilter, filtered_profit};
+use crate::analysis::{AnalysisCriterion, CostModel};
+use crate::bar::types::BarSeries;
+use crate::num::TrNum;
+use crate::position::Position;
+
+/// 盈利因子：盈利持仓利润总和 / 亏损持仓亏损总和（绝对值）
+///
+/// - 没有盈利时返回 0
+/// - 有盈利但没有亏损时返回 1（与 ta4j ProfitLossRatioCriterion 约定一致）
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProfitFactorCriterion;
+
+impl ProfitFactorCriterion {
+    fn profit_factor<'a, T, CM, HM, S, P>(positions: P) -> T
+    where
+        T: TrNum + 'static,
+        CM: CostModel<T> + Clone + 'a,
+        HM: CostModel<T> + Clone + 'a,
+        S: BarSeries<T> + 'static,
+        P: IntoIterator<Item = &'a Position<T, CM, HM, S>> + Clone,
+    {
+        let (gross_profit, _) = filtered_profit(positions.clone(), PositionFilter::Profit);
+        let (gross_loss, _) = filtered_profit(positions, PositionFilter::Loss);
+        ratio_or_default(&gross_profit, &gross_loss)
+    }
+}
+
+/// 盈利 / |亏损|，按 ta4j 约定处理零值
+pub(crate) fn ratio_or_default<T: TrNum>(profit: &T, loss: &T) -> T {
+    if profit.is_zero() {
+        return T::zero();
+    }
+    if loss.is_zero() {
+        return T::one();
+    }
+    profit
+        .divided_by(loss)
+        .map(|ratio| ratio.abs())
+        .unwrap_or_else(|_| T::one())
+}
+
+impl<T, CM, HM, S> AnalysisCriterion<T, CM, HM, S> for ProfitFactorCriterion
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    fn calculate_position(&self, _series: &S, position: &Position<T, CM, HM, S>) -> T {
+        Self::profit_factor(std::iter::once(position))
+    }
+
+    fn calculate_record(&self, _series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> T {
+        Self::profit_factor(record.positions().iter().map(|p| p.as_ref()))
+    }
+
+    fn better_than(&self, criterion_value1: &T, criterion_value2: &T) -> bool {
+        criterion_value1.is_greater_than(criterion_value2)
+    }
+}
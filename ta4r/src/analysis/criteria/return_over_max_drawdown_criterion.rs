@@ -0,0 +1,81 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::criteria::gross_return_criterion::GrossReturnCriterion;
+use crate::analysis::criteria::maximum_drawdown_criterion::MaximumDrawdownCriterion;
+use crate::analysis::{AnalysisCriterion, CostModel};
+use crate::bar::types::BarSeries;
+use crate::num::TrNum;
+use crate::position::Position;
+
+/// 收益回撤比（RoMaD）：净收益率 / 最大回撤，越大越好
+///
+/// - 净收益率 = 毛收益率 - 1
+/// - 没有回撤时直接返回净收益率（与 ta4j 一致）
+/// - 未关闭的持仓返回 0
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReturnOverMaxDrawdownCriterion;
+
+impl ReturnOverMaxDrawdownCriterion {
+    fn ratio<T: TrNum>(gross_return: T, max_drawdown: T) -> T {
+        let net_return = gross_return - T::one();
+        if max_drawdown.is_zero() {
+            return net_return;
+        }
+        net_return
+            .divided_by(&max_drawdown)
+            .unwrap_or_else(|_| T::zero())
+    }
+}
+
+impl<T, CM, HM, S> AnalysisCriterion<T, CM, HM, S> for ReturnOverMaxDrawdownCriterion
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    fn calculate_position(&self, series: &S, position: &Position<T, CM, HM, S>) -> T {
+        if !position.is_closed() {
+            return T::zero();
+        }
+        Self::ratio(
+            GrossReturnCriterion.calculate_position(series, position),
+            MaximumDrawdownCriterion.calculate_position(series, position),
+        )
+    }
+
+    fn calculate_record(&self, series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> T {
+        Self::ratio(
+            GrossReturnCriterion.calculate_record(series, record),
+            MaximumDrawdownCriterion.calculate_record(series, record),
+        )
+    }
+
+    fn better_than(&self, criterion_value1: &T, criterion_value2: &T) -> bool {
+        criterion_value1.is_greater_than(criterion_value2)
+    }
+}
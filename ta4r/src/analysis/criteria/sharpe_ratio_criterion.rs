@@ -0,0 +1,95 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::criteria::{annualize, mean, sample_standard_deviation};
+use crate::analysis::equity_curve::{EquityCurve, ReturnType};
+use crate::analysis::{AnalysisCriterion, CostModel};
+use crate::bar::types::BarSeries;
+use crate::num::{NumFactory, TrNum};
+use crate::position::Position;
+
+/// 夏普比率：逐 bar 超额收益均值 / 逐 bar 收益样本标准差，越大越好
+///
+/// - `risk_free_rate` 为每根 bar 的无风险收益率
+/// - 设置 `bars_per_year` 后按 sqrt(bars_per_year) 年化
+/// - 收益率无波动时返回 0
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SharpeRatioCriterion {
+    risk_free_rate: f64,
+    bars_per_year: Option<f64>,
+}
+
+impl SharpeRatioCriterion {
+    pub fn new(risk_free_rate: f64) -> Self {
+        Self {
+            risk_free_rate,
+            bars_per_year: None,
+        }
+    }
+
+    /// 按每年 bar 数年化（如日线 252）
+    pub fn with_bars_per_year(mut self, bars_per_year: f64) -> Self {
+        self.bars_per_year = Some(bars_per_year);
+        self
+    }
+
+    /// 计算资金曲线的夏普比率
+    pub fn calculate_curve<T, S>(&self, series: &S, curve: &EquityCurve<T>) -> T
+    where
+        T: TrNum + 'static,
+        S: BarSeries<T> + 'static,
+    {
+        let returns = curve.returns(ReturnType::Arithmetic);
+        let deviation = sample_standard_deviation(&returns);
+        if deviation.is_zero() {
+            return T::zero();
+        }
+        let risk_free_rate = series.num_factory().num_of_f64(self.risk_free_rate);
+        let excess = mean(&returns).minus(&risk_free_rate);
+        let ratio = excess.divided_by(&deviation).unwrap_or_else(|_| T::zero());
+        annualize(ratio, self.bars_per_year)
+    }
+}
+
+impl<T, CM, HM, S> AnalysisCriterion<T, CM, HM, S> for SharpeRatioCriterion
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    fn calculate_position(&self, series: &S, position: &Position<T, CM, HM, S>) -> T {
+        self.calculate_curve(series, &EquityCurve::from_position(series, position))
+    }
+
+    fn calculate_record(&self, series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> T {
+        self.calculate_curve(series, &EquityCurve::new(series, record))
+    }
+
+    fn better_than(&self, criterion_value1: &T, criterion_value2: &T) -> bool {
+        criterion_value1.is_greater_than(criterion_value2)
+    }
+}
@@ -0,0 +1,110 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::criteria::{annualize, mean};
+use crate::analysis::equity_curve::{EquityCurve, ReturnType};
+use crate::analysis::{AnalysisCriterion, CostModel};
+use crate::bar::types::BarSeries;
+use crate::num::{NumFactory, TrNum};
+use crate::position::Position;
+
+/// 索提诺比率：逐 bar 超额收益均值 / 下行标准差，越大越好
+///
+/// - `minimum_acceptable_return` 为每根 bar 的目标收益率（默认 0）
+/// - 下行标准差 = sqrt(Σ min(r - target, 0)² / n)
+/// - 设置 `bars_per_year` 后按 sqrt(bars_per_year) 年化
+/// - 没有下行波动时返回 0
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SortinoRatioCriterion {
+    minimum_acceptable_return: f64,
+    bars_per_year: Option<f64>,
+}
+
+impl SortinoRatioCriterion {
+    pub fn new(minimum_acceptable_return: f64) -> Self {
+        Self {
+            minimum_acceptable_return,
+            bars_per_year: None,
+        }
+    }
+
+    /// 按每年 bar 数年化（如日线 252）
+    pub fn with_bars_per_year(mut self, bars_per_year: f64) -> Self {
+        self.bars_per_year = Some(bars_per_year);
+        self
+    }
+
+    /// 计算资金曲线的索提诺比率
+    pub fn calculate_curve<T, S>(&self, series: &S, curve: &EquityCurve<T>) -> T
+    where
+        T: TrNum + 'static,
+        S: BarSeries<T> + 'static,
+    {
+        let target = series
+            .num_factory()
+            .num_of_f64(self.minimum_acceptable_return);
+        let excess: Vec<T> = curve
+            .returns(ReturnType::Arithmetic)
+            .iter()
+            .map(|r| r.minus(&target))
+            .collect();
+        let downside: Vec<T> = excess
+            .iter()
+            .map(|r| {
+                let shortfall = r.min(&T::zero());
+                shortfall.multiplied_by(&shortfall)
+            })
+            .collect();
+        let downside_deviation = mean(&downside).sqrt().unwrap_or_else(|_| T::zero());
+        if downside_deviation.is_zero() {
+            return T::zero();
+        }
+        let ratio = mean(&excess)
+            .divided_by(&downside_deviation)
+            .unwrap_or_else(|_| T::zero());
+        annualize(ratio, self.bars_per_year)
+    }
+}
+
+impl<T, CM, HM, S> AnalysisCriterion<T, CM, HM, S> for SortinoRatioCriterion
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    fn calculate_position(&self, series: &S, position: &Position<T, CM, HM, S>) -> T {
+        self.calculate_curve(series, &EquityCurve::from_position(series, position))
+    }
+
+    fn calculate_record(&self, series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> T {
+        self.calculate_curve(series, &EquityCurve::new(series, record))
+    }
+
+    fn better_than(&self, criterion_value1: &T, criterion_value2: &T) -> bool {
+        criterion_value1.is_greater_than(criterion_value2)
+    }
+}
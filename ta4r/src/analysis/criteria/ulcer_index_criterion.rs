@@ -0,0 +1,86 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::criteria::mean;
+use crate::analysis::equity_curve::EquityCurve;
+use crate::analysis::{AnalysisCriterion, CostModel};
+use crate::bar::types::BarSeries;
+use crate::num::{NumFactory, TrNum};
+use crate::position::Position;
+
+/// 溃疡指数（Ulcer Index）：资金曲线百分比回撤的均方根，越小越好
+///
+/// `sqrt(mean(((value - peak) / peak * 100)²))`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UlcerIndexCriterion;
+
+impl UlcerIndexCriterion {
+    /// 计算资金曲线的溃疡指数
+    pub fn calculate_curve<T, S>(series: &S, curve: &EquityCurve<T>) -> T
+    where
+        T: TrNum + 'static,
+        S: BarSeries<T> + 'static,
+    {
+        let hundred = series.num_factory().hundred().as_ref().clone();
+        let mut peak = curve.values()[0].clone();
+        let squared_drawdowns: Vec<T> = curve
+            .values()
+            .iter()
+            .map(|value| {
+                peak = peak.max(value);
+                let percent = value
+                    .minus(&peak)
+                    .divided_by(&peak)
+                    .map(|drawdown| drawdown.multiplied_by(&hundred))
+                    .unwrap_or_else(|_| T::zero());
+                percent.multiplied_by(&percent)
+            })
+            .collect();
+        mean(&squared_drawdowns)
+            .sqrt()
+            .unwrap_or_else(|_| T::zero())
+    }
+}
+
+impl<T, CM, HM, S> AnalysisCriterion<T, CM, HM, S> for UlcerIndexCriterion
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    fn calculate_position(&self, series: &S, position: &Position<T, CM, HM, S>) -> T {
+        Self::calculate_curve(series, &EquityCurve::from_position(series, position))
+    }
+
+    fn calculate_record(&self, series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> T {
+        Self::calculate_curve(series, &EquityCurve::new(series, record))
+    }
+
+    fn better_than(&self, criterion_value1: &T, criterion_value2: &T) -> bool {
+        criterion_value1.is_less_than(criterion_value2)
+    }
+}
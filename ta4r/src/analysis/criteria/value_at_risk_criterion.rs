@@ -0,0 +1,111 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::equity_curve::{EquityCurve, ReturnType};
+use crate::analysis::{AnalysisCriterion, CostModel};
+use crate::bar::types::BarSeries;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use crate::position::Position;
+
+/// 历史模拟法风险价值（VaR）：逐 bar 对数收益率在 (1 - confidence) 分位处的损失
+///
+/// 结果为非正数（-0.05 表示 5% 的单 bar 损失），越大（越接近 0）越好
+#[derive(Debug, Clone, Copy)]
+pub struct ValueAtRiskCriterion {
+    confidence: f64,
+}
+
+impl Default for ValueAtRiskCriterion {
+    fn default() -> Self {
+        Self { confidence: 0.95 }
+    }
+}
+
+impl ValueAtRiskCriterion {
+    /// confidence 取值 (0, 1)，如 0.95
+    pub fn new(confidence: f64) -> Result<Self, IndicatorError> {
+        check_confidence(confidence)?;
+        Ok(Self { confidence })
+    }
+
+    pub fn confidence(&self) -> f64 {
+        self.confidence
+    }
+
+    /// 计算资金曲线的 VaR
+    pub fn calculate_curve<T: TrNum + 'static>(&self, curve: &EquityCurve<T>) -> T {
+        let returns = sorted_returns(curve);
+        match returns.get(tail_index(returns.len(), self.confidence)) {
+            Some(value_at_risk) => value_at_risk.min(&T::zero()),
+            None => T::zero(),
+        }
+    }
+}
+
+/// 置信度必须在 (0, 1) 内
+pub(crate) fn check_confidence(confidence: f64) -> Result<(), IndicatorError> {
+    if confidence > 0.0 && confidence < 1.0 {
+        Ok(())
+    } else {
+        Err(IndicatorError::CalculationError {
+            message: format!("Confidence must be in (0, 1), got {}", confidence),
+        })
+    }
+}
+
+/// 升序排列的逐 bar 对数收益率
+pub(crate) fn sorted_returns<T: TrNum + 'static>(curve: &EquityCurve<T>) -> Vec<T> {
+    let mut returns = curve.returns(ReturnType::Log);
+    returns.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    returns
+}
+
+/// (1 - confidence) 分位对应的升序下标
+pub(crate) fn tail_index(len: usize, confidence: f64) -> usize {
+    let tail_len = ((1.0 - confidence) * len as f64).ceil() as usize;
+    tail_len.clamp(1, len.max(1)) - 1
+}
+
+impl<T, CM, HM, S> AnalysisCriterion<T, CM, HM, S> for ValueAtRiskCriterion
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    fn calculate_position(&self, series: &S, position: &Position<T, CM, HM, S>) -> T {
+        self.calculate_curve(&EquityCurve::from_position(series, position))
+    }
+
+    fn calculate_record(&self, series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> T {
+        self.calculate_curve(&EquityCurve::new(series, record))
+    }
+
+    fn better_than(&self, criterion_value1: &T, criterion_value2: &T) -> bool {
+        criterion_value1.is_greater_than(criterion_value2)
+    }
+}
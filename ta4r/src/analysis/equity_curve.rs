@@ -0,0 +1,261 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::CostModel;
+use crate::bar::types::{Bar, BarSeries};
+use crate::num::{NumFactory, TrNum};
+use crate::position::Position;
+
+/// 收益率计算方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReturnType {
+    /// 算术收益率：v[i] / v[i-1] - 1
+    #[default]
+    Arithmetic,
+    /// 对数收益率：ln(v[i] / v[i-1])
+    Log,
+}
+
+/// 资金曲线（对应 ta4j CashFlow 的计算逻辑）
+///
+/// - 起始值为 1，按收盘价逐 bar 计算持仓期间的资金比率
+/// - 入场/出场使用成交净价（已含交易成本），持仓成本按持仓 bar 数平摊
+/// - 未平仓持仓按最后一根 bar 的收盘价计值（mark-to-market）
+/// - 内部按 `begin_index` 偏移存储，`value(index)` 使用序列的绝对索引
+#[derive(Debug, Clone)]
+pub struct EquityCurve<T: TrNum> {
+    begin_index: usize,
+    values: Vec<T>,
+}
+
+impl<T: TrNum + 'static> EquityCurve<T> {
+    /// 由交易记录构建资金曲线，计算到序列最后一根 bar
+    pub fn new<CM, HM, S>(series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> Self
+    where
+        CM: CostModel<T> + Clone,
+        HM: CostModel<T> + Clone,
+        S: BarSeries<T> + 'static,
+    {
+        let final_index = series.get_end_index().unwrap_or(0);
+        Self::with_final_index(series, record, final_index)
+    }
+
+    /// 由交易记录构建资金曲线，计算到 final_index（包含）
+    pub fn with_final_index<CM, HM, S>(
+        series: &S,
+        record: &dyn TradingRecord<T, CM, HM, S>,
+        final_index: usize,
+    ) -> Self
+    where
+        CM: CostModel<T> + Clone,
+        HM: CostModel<T> + Clone,
+        S: BarSeries<T> + 'static,
+    {
+        let current = record.current_position();
+        let open = current.is_opened().then_some(current.as_ref());
+        let positions = record.positions().iter().map(|p| p.as_ref()).chain(open);
+        Self::from_positions(series, positions, final_index)
+    }
+
+    /// 由单个持仓构建资金曲线，计算到序列最后一根 bar
+    pub fn from_position<CM, HM, S>(series: &S, position: &Position<T, CM, HM, S>) -> Self
+    where
+        CM: CostModel<T> + Clone,
+        HM: CostModel<T> + Clone,
+        S: BarSeries<T> + 'static,
+    {
+        let final_index = series.get_end_index().unwrap_or(0);
+        Self::from_positions(series, std::iter::once(position), final_index)
+    }
+
+    fn from_positions<'a, CM, HM, S, P>(series: &S, positions: P, final_index: usize) -> Self
+    where
+        CM: CostModel<T> + Clone + 'a,
+        HM: CostModel<T> + Clone + 'a,
+        S: BarSeries<T> + 'static,
+        P: IntoIterator<Item = &'a Position<T, CM, HM, S>>,
+    {
+        let begin_index = series.get_begin_index().unwrap_or(0);
+        let final_index = series
+            .get_end_index()
+            .map_or(final_index, |end| end.min(final_index))
+            .max(begin_index);
+
+        let mut curve = Self {
+            begin_index,
+            values: vec![T::one()],
+        };
+        for position in positions {
+            curve.add_position(series, position, final_index);
+        }
+        curve.fill_to(final_index);
+        curve
+    }
+
+    fn add_position<CM, HM, S>(
+        &mut self,
+        series: &S,
+        position: &Position<T, CM, HM, S>,
+        final_index: usize,
+    ) where
+        CM: CostModel<T> + Clone,
+        HM: CostModel<T> + Clone,
+        S: BarSeries<T> + 'static,
+    {
+        let Some(entry) = position.entry() else {
+            return;
+        };
+        let entry_index = entry.index;
+        if entry_index < self.begin_index || entry_index > final_index {
+            return;
+        }
+
+        // 出场在计算区间之外的持仓视为未平仓，按收盘价计值
+        let exit = position.exit().filter(|exit| exit.index <= final_index);
+        let end_index = exit.map_or(final_index, |exit| exit.index);
+        self.fill_to(entry_index);
+
+        // 入场时资金已为非正，不再计算该持仓
+        let entry_value = self.values[entry_index - self.begin_index].clone();
+        if !entry_value.is_positive() || end_index <= entry_index {
+            return;
+        }
+
+        let is_long = entry.is_buy();
+        let holding_cost = position.get_holding_cost_with_final(end_index);
+        let periods = series.num_factory().num_of_usize(end_index - entry_index);
+        let average_cost = holding_cost
+            .divided_by(&periods)
+            .unwrap_or_else(|_| T::zero());
+        let net_entry_price = entry.net_price.clone();
+
+        // 清除入场之后的值（与上一个持仓重叠时以当前持仓为准）
+        self.values.truncate(entry_index - self.begin_index + 1);
+        for index in entry_index + 1..end_index {
+            let close = close_price(series, index).unwrap_or_else(|| net_entry_price.clone());
+            let net_price = add_cost(&close, &average_cost, is_long);
+            let ratio = intermediate_ratio(is_long, &net_entry_price, &net_price);
+            self.values.push(entry_value.multiplied_by(&ratio));
+        }
+
+        let exit_price = match exit {
+            Some(exit) => exit.net_price.clone(),
+            None => close_price(series, end_index).unwrap_or_else(|| net_entry_price.clone()),
+        };
+        let net_exit_price = add_cost(&exit_price, &average_cost, is_long);
+        let ratio = intermediate_ratio(is_long, &net_entry_price, &net_exit_price);
+        self.values.push(entry_value.multiplied_by(&ratio));
+    }
+
+    /// 用最后一个值填充到 index（包含）
+    fn fill_to(&mut self, index: usize) {
+        let len = index + 1 - self.begin_index;
+        if len > self.values.len() {
+            let last = self.values.last().cloned().unwrap_or_else(T::one);
+            self.values.resize(len, last);
+        }
+    }
+
+    /// 第一个值对应的序列索引
+    pub fn begin_index(&self) -> usize {
+        self.begin_index
+    }
+
+    /// 最后一个值对应的序列索引
+    pub fn end_index(&self) -> usize {
+        self.begin_index + self.values.len() - 1
+    }
+
+    /// 获取指定序列索引的资金值
+    pub fn value(&self, index: usize) -> Option<&T> {
+        index
+            .checked_sub(self.begin_index)
+            .and_then(|i| self.values.get(i))
+    }
+
+    /// 所有资金值（从 begin_index 开始）
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// 最后一个资金值
+    pub fn last_value(&self) -> &T {
+        self.values.last().expect("EquityCurve always has a value")
+    }
+
+    /// 整体收益率（比率形式）：最后值 / 初始值
+    pub fn total_return(&self) -> T {
+        self.last_value()
+            .divided_by(&self.values[0])
+            .unwrap_or_else(|_| T::one())
+    }
+
+    /// 逐 bar 收益率，长度为 values().len() - 1，第 i 个对应序列索引 begin_index + i + 1
+    pub fn returns(&self, return_type: ReturnType) -> Vec<T> {
+        self.values
+            .windows(2)
+            .map(|w| period_return(&w[0], &w[1], return_type))
+            .collect()
+    }
+}
+
+/// 从 previous 到 current 的单期收益率，无法计算时返回 0
+pub(crate) fn period_return<T: TrNum>(previous: &T, current: &T, return_type: ReturnType) -> T {
+    let Ok(ratio) = current.divided_by(previous) else {
+        return T::zero();
+    };
+    match return_type {
+        ReturnType::Arithmetic => ratio - T::one(),
+        ReturnType::Log => ratio.log().unwrap_or_else(|_| T::zero()),
+    }
+}
+
+fn close_price<T, S>(series: &S, index: usize) -> Option<T>
+where
+    T: TrNum + 'static,
+    S: BarSeries<T>,
+{
+    series.get_bar(index).and_then(|bar| bar.get_close_price())
+}
+
+/// 将平摊的持仓成本计入价格：多头降低卖出净价，空头提高买回净价
+fn add_cost<T: TrNum>(price: &T, cost: &T, is_long: bool) -> T {
+    if is_long {
+        price.minus(cost)
+    } else {
+        price.plus(cost)
+    }
+}
+
+/// 持仓期间资金比率：多头为 price / entry，空头为 2 - price / entry
+fn intermediate_ratio<T: TrNum>(is_long: bool, entry_price: &T, price: &T) -> T {
+    let ratio = price.divided_by(entry_price).unwrap_or_else(|_| T::one());
+    if is_long {
+        ratio
+    } else {
+        T::from_i64(2).unwrap_or_else(T::one).minus(&ratio)
+    }
+}
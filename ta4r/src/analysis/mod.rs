@@ -23,10 +23,17 @@
  * SOFTWARE.
  */
 
+use crate::TradingRecord;
 use crate::analysis::cost::CostContext;
+use crate::bar::types::BarSeries;
 use crate::num::TrNum;
+use crate::position::Position;
 
+pub mod cash_flow;
 pub mod cost;
+pub mod criteria;
+pub mod equity_curve;
+pub mod returns;
 
 pub trait CostModel<T: TrNum + 'static> {
     /// 计算给定持仓的成本
@@ -41,3 +48,21 @@ pub trait CostModel<T: TrNum + 'static> {
     /// 判断两个成本模型是否相等
     fn equals(&self, other: &Self) -> bool;
 }
+
+/// 交易分析指标（对应 ta4j AnalysisCriterion），用于评估单个持仓或整个交易记录
+pub trait AnalysisCriterion<T, CM, HM, S>
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    /// 计算单个持仓的指标值
+    fn calculate_position(&self, series: &S, position: &Position<T, CM, HM, S>) -> T;
+
+    /// 计算整个交易记录的指标值（仅统计已关闭的持仓，除非指标另有说明）
+    fn calculate_record(&self, series: &S, record: &dyn TradingRecord<T, CM, HM, S>) -> T;
+
+    /// 判断 criterion_value1 是否优于 criterion_value2
+    fn better_than(&self, criterion_value1: &T, criterion_value2: &T) -> bool;
+}
@@ -0,0 +1,154 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::CostModel;
+use crate::analysis::cash_flow::CashFlow;
+use crate::analysis::equity_curve::{EquityCurve, ReturnType};
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use crate::position::Position;
+use std::sync::Arc;
+
+/// Returns：逐 bar 收益率指标（对应 ta4j Returns）
+///
+/// - 基于 [`CashFlow`] 的资金曲线计算，支持算术收益率与对数收益率
+/// - 第一根 bar 没有前值，收益率为 0
+pub struct Returns<T, S>
+where
+    T: TrNum + 'static,
+    S: BarSeries<T> + 'static,
+{
+    series: BarSeriesRef<S>,
+    return_type: ReturnType,
+    begin_index: usize,
+    values: Arc<Vec<T>>,
+}
+
+impl<T, S> Clone for Returns<T, S>
+where
+    T: TrNum + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            series: self.series.clone(),
+            return_type: self.return_type,
+            begin_index: self.begin_index,
+            values: Arc::clone(&self.values),
+        }
+    }
+}
+
+impl<T, S> Returns<T, S>
+where
+    T: TrNum + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 由交易记录构建，计算到序列最后一根 bar
+    pub fn new<CM, HM>(
+        series_ref: BarSeriesRef<S>,
+        record: &dyn TradingRecord<T, CM, HM, S>,
+        return_type: ReturnType,
+    ) -> Result<Self, IndicatorError>
+    where
+        CM: CostModel<T> + Clone,
+        HM: CostModel<T> + Clone,
+    {
+        let cash_flow = CashFlow::new(series_ref, record)?;
+        Ok(Self::from_cash_flow(&cash_flow, return_type))
+    }
+
+    /// 由单个持仓构建
+    pub fn from_position<CM, HM>(
+        series_ref: BarSeriesRef<S>,
+        position: &Position<T, CM, HM, S>,
+        return_type: ReturnType,
+    ) -> Result<Self, IndicatorError>
+    where
+        CM: CostModel<T> + Clone,
+        HM: CostModel<T> + Clone,
+    {
+        let cash_flow = CashFlow::from_position(series_ref, position)?;
+        Ok(Self::from_cash_flow(&cash_flow, return_type))
+    }
+
+    /// 由已有的 CashFlow 构建
+    pub fn from_cash_flow(cash_flow: &CashFlow<T, S>, return_type: ReturnType) -> Self {
+        let curve: &EquityCurve<T> = cash_flow.curve();
+        let mut values = Vec::with_capacity(curve.values().len());
+        values.push(T::zero());
+        values.extend(curve.returns(return_type));
+        Self {
+            series: cash_flow.bar_series(),
+            return_type,
+            begin_index: curve.begin_index(),
+            values: Arc::new(values),
+        }
+    }
+
+    pub fn return_type(&self) -> ReturnType {
+        self.return_type
+    }
+
+    /// 所有收益率（从序列 begin_index 开始）
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// 收益率个数
+    pub fn size(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<T, S> Indicator for Returns<T, S>
+where
+    T: TrNum + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<Self::Output, IndicatorError> {
+        index
+            .checked_sub(self.begin_index)
+            .and_then(|i| self.values.get(i))
+            .cloned()
+            .ok_or(IndicatorError::OutOfBounds { index })
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.series.clone()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        0
+    }
+}
@@ -0,0 +1,299 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::TradingRecord;
+use crate::analysis::CostModel;
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::{Bar, BarSeries};
+use crate::base_trading_record::BaseTradingRecord;
+use crate::num::TrNum;
+use crate::strategy::Strategy;
+use crate::trade::TradeType;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// 回测结束时对未平仓持仓的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OpenPositionHandling {
+    /// 保留未平仓持仓（与 ta4j 一致）
+    #[default]
+    Keep,
+    /// 在回测区间最后一根 bar 以收盘价平仓，且不在最后一根 bar 入场
+    CloseAtEnd,
+}
+
+/// BarSeriesManager：在 BarSeries 上运行策略的回测器
+///
+/// - 按收盘价执行交易，数量默认为 1
+/// - 跳过策略的不稳定期（`Strategy::unstable_bars`）
+/// - 返回填充完成的 [`BaseTradingRecord`]
+pub struct BarSeriesManager<T, CM, HM, S>
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    series: BarSeriesRef<S>,
+    transaction_cost_model: CM,
+    holding_cost_model: HM,
+    open_position_handling: OpenPositionHandling,
+    _phantom: PhantomData<T>,
+}
+
+impl<T, CM, HM, S> Clone for BarSeriesManager<T, CM, HM, S>
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            series: self.series.clone(),
+            transaction_cost_model: self.transaction_cost_model.clone(),
+            holding_cost_model: self.holding_cost_model.clone(),
+            open_position_handling: self.open_position_handling,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, CM, HM, S> BarSeriesManager<T, CM, HM, S>
+where
+    T: TrNum + 'static,
+    CM: CostModel<T> + Clone,
+    HM: CostModel<T> + Clone,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，直接传入 BarSeriesRef
+    pub fn new(
+        series_ref: BarSeriesRef<S>,
+        transaction_cost_model: CM,
+        holding_cost_model: HM,
+    ) -> Self {
+        Self {
+            series: series_ref,
+            transaction_cost_model,
+            holding_cost_model,
+            open_position_handling: OpenPositionHandling::default(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(
+        series: Arc<RwLock<S>>,
+        transaction_cost_model: CM,
+        holding_cost_model: HM,
+    ) -> Self {
+        Self::new(
+            BarSeriesRef::Shared(series),
+            transaction_cost_model,
+            holding_cost_model,
+        )
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(
+        series: Arc<RefCell<S>>,
+        transaction_cost_model: CM,
+        holding_cost_model: HM,
+    ) -> Self {
+        Self::new(
+            BarSeriesRef::Mut(series),
+            transaction_cost_model,
+            holding_cost_model,
+        )
+    }
+
+    /// 设置回测结束时未平仓持仓的处理方式
+    pub fn with_open_position_handling(mut self, handling: OpenPositionHandling) -> Self {
+        self.open_position_handling = handling;
+        self
+    }
+
+    pub fn bar_series(&self) -> BarSeriesRef<S> {
+        self.series.clone()
+    }
+
+    pub fn transaction_cost_model(&self) -> &CM {
+        &self.transaction_cost_model
+    }
+
+    pub fn holding_cost_model(&self) -> &HM {
+        &self.holding_cost_model
+    }
+
+    pub fn open_position_handling(&self) -> OpenPositionHandling {
+        self.open_position_handling
+    }
+
+    /// 在整个序列上运行策略（BUY 起始，数量为 1）
+    pub fn run<ST>(&self, strategy: &ST) -> Result<BaseTradingRecord<T, CM, HM, S>, String>
+    where
+        ST: Strategy<
+                Num = T,
+                CostBuy = CM,
+                CostSell = HM,
+                Series = S,
+                TradingRec = BaseTradingRecord<T, CM, HM, S>,
+            >,
+    {
+        self.run_with_type(strategy, TradeType::Buy)
+    }
+
+    /// 在整个序列上运行策略，指定起始交易类型
+    pub fn run_with_type<ST>(
+        &self,
+        strategy: &ST,
+        trade_type: TradeType,
+    ) -> Result<BaseTradingRecord<T, CM, HM, S>, String>
+    where
+        ST: Strategy<
+                Num = T,
+                CostBuy = CM,
+                CostSell = HM,
+                Series = S,
+                TradingRec = BaseTradingRecord<T, CM, HM, S>,
+            >,
+    {
+        self.run_with_range(strategy, trade_type, None, None)
+    }
+
+    /// 在 [start_index, end_index] 区间运行策略，未指定的边界取序列边界
+    pub fn run_with_range<ST>(
+        &self,
+        strategy: &ST,
+        trade_type: TradeType,
+        start_index: Option<usize>,
+        end_index: Option<usize>,
+    ) -> Result<BaseTradingRecord<T, CM, HM, S>, String>
+    where
+        ST: Strategy<
+                Num = T,
+                CostBuy = CM,
+                CostSell = HM,
+                Series = S,
+                TradingRec = BaseTradingRecord<T, CM, HM, S>,
+            >,
+    {
+        self.run_with_amount(strategy, trade_type, T::one(), start_index, end_index)
+    }
+
+    /// 完整参数运行策略：起始交易类型、每笔交易数量、可选索引区间
+    pub fn run_with_amount<ST>(
+        &self,
+        strategy: &ST,
+        trade_type: TradeType,
+        amount: T,
+        start_index: Option<usize>,
+        end_index: Option<usize>,
+    ) -> Result<BaseTradingRecord<T, CM, HM, S>, String>
+    where
+        ST: Strategy<
+                Num = T,
+                CostBuy = CM,
+                CostSell = HM,
+                Series = S,
+                TradingRec = BaseTradingRecord<T, CM, HM, S>,
+            >,
+    {
+        let (series_begin, series_end) = self
+            .series
+            .with_ref(|s| (s.get_begin_index(), s.get_end_index()))?;
+
+        let (Some(series_begin), Some(series_end)) = (series_begin, series_end) else {
+            // 空序列：返回空交易记录
+            return Ok(self.new_record(trade_type, start_index, end_index));
+        };
+
+        let run_begin = start_index.map_or(series_begin, |i| i.max(series_begin));
+        let run_end = end_index.map_or(series_end, |i| i.min(series_end));
+        if run_begin > run_end {
+            return Err(format!(
+                "Invalid run range: start index {} is after end index {}",
+                run_begin, run_end
+            ));
+        }
+
+        log::trace!(
+            "Running strategy (for {}): {} (starting with {:?}) from index {} to {}",
+            self.series
+                .with_ref_or(String::new(), |s| s.get_name().to_string()),
+            strategy.name(),
+            trade_type,
+            run_begin,
+            run_end
+        );
+
+        let mut record = self.new_record(trade_type, Some(run_begin), Some(run_end));
+
+        // 不稳定期内策略不产生信号，直接从第一根稳定的 bar 开始
+        let first_index = run_begin.max(strategy.unstable_bars());
+        let close_at_end = self.open_position_handling == OpenPositionHandling::CloseAtEnd;
+        for index in first_index..=run_end {
+            // 收盘平仓模式下不在最后一根 bar 入场，否则会产生同价入场出场的零长度持仓
+            if close_at_end && index == run_end && record.current_position().is_new() {
+                break;
+            }
+            // 注意：调用策略时不能持有 series 的借用，规则内的指标会再次访问 series
+            if strategy.should_operate(index, &record) {
+                let price = self.close_price(index)?;
+                record.operate_with_price_amount(index, price, amount.clone());
+            }
+        }
+
+        if close_at_end && record.current_position().is_opened() {
+            let price = self.close_price(run_end)?;
+            record.operate_with_price_amount(run_end, price, amount);
+        }
+
+        Ok(record)
+    }
+
+    fn new_record(
+        &self,
+        trade_type: TradeType,
+        start_index: Option<usize>,
+        end_index: Option<usize>,
+    ) -> BaseTradingRecord<T, CM, HM, S> {
+        BaseTradingRecord::new_with_type(
+            trade_type,
+            start_index,
+            end_index,
+            self.transaction_cost_model.clone(),
+            self.holding_cost_model.clone(),
+        )
+    }
+
+    fn close_price(&self, index: usize) -> Result<T, String> {
+        self.series
+            .with_ref(|s| s.get_bar(index).and_then(|bar| bar.get_close_price()))?
+            .ok_or_else(|| format!("No close price available at index {}", index))
+    }
+}
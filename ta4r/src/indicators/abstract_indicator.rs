@@ -108,3 +108,56 @@ where
         0
     }
 }
+
+/// bar 上的价格字段
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BarPrice {
+    Open,
+    High,
+    Low,
+    Close,
+}
+
+impl BarPrice {
+    fn name(self) -> &'static str {
+        match self {
+            BarPrice::Open => "Open",
+            BarPrice::High => "High",
+            BarPrice::Low => "Low",
+            BarPrice::Close => "Close",
+        }
+    }
+}
+
+/// 读取 index 处的 bar，不存在时返回 OutOfBounds
+pub(crate) fn bar_at<T, S>(series: &S, index: usize) -> Result<&S::Bar, IndicatorError>
+where
+    T: TrNum + 'static,
+    S: BarSeries<T>,
+{
+    series
+        .get_bar(index)
+        .ok_or(IndicatorError::OutOfBounds { index })
+}
+
+/// 读取 index 处 bar 的价格，价格缺失时返回 CalculationError
+pub(crate) fn bar_price<T, S>(
+    series: &S,
+    index: usize,
+    price: BarPrice,
+) -> Result<T, IndicatorError>
+where
+    T: TrNum + 'static,
+    S: BarSeries<T>,
+{
+    let bar = bar_at(series, index)?;
+    let value = match price {
+        BarPrice::Open => bar.get_open_price(),
+        BarPrice::High => bar.get_high_price(),
+        BarPrice::Low => bar.get_low_price(),
+        BarPrice::Close => bar.get_close_price(),
+    };
+    value.ok_or_else(|| IndicatorError::CalculationError {
+        message: format!("{} price missing at index {}", price.name(), index),
+    })
+}
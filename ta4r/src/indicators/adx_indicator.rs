@@ -0,0 +1,139 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::averages::mma_indicator::MMAIndicator;
+use crate::indicators::dx_indicator::DxIndicator;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// 平均动向指数 ADX：DX 的 MMA
+///
+/// 默认 DI 与 ADX 使用相同周期（通常为 14）
+pub struct AdxIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    dx: Arc<DxIndicator<T, S>>,
+    average_dx: MMAIndicator<T, S, DxIndicator<T, S>>,
+    unstable_bars: usize,
+}
+
+impl<T, S> Clone for AdxIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            dx: Arc::clone(&self.dx),
+            average_dx: self.average_dx.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S> AdxIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// DI 与 ADX 使用相同周期
+    pub fn new(series_ref: BarSeriesRef<S>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::with_periods(series_ref, bar_count, bar_count)
+    }
+
+    /// 分别指定 DI 周期与 ADX 平滑周期
+    pub fn with_periods(
+        series_ref: BarSeriesRef<S>,
+        di_bar_count: usize,
+        adx_bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        let dx = DxIndicator::new(series_ref, di_bar_count)?;
+        Self::from_dx(Arc::new(dx), adx_bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), bar_count)
+    }
+
+    /// 复用已有的 DX
+    pub fn from_dx(
+        dx: Arc<DxIndicator<T, S>>,
+        adx_bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        if adx_bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "ADX bar count must be greater than 0".to_string(),
+            });
+        }
+        let unstable_bars = dx.count_of_unstable_bars() + adx_bar_count;
+        let average_dx = MMAIndicator::new(Arc::clone(&dx), adx_bar_count)?;
+        Ok(Self {
+            dx,
+            average_dx,
+            unstable_bars,
+        })
+    }
+
+    pub fn dx(&self) -> Arc<DxIndicator<T, S>> {
+        Arc::clone(&self.dx)
+    }
+}
+
+impl<T, S> Indicator for AdxIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.average_dx.get_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.average_dx.bar_series()
+    }
+
+    /// DX 的不稳定期 + ADX 平滑周期
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
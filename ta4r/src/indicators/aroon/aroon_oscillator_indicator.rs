@@ -0,0 +1,116 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::aroon::aroon_down_indicator::AroonDownIndicator;
+use crate::indicators::aroon::aroon_up_indicator::AroonUpIndicator;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// Aroon 振荡器
+///
+/// AroonOscillator = AroonUp - AroonDown，取值范围 [-100, 100]
+pub struct AroonOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    aroon_up: AroonUpIndicator<T, S>,
+    aroon_down: AroonDownIndicator<T, S>,
+}
+
+impl<T, S> Clone for AroonOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            aroon_up: self.aroon_up.clone(),
+            aroon_down: self.aroon_down.clone(),
+        }
+    }
+}
+
+impl<T, S> AroonOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，直接传入 BarSeriesRef
+    pub fn new(series_ref: BarSeriesRef<S>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Ok(Self {
+            aroon_up: AroonUpIndicator::new(series_ref.clone(), bar_count)?,
+            aroon_down: AroonDownIndicator::new(series_ref, bar_count)?,
+        })
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), bar_count)
+    }
+
+    pub fn aroon_up(&self) -> &AroonUpIndicator<T, S> {
+        &self.aroon_up
+    }
+
+    pub fn aroon_down(&self) -> &AroonDownIndicator<T, S> {
+        &self.aroon_down
+    }
+}
+
+impl<T, S> Indicator for AroonOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        let up = self.aroon_up.get_value(index)?;
+        let down = self.aroon_down.get_value(index)?;
+        Ok(up.minus(&down))
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.aroon_up.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.aroon_up.count_of_unstable_bars()
+    }
+}
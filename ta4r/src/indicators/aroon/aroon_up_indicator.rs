@@ -0,0 +1,163 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::high_price_indicator::HighPriceIndicator;
+use crate::indicators::helpers::highest_value_indicator::HighestValueIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// AroonUpCalculator：(n - 距最高价的 bar 数) / n * 100
+pub struct AroonUpCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    highest: Arc<HighestValueIndicator<T, S, HighPriceIndicator<T, S>>>,
+    bar_count: usize,
+}
+
+impl<T, S> Clone for AroonUpCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            highest: Arc::clone(&self.highest),
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S> IndicatorCalculator<T, S> for AroonUpCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let bars_since = self.highest.bars_since_highest(index)?;
+        let hundred = T::from_usize(100).unwrap_or_else(T::one);
+        let bar_count = T::from_usize(self.bar_count).unwrap_or_else(T::one);
+        let remaining = T::from_usize(self.bar_count - bars_since).unwrap_or_else(T::zero);
+        Ok(remaining.divided_by(&bar_count)?.multiplied_by(&hundred))
+    }
+}
+
+/// Aroon Up
+///
+/// AroonUp = (n - 最近 n + 1 根 bar 中距最高价的 bar 数) / n * 100，取值范围 [0, 100]
+pub struct AroonUpIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, AroonUpCalculator<T, S>>,
+}
+
+impl<T, S> Clone for AroonUpIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+        }
+    }
+}
+
+impl<T, S> AroonUpIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，直接传入 BarSeriesRef
+    pub fn new(series_ref: BarSeriesRef<S>, bar_count: usize) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+        let high = Arc::new(HighPriceIndicator::new(series_ref.clone()));
+        // 窗口包含当前 bar 与之前的 n 根 bar
+        let calculator = AroonUpCalculator {
+            highest: Arc::new(HighestValueIndicator::new(high, bar_count + 1)),
+            bar_count,
+        };
+        Ok(Self {
+            cached: CachedIndicator::new_from_series(series_ref, calculator),
+        })
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), bar_count)
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.cached.calculator().bar_count
+    }
+}
+
+impl<T, S> Indicator for AroonUpIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.bar_count()
+    }
+}
@@ -27,7 +27,7 @@ use crate::bar::types::BarSeries;
 use crate::indicators::Indicator;
 use crate::indicators::abstract_indicator::BaseIndicator;
 use crate::indicators::recursive_cached_indicator::RecursiveCachedIndicator;
-use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::indicators::types::{IndicatorError, RecursiveIndicatorCalculator};
 use crate::num::TrNum;
 use std::marker::PhantomData;
 use std::sync::Arc;
@@ -59,7 +59,7 @@ where
     }
 }
 
-impl<T, S, I> IndicatorCalculator<T, S> for BaseEmaCalculator<T, S, I>
+impl<T, S, I> RecursiveIndicatorCalculator<T, S> for BaseEmaCalculator<T, S, I>
 where
     T: TrNum + Clone + 'static,
     S: BarSeries<T> + 'static,
@@ -69,17 +69,18 @@ where
 
     fn calculate(
         &self,
-        base: &BaseIndicator<T, S>,
+        _base: &BaseIndicator<T, S>,
         index: usize,
+        previous: Option<&T>,
     ) -> Result<Self::Output, IndicatorError> {
-        if index == 0 {
-            return self.indicator.get_value(0);
-        }
+        // 第一个 bar 没有前值，直接取被包装指标的值
+        let Some(prev) = previous else {
+            return self.indicator.get_value(index);
+        };
 
-        let prev = base.get_value(index - 1)?;
         let current = self.indicator.get_value(index)?;
         let diff = current.clone() - prev.clone();
-        Ok(diff * self.multiplier.clone() + prev)
+        Ok(diff * self.multiplier.clone() + prev.clone())
     }
 }
 
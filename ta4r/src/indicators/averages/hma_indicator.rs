@@ -0,0 +1,125 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::averages::wma_indicator::WmaIndicator;
+use crate::indicators::numeric::binary_operation::BinaryOperation;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use std::sync::Arc;
+
+/// 2 * WMA(n / 2) - WMA(n)
+type HmaRawIndicator<T, S, I> = BinaryOperation<T, WmaIndicator<T, S, I>, WmaIndicator<T, S, I>>;
+
+/// 赫尔移动平均（HMA）
+///
+/// HMA = WMA(2 * WMA(n / 2) - WMA(n), sqrt(n))，不稳定 bar 数为输入的不稳定 bar 数加 n（与 ta4j 一致）
+pub struct HmaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S> + 'static,
+{
+    hma: WmaIndicator<T, S, HmaRawIndicator<T, S, I>>,
+    unstable_bars: usize,
+    bar_count: usize,
+}
+
+impl<T, S, I> Clone for HmaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            hma: self.hma.clone(),
+            unstable_bars: self.unstable_bars,
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S, I> HmaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S> + 'static,
+{
+    /// bar_count 至少为 2，以保证 n / 2 的周期有效
+    pub fn new(indicator: Arc<I>, bar_count: usize) -> Result<Self, IndicatorError> {
+        if bar_count < 2 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be at least 2".to_string(),
+            });
+        }
+
+        let half_wma = Arc::new(WmaIndicator::new(Arc::clone(&indicator), bar_count / 2));
+        let full_wma = Arc::new(WmaIndicator::new(Arc::clone(&indicator), bar_count));
+        let unstable_bars = indicator.count_of_unstable_bars() + bar_count;
+        let raw = Arc::new(BinaryOperation::new_simple(
+            half_wma,
+            full_wma,
+            |half, full| half.plus(half).minus(full),
+        ));
+
+        let sqrt_bar_count = (bar_count as f64).sqrt() as usize;
+        let hma = WmaIndicator::new(raw, sqrt_bar_count);
+        Ok(Self {
+            hma,
+            unstable_bars,
+            bar_count,
+        })
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+}
+
+impl<T, S, I> Indicator for HmaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.hma.get_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.hma.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -0,0 +1,207 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::recursive_cached_indicator::RecursiveCachedIndicator;
+use crate::indicators::types::{IndicatorError, RecursiveIndicatorCalculator};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// KamaCalculator：按效率系数调整平滑常数的递推平均
+pub struct KamaCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    indicator: Arc<I>,
+    effective_ratio_bar_count: usize,
+    fastest: T,
+    slowest: T,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for KamaCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            indicator: Arc::clone(&self.indicator),
+            effective_ratio_bar_count: self.effective_ratio_bar_count,
+            fastest: self.fastest.clone(),
+            slowest: self.slowest.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> RecursiveIndicatorCalculator<T, S> for KamaCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+        previous: Option<&T>,
+    ) -> Result<Self::Output, IndicatorError> {
+        let current = self.indicator.get_value(index)?;
+        // 窗口未填满前直接取输入值
+        let Some(prior_kama) = previous.filter(|_| index >= self.effective_ratio_bar_count) else {
+            return Ok(current);
+        };
+
+        let start = index - self.effective_ratio_bar_count;
+        let change = current.minus(&self.indicator.get_value(start)?).abs();
+        let mut volatility = T::zero();
+        let mut last = self.indicator.get_value(start)?;
+        for i in (start + 1)..=index {
+            let value = self.indicator.get_value(i)?;
+            volatility = volatility.plus(&value.minus(&last).abs());
+            last = value;
+        }
+
+        // 窗口内没有波动时效率系数取 0，按最慢的平滑常数推进
+        let efficiency_ratio = if volatility.is_zero() {
+            T::zero()
+        } else {
+            change.divided_by(&volatility)?
+        };
+        let smoothing = efficiency_ratio
+            .multiplied_by(&self.fastest.minus(&self.slowest))
+            .plus(&self.slowest)
+            .pow(2)?;
+
+        Ok(prior_kama.plus(&smoothing.multiplied_by(&current.minus(prior_kama))))
+    }
+}
+
+/// 考夫曼自适应移动平均（KAMA）
+///
+/// - ER = |price - price[n]| / sum(|price[i] - price[i-1]|)
+/// - SC = (ER * (fastest - slowest) + slowest)^2，fastest = 2 / (fast + 1)，slowest = 2 / (slow + 1)
+/// - KAMA = KAMA[-1] + SC * (price - KAMA[-1])
+pub struct KamaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    inner: RecursiveCachedIndicator<T, S, KamaCalculator<T, S, I>>,
+    unstable_bars: usize,
+}
+
+impl<T, S, I> Clone for KamaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I> KamaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 默认参数构造：效率系数周期 10，快线 2，慢线 30
+    pub fn with_defaults(indicator: Arc<I>) -> Result<Self, IndicatorError> {
+        Self::new(indicator, 10, 2, 30)
+    }
+
+    pub fn new(
+        indicator: Arc<I>,
+        effective_ratio_bar_count: usize,
+        fast_bar_count: usize,
+        slow_bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        if effective_ratio_bar_count == 0 || fast_bar_count == 0 || slow_bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+
+        let two = T::from_usize(2).unwrap_or_else(T::one);
+        let smoothing_constant =
+            |bar_count: usize| two.divided_by(&T::from_usize(bar_count + 1).unwrap_or_else(T::one));
+        let calculator = KamaCalculator {
+            indicator: Arc::clone(&indicator),
+            effective_ratio_bar_count,
+            fastest: smoothing_constant(fast_bar_count)?,
+            slowest: smoothing_constant(slow_bar_count)?,
+            _phantom: PhantomData,
+        };
+        let unstable_bars = indicator.count_of_unstable_bars() + effective_ratio_bar_count;
+        Ok(Self {
+            inner: RecursiveCachedIndicator::from_indicator(indicator, calculator),
+            unstable_bars,
+        })
+    }
+
+    pub fn effective_ratio_bar_count(&self) -> usize {
+        self.inner.calculator().effective_ratio_bar_count
+    }
+}
+
+impl<T, S, I> Indicator for KamaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.inner.get_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.inner.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
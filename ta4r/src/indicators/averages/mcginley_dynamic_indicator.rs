@@ -0,0 +1,173 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::recursive_cached_indicator::RecursiveCachedIndicator;
+use crate::indicators::types::{IndicatorError, RecursiveIndicatorCalculator};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// McGinleyDynamicCalculator：MD[-1] + (price - MD[-1]) / (n * (price / MD[-1])^4)
+pub struct McGinleyDynamicCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    indicator: Arc<I>,
+    bar_count: T,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for McGinleyDynamicCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            indicator: Arc::clone(&self.indicator),
+            bar_count: self.bar_count.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> RecursiveIndicatorCalculator<T, S> for McGinleyDynamicCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+        previous: Option<&T>,
+    ) -> Result<Self::Output, IndicatorError> {
+        let current = self.indicator.get_value(index)?;
+        // 第一个值或前值为 0 时无法计算比例，直接取输入值
+        let Some(prev) = previous.filter(|prev| !prev.is_zero()) else {
+            return Ok(current);
+        };
+
+        let ratio = current.divided_by(prev)?.pow(4)?;
+        let divisor = self.bar_count.multiplied_by(&ratio);
+        if divisor.is_zero() {
+            return Ok(current);
+        }
+        Ok(prev.plus(&current.minus(prev).divided_by(&divisor)?))
+    }
+}
+
+/// McGinley 动态均线
+///
+/// 价格远离均线时自动加快跟随速度，价格下跌时比上涨时跟随得更快
+pub struct McGinleyDynamicIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    inner: RecursiveCachedIndicator<T, S, McGinleyDynamicCalculator<T, S, I>>,
+    unstable_bars: usize,
+    bar_count: usize,
+}
+
+impl<T, S, I> Clone for McGinleyDynamicIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            unstable_bars: self.unstable_bars,
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S, I> McGinleyDynamicIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    pub fn new(indicator: Arc<I>, bar_count: usize) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+
+        let calculator = McGinleyDynamicCalculator {
+            indicator: Arc::clone(&indicator),
+            bar_count: T::from_usize(bar_count).unwrap_or_else(T::one),
+            _phantom: PhantomData,
+        };
+        let unstable_bars = indicator.count_of_unstable_bars() + bar_count;
+        Ok(Self {
+            inner: RecursiveCachedIndicator::from_indicator(indicator, calculator),
+            unstable_bars,
+            bar_count,
+        })
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+}
+
+impl<T, S, I> Indicator for McGinleyDynamicIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.inner.get_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.inner.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -23,7 +23,15 @@
  * SOFTWARE.
  */
 pub mod base_ema_indicator;
+pub mod dema_indicator;
 pub mod ema_indicator;
+pub mod hma_indicator;
+pub mod kama_indicator;
+pub mod mcginley_dynamic_indicator;
 pub mod mma_indicator;
 pub mod sma_indicator;
+pub mod t3_indicator;
+pub mod tema_indicator;
+pub mod vidya_indicator;
 pub mod wma_indicator;
+pub mod zlema_indicator;
@@ -0,0 +1,231 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::averages::ema_indicator::EmaIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+type Ema2<T, S, I> = EmaIndicator<T, S, EmaIndicator<T, S, I>>;
+type Ema3<T, S, I> = EmaIndicator<T, S, Ema2<T, S, I>>;
+type Ema4<T, S, I> = EmaIndicator<T, S, Ema3<T, S, I>>;
+type Ema5<T, S, I> = EmaIndicator<T, S, Ema4<T, S, I>>;
+type Ema6<T, S, I> = EmaIndicator<T, S, Ema5<T, S, I>>;
+
+/// T3Calculator：c1 * e6 + c2 * e5 + c3 * e4 + c4 * e3
+pub struct T3Calculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    e3: Arc<Ema3<T, S, I>>,
+    e4: Arc<Ema4<T, S, I>>,
+    e5: Arc<Ema5<T, S, I>>,
+    e6: Arc<Ema6<T, S, I>>,
+    coefficients: [T; 4],
+    volume_factor: T,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for T3Calculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            e3: Arc::clone(&self.e3),
+            e4: Arc::clone(&self.e4),
+            e5: Arc::clone(&self.e5),
+            e6: Arc::clone(&self.e6),
+            coefficients: self.coefficients.clone(),
+            volume_factor: self.volume_factor.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for T3Calculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let [c1, c2, c3, c4] = &self.coefficients;
+        Ok(c1
+            .multiplied_by(&self.e6.get_value(index)?)
+            .plus(&c2.multiplied_by(&self.e5.get_value(index)?))
+            .plus(&c3.multiplied_by(&self.e4.get_value(index)?))
+            .plus(&c4.multiplied_by(&self.e3.get_value(index)?)))
+    }
+}
+
+/// Tillson T3 移动平均
+///
+/// 六层 EMA 的加权组合，a 为 volume factor（默认 0.7）：
+/// - c1 = -a³，c2 = 3a² + 3a³，c3 = -6a² - 3a - 3a³，c4 = 1 + 3a + a³ + 3a²
+/// - T3 = c1 * e6 + c2 * e5 + c3 * e4 + c4 * e3
+///
+/// 不稳定 bar 数为输入的不稳定 bar 数加 n，与 DEMA、TEMA 一致
+pub struct T3Indicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, T3Calculator<T, S, I>>,
+    unstable_bars: usize,
+    bar_count: usize,
+}
+
+impl<T, S, I> Clone for T3Indicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S, I> T3Indicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 使用默认 volume factor 0.7 构造
+    pub fn with_default_volume_factor(
+        indicator: Arc<I>,
+        bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        let volume_factor = indicator
+            .bar_series()
+            .with_ref(|s| s.num_factory().num_of_f64(0.7))?;
+        Self::new(indicator, bar_count, volume_factor)
+    }
+
+    pub fn new(
+        indicator: Arc<I>,
+        bar_count: usize,
+        volume_factor: T,
+    ) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+
+        let e1 = Arc::new(EmaIndicator::new(Arc::clone(&indicator), bar_count));
+        let e2 = Arc::new(EmaIndicator::new(Arc::clone(&e1), bar_count));
+        let e3 = Arc::new(EmaIndicator::new(Arc::clone(&e2), bar_count));
+        let e4 = Arc::new(EmaIndicator::new(Arc::clone(&e3), bar_count));
+        let e5 = Arc::new(EmaIndicator::new(Arc::clone(&e4), bar_count));
+        let e6 = Arc::new(EmaIndicator::new(Arc::clone(&e5), bar_count));
+        let unstable_bars = indicator.count_of_unstable_bars() + bar_count;
+
+        let a = volume_factor.clone();
+        let a2 = a.multiplied_by(&a);
+        let a3 = a2.multiplied_by(&a);
+        let three = T::from_usize(3).unwrap_or_else(T::one);
+        let six = T::from_usize(6).unwrap_or_else(T::one);
+        let c1 = -a3.clone();
+        let c2 = three.multiplied_by(&a2).plus(&three.multiplied_by(&a3));
+        let c3 = -six
+            .multiplied_by(&a2)
+            .plus(&three.multiplied_by(&a))
+            .plus(&three.multiplied_by(&a3));
+        let c4 = T::one()
+            .plus(&three.multiplied_by(&a))
+            .plus(&a3)
+            .plus(&three.multiplied_by(&a2));
+
+        let calculator = T3Calculator {
+            e3,
+            e4,
+            e5,
+            e6,
+            coefficients: [c1, c2, c3, c4],
+            volume_factor,
+            _phantom: PhantomData,
+        };
+        Ok(Self {
+            cached: CachedIndicator::new_from_indicator(indicator, calculator),
+            unstable_bars,
+            bar_count,
+        })
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+
+    pub fn volume_factor(&self) -> &T {
+        &self.cached.calculator().volume_factor
+    }
+}
+
+impl<T, S, I> Indicator for T3Indicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
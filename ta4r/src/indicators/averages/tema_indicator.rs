@@ -0,0 +1,174 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::averages::ema_indicator::EmaIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+type EmaEmaIndicator<T, S, I> = EmaIndicator<T, S, EmaIndicator<T, S, I>>;
+
+/// TemaCalculator：3 * EMA - 3 * EMA(EMA) + EMA(EMA(EMA))
+pub struct TemaCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    ema: Arc<EmaIndicator<T, S, I>>,
+    ema_ema: Arc<EmaEmaIndicator<T, S, I>>,
+    ema_ema_ema: Arc<EmaIndicator<T, S, EmaEmaIndicator<T, S, I>>>,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for TemaCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            ema: Arc::clone(&self.ema),
+            ema_ema: Arc::clone(&self.ema_ema),
+            ema_ema_ema: Arc::clone(&self.ema_ema_ema),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for TemaCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let ema = self.ema.get_value(index)?;
+        let ema_ema = self.ema_ema.get_value(index)?;
+        let ema_ema_ema = self.ema_ema_ema.get_value(index)?;
+        let three = T::from_usize(3).unwrap_or_else(T::one);
+        Ok(three.multiplied_by(&ema.minus(&ema_ema)).plus(&ema_ema_ema))
+    }
+}
+
+/// 三重指数移动平均（TEMA）
+///
+/// TEMA = 3 * EMA - 3 * EMA(EMA) + EMA(EMA(EMA))，不稳定 bar 数为输入的不稳定 bar 数加 n（与 ta4j 一致）
+pub struct TemaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, TemaCalculator<T, S, I>>,
+    unstable_bars: usize,
+    bar_count: usize,
+}
+
+impl<T, S, I> Clone for TemaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S, I> TemaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    pub fn new(indicator: Arc<I>, bar_count: usize) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+
+        let ema = Arc::new(EmaIndicator::new(Arc::clone(&indicator), bar_count));
+        let ema_ema = Arc::new(EmaIndicator::new(Arc::clone(&ema), bar_count));
+        let ema_ema_ema = Arc::new(EmaIndicator::new(Arc::clone(&ema_ema), bar_count));
+        let unstable_bars = indicator.count_of_unstable_bars() + bar_count;
+        let calculator = TemaCalculator {
+            ema,
+            ema_ema,
+            ema_ema_ema,
+            _phantom: PhantomData,
+        };
+        Ok(Self {
+            cached: CachedIndicator::new_from_indicator(indicator, calculator),
+            unstable_bars,
+            bar_count,
+        })
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+}
+
+impl<T, S, I> Indicator for TemaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
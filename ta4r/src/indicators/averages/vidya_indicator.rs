@@ -0,0 +1,197 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cmo_indicator::CmoIndicator;
+use crate::indicators::recursive_cached_indicator::RecursiveCachedIndicator;
+use crate::indicators::types::{IndicatorError, RecursiveIndicatorCalculator};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// VidyaCalculator：以 |CMO| 调整 EMA 平滑系数
+pub struct VidyaCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    indicator: Arc<I>,
+    cmo: Arc<CmoIndicator<T, S, I>>,
+    alpha: T,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for VidyaCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            indicator: Arc::clone(&self.indicator),
+            cmo: Arc::clone(&self.cmo),
+            alpha: self.alpha.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> RecursiveIndicatorCalculator<T, S> for VidyaCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+        previous: Option<&T>,
+    ) -> Result<Self::Output, IndicatorError> {
+        let current = self.indicator.get_value(index)?;
+        let Some(prev) = previous else {
+            return Ok(current);
+        };
+
+        // CMO 以百分比表示，换算为 [0, 1] 的比例
+        let hundred = T::from_usize(100).unwrap_or_else(T::one);
+        let cmo = self.cmo.get_value(index)?.abs().divided_by(&hundred)?;
+
+        let factor = self.alpha.multiplied_by(&cmo);
+        Ok(prev.plus(&factor.multiplied_by(&current.minus(prev))))
+    }
+}
+
+/// 可变指数动态平均（VIDYA，Chande）
+///
+/// VIDYA = VIDYA[-1] + α * |CMO| / 100 * (price - VIDYA[-1])，α = 2 / (n + 1)
+pub struct VidyaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    inner: RecursiveCachedIndicator<T, S, VidyaCalculator<T, S, I>>,
+    unstable_bars: usize,
+    bar_count: usize,
+    cmo_bar_count: usize,
+}
+
+impl<T, S, I> Clone for VidyaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            unstable_bars: self.unstable_bars,
+            bar_count: self.bar_count,
+            cmo_bar_count: self.cmo_bar_count,
+        }
+    }
+}
+
+impl<T, S, I> VidyaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 使用默认的 CMO 周期 9 构造
+    pub fn with_default_cmo(indicator: Arc<I>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(indicator, bar_count, 9)
+    }
+
+    pub fn new(
+        indicator: Arc<I>,
+        bar_count: usize,
+        cmo_bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        if bar_count == 0 || cmo_bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+
+        let alpha = T::from_usize(2)
+            .unwrap_or_else(T::one)
+            .divided_by(&T::from_usize(bar_count + 1).unwrap_or_else(T::one))?;
+        let cmo = CmoIndicator::new(Arc::clone(&indicator), cmo_bar_count)?;
+        // 与其他组合均线一致，不稳定 bar 数为输入的不稳定 bar 数加 n
+        let unstable_bars = indicator.count_of_unstable_bars() + bar_count;
+        let calculator = VidyaCalculator {
+            indicator: Arc::clone(&indicator),
+            cmo: Arc::new(cmo),
+            alpha,
+            _phantom: PhantomData,
+        };
+        Ok(Self {
+            inner: RecursiveCachedIndicator::from_indicator(indicator, calculator),
+            unstable_bars,
+            bar_count,
+            cmo_bar_count,
+        })
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+
+    pub fn cmo_bar_count(&self) -> usize {
+        self.cmo_bar_count
+    }
+}
+
+impl<T, S, I> Indicator for VidyaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.inner.get_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.inner.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
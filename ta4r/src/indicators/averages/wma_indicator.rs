@@ -105,13 +105,12 @@ where
         };
 
         let mut weighted_sum = zero.clone();
-        let mut actual_index = index;
 
+        // 权重 loop_len 对应当前 bar，依次向前递减到 1
         for i in (1..=loop_len).rev() {
             let weight = num_factory.num_of_i64(i as i64);
-            let value = self.indicator.get_value(actual_index)?;
+            let value = self.indicator.get_value(index + i - loop_len)?;
             weighted_sum = weighted_sum.plus(&weight.multiplied_by(&value));
-            actual_index -= 1;
         }
 
         let denominator = (loop_len * (loop_len + 1)) / 2;
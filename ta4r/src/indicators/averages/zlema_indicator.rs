@@ -0,0 +1,183 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::recursive_cached_indicator::RecursiveCachedIndicator;
+use crate::indicators::types::{IndicatorError, RecursiveIndicatorCalculator};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// ZlemaCalculator：对去滞后的输入 2 * price - price[lag] 做 EMA
+pub struct ZlemaCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    indicator: Arc<I>,
+    lag: usize,
+    multiplier: T,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for ZlemaCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            indicator: Arc::clone(&self.indicator),
+            lag: self.lag,
+            multiplier: self.multiplier.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> RecursiveIndicatorCalculator<T, S> for ZlemaCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+        previous: Option<&T>,
+    ) -> Result<Self::Output, IndicatorError> {
+        let current = self.indicator.get_value(index)?;
+        let Some(prev) = previous else {
+            return Ok(current);
+        };
+
+        // 不足 lag 根 bar 时没有滞后值，直接使用当前值
+        let delagged = if index >= self.lag {
+            current
+                .plus(&current)
+                .minus(&self.indicator.get_value(index - self.lag)?)
+        } else {
+            current
+        };
+        Ok(prev.plus(&self.multiplier.multiplied_by(&delagged.minus(prev))))
+    }
+}
+
+/// 零滞后指数移动平均（ZLEMA）
+///
+/// ZLEMA = EMA(2 * price - price[lag], n)，lag = (n - 1) / 2
+pub struct ZlemaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    inner: RecursiveCachedIndicator<T, S, ZlemaCalculator<T, S, I>>,
+    unstable_bars: usize,
+    bar_count: usize,
+}
+
+impl<T, S, I> Clone for ZlemaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            unstable_bars: self.unstable_bars,
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S, I> ZlemaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    pub fn new(indicator: Arc<I>, bar_count: usize) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+
+        let lag = (bar_count - 1) / 2;
+        let multiplier = T::from_usize(2)
+            .unwrap_or_else(T::one)
+            .divided_by(&T::from_usize(bar_count + 1).unwrap_or_else(T::one))?;
+        let calculator = ZlemaCalculator {
+            indicator: Arc::clone(&indicator),
+            lag,
+            multiplier,
+            _phantom: PhantomData,
+        };
+        // 与 ta4j 一致，不稳定 bar 数不计入 lag
+        let unstable_bars = indicator.count_of_unstable_bars() + bar_count;
+        Ok(Self {
+            inner: RecursiveCachedIndicator::from_indicator(indicator, calculator),
+            unstable_bars,
+            bar_count,
+        })
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+}
+
+impl<T, S, I> Indicator for ZlemaIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.inner.get_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.inner.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
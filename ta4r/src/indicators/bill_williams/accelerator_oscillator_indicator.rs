@@ -0,0 +1,175 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::averages::sma_indicator::SmaIndicator;
+use crate::indicators::bill_williams::DEFAULT_ACCELERATOR_BAR_COUNT;
+use crate::indicators::bill_williams::awesome_oscillator_indicator::AwesomeOscillatorIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// AcceleratorOscillatorCalculator：AO - SMA(AO, n)
+pub struct AcceleratorOscillatorCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    awesome: Arc<AwesomeOscillatorIndicator<T, S>>,
+    awesome_sma: Arc<SmaIndicator<T, S, AwesomeOscillatorIndicator<T, S>>>,
+}
+
+impl<T, S> Clone for AcceleratorOscillatorCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            awesome: Arc::clone(&self.awesome),
+            awesome_sma: Arc::clone(&self.awesome_sma),
+        }
+    }
+}
+
+impl<T, S> IndicatorCalculator<T, S> for AcceleratorOscillatorCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let awesome = self.awesome.get_value(index)?;
+        Ok(awesome.minus(&self.awesome_sma.get_value(index)?))
+    }
+}
+
+/// 加速震荡指标（Accelerator Oscillator, AC）
+///
+/// AC = AO - SMA(AO, n)，默认 AO 周期 (5, 34)、n = 5，用于衡量 AO 动量的加速或减速
+pub struct AcceleratorOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, AcceleratorOscillatorCalculator<T, S>>,
+    bar_count: usize,
+}
+
+impl<T, S> Clone for AcceleratorOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S> AcceleratorOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 使用默认周期构造
+    pub fn new(series_ref: BarSeriesRef<S>) -> Result<Self, IndicatorError> {
+        let awesome = AwesomeOscillatorIndicator::new(series_ref)?;
+        Self::from_awesome(Arc::new(awesome), DEFAULT_ACCELERATOR_BAR_COUNT)
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series))
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series))
+    }
+
+    /// 基于已有的 AO 构造，bar_count 为对 AO 取均值的周期
+    pub fn from_awesome(
+        awesome: Arc<AwesomeOscillatorIndicator<T, S>>,
+        bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+        let calculator = AcceleratorOscillatorCalculator {
+            awesome: Arc::clone(&awesome),
+            awesome_sma: Arc::new(SmaIndicator::new(Arc::clone(&awesome), bar_count)),
+        };
+        Ok(Self {
+            cached: CachedIndicator::new_from_indicator(awesome, calculator),
+            bar_count,
+        })
+    }
+
+    pub fn awesome(&self) -> Arc<AwesomeOscillatorIndicator<T, S>> {
+        Arc::clone(&self.cached.calculator().awesome)
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+}
+
+impl<T, S> Indicator for AcceleratorOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.cached.calculator().awesome.count_of_unstable_bars() + self.bar_count - 1
+    }
+}
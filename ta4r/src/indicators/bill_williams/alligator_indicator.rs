@@ -0,0 +1,149 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::averages::mma_indicator::MMAIndicator;
+use crate::indicators::bill_williams::AlligatorLine;
+use crate::indicators::helpers::displaced_index;
+use crate::indicators::helpers::median_price_indicator::MedianPriceIndicator;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// 中间价的平滑移动平均（SMMA，即 MMA）
+pub type MedianPriceMmaIndicator<T, S> = MMAIndicator<T, S, MedianPriceIndicator<T, S>>;
+
+/// 鳄鱼线（Alligator）中的一条线
+///
+/// - 中间价 (high + low) / 2 的 SMMA(n)，向前平移 shift 根 bar 显示
+/// - index 处的值为 shift 根 bar 之前计算出的 SMMA，只使用 index 及之前的数据；
+///   index < shift 时没有可用的值，返回 `IndicatorError::NotAvailable`
+/// - `leading_value(index)` 返回 index 处计算、将显示在 index + shift 处的值
+pub struct AlligatorLineIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    smma: Arc<MedianPriceMmaIndicator<T, S>>,
+    bar_count: usize,
+    shift: usize,
+}
+
+impl<T, S> Clone for AlligatorLineIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            smma: Arc::clone(&self.smma),
+            bar_count: self.bar_count,
+            shift: self.shift,
+        }
+    }
+}
+
+impl<T, S> AlligatorLineIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 使用默认周期与平移构造下颚、牙齿或嘴唇
+    pub fn new(series_ref: BarSeriesRef<S>, line: AlligatorLine) -> Result<Self, IndicatorError> {
+        Self::with_periods(series_ref, line.bar_count(), line.shift())
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(
+        series: Arc<RwLock<S>>,
+        line: AlligatorLine,
+    ) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), line)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, line: AlligatorLine) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), line)
+    }
+
+    /// 自定义平滑周期与平移 bar 数
+    pub fn with_periods(
+        series_ref: BarSeriesRef<S>,
+        bar_count: usize,
+        shift: usize,
+    ) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+        let median = Arc::new(MedianPriceIndicator::new(series_ref));
+        let smma = MMAIndicator::new(median, bar_count)?;
+        Ok(Self {
+            smma: Arc::new(smma),
+            bar_count,
+            shift,
+        })
+    }
+
+    /// index 处计算、向前平移后显示在 index + shift 处的值
+    pub fn leading_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.smma.get_value(index)
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+
+    pub fn shift(&self) -> usize {
+        self.shift
+    }
+}
+
+impl<T, S> Indicator for AlligatorLineIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.smma.get_value(displaced_index(index, self.shift)?)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.smma.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.smma.count_of_unstable_bars() + self.shift
+    }
+}
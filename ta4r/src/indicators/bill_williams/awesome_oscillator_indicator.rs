@@ -0,0 +1,191 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::averages::sma_indicator::SmaIndicator;
+use crate::indicators::bill_williams::{
+    DEFAULT_AWESOME_LONG_BAR_COUNT, DEFAULT_AWESOME_SHORT_BAR_COUNT,
+};
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::median_price_indicator::MedianPriceIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// 中间价的简单移动平均
+pub type MedianPriceSmaIndicator<T, S> = SmaIndicator<T, S, MedianPriceIndicator<T, S>>;
+
+/// AwesomeOscillatorCalculator：短周期 SMA - 长周期 SMA
+pub struct AwesomeOscillatorCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    short_term_sma: Arc<MedianPriceSmaIndicator<T, S>>,
+    long_term_sma: Arc<MedianPriceSmaIndicator<T, S>>,
+}
+
+impl<T, S> Clone for AwesomeOscillatorCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            short_term_sma: Arc::clone(&self.short_term_sma),
+            long_term_sma: Arc::clone(&self.long_term_sma),
+        }
+    }
+}
+
+impl<T, S> IndicatorCalculator<T, S> for AwesomeOscillatorCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let short_term = self.short_term_sma.get_value(index)?;
+        let long_term = self.long_term_sma.get_value(index)?;
+        Ok(short_term.minus(&long_term))
+    }
+}
+
+/// 动量震荡指标（Awesome Oscillator, AO）
+///
+/// AO = SMA(中间价, short) - SMA(中间价, long)，默认周期 (5, 34)
+pub struct AwesomeOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, AwesomeOscillatorCalculator<T, S>>,
+    long_bar_count: usize,
+}
+
+impl<T, S> Clone for AwesomeOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            long_bar_count: self.long_bar_count,
+        }
+    }
+}
+
+impl<T, S> AwesomeOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 使用默认周期 (5, 34) 构造
+    pub fn new(series_ref: BarSeriesRef<S>) -> Result<Self, IndicatorError> {
+        Self::with_periods(
+            series_ref,
+            DEFAULT_AWESOME_SHORT_BAR_COUNT,
+            DEFAULT_AWESOME_LONG_BAR_COUNT,
+        )
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series))
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series))
+    }
+
+    /// 自定义周期，长周期必须大于短周期
+    pub fn with_periods(
+        series_ref: BarSeriesRef<S>,
+        short_bar_count: usize,
+        long_bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        if short_bar_count == 0 || short_bar_count >= long_bar_count {
+            return Err(IndicatorError::CalculationError {
+                message: format!(
+                    "Long term period count ({}) must be greater than short term period count ({})",
+                    long_bar_count, short_bar_count
+                ),
+            });
+        }
+
+        let median = Arc::new(MedianPriceIndicator::new(series_ref));
+        let calculator = AwesomeOscillatorCalculator {
+            short_term_sma: Arc::new(SmaIndicator::new(Arc::clone(&median), short_bar_count)),
+            long_term_sma: Arc::new(SmaIndicator::new(Arc::clone(&median), long_bar_count)),
+        };
+        Ok(Self {
+            cached: CachedIndicator::new_from_indicator(median, calculator),
+            long_bar_count,
+        })
+    }
+
+    pub fn short_term_sma(&self) -> Arc<MedianPriceSmaIndicator<T, S>> {
+        Arc::clone(&self.cached.calculator().short_term_sma)
+    }
+
+    pub fn long_term_sma(&self) -> Arc<MedianPriceSmaIndicator<T, S>> {
+        Arc::clone(&self.cached.calculator().long_term_sma)
+    }
+}
+
+impl<T, S> Indicator for AwesomeOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    /// 由长周期 SMA 决定
+    fn count_of_unstable_bars(&self) -> usize {
+        self.long_bar_count
+    }
+}
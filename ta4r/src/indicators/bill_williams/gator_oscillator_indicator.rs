@@ -0,0 +1,142 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::bill_williams::AlligatorLine;
+use crate::indicators::bill_williams::alligator_indicator::AlligatorLineIndicator;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use std::sync::Arc;
+
+/// 鳄鱼震荡指标（Gator Oscillator）
+///
+/// - 上方柱 = |下颚 - 牙齿|，下方柱 = -|牙齿 - 嘴唇|
+/// - 两侧柱体同时放大表示鳄鱼“进食”（趋势展开），同时收缩表示“沉睡”（盘整）
+pub struct GatorOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    slower: Arc<AlligatorLineIndicator<T, S>>,
+    faster: Arc<AlligatorLineIndicator<T, S>>,
+    upper: bool,
+}
+
+impl<T, S> Clone for GatorOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            slower: Arc::clone(&self.slower),
+            faster: Arc::clone(&self.faster),
+            upper: self.upper,
+        }
+    }
+}
+
+impl<T, S> GatorOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 使用默认鳄鱼线构造上方柱 |下颚 - 牙齿|
+    pub fn upper(series_ref: BarSeriesRef<S>) -> Result<Self, IndicatorError> {
+        let jaw = AlligatorLineIndicator::new(series_ref.clone(), AlligatorLine::Jaw)?;
+        let teeth = AlligatorLineIndicator::new(series_ref, AlligatorLine::Teeth)?;
+        Ok(Self::upper_from_lines(Arc::new(jaw), Arc::new(teeth)))
+    }
+
+    /// 使用默认鳄鱼线构造下方柱 -|牙齿 - 嘴唇|
+    pub fn lower(series_ref: BarSeriesRef<S>) -> Result<Self, IndicatorError> {
+        let teeth = AlligatorLineIndicator::new(series_ref.clone(), AlligatorLine::Teeth)?;
+        let lips = AlligatorLineIndicator::new(series_ref, AlligatorLine::Lips)?;
+        Ok(Self::lower_from_lines(Arc::new(teeth), Arc::new(lips)))
+    }
+
+    /// 基于已有的下颚与牙齿构造上方柱
+    pub fn upper_from_lines(
+        jaw: Arc<AlligatorLineIndicator<T, S>>,
+        teeth: Arc<AlligatorLineIndicator<T, S>>,
+    ) -> Self {
+        Self {
+            slower: jaw,
+            faster: teeth,
+            upper: true,
+        }
+    }
+
+    /// 基于已有的牙齿与嘴唇构造下方柱
+    pub fn lower_from_lines(
+        teeth: Arc<AlligatorLineIndicator<T, S>>,
+        lips: Arc<AlligatorLineIndicator<T, S>>,
+    ) -> Self {
+        Self {
+            slower: teeth,
+            faster: lips,
+            upper: false,
+        }
+    }
+
+    pub fn is_upper(&self) -> bool {
+        self.upper
+    }
+}
+
+impl<T, S> Indicator for GatorOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        let spread = self
+            .slower
+            .get_value(index)?
+            .minus(&self.faster.get_value(index)?)
+            .abs();
+        if self.upper {
+            Ok(spread)
+        } else {
+            Ok(T::zero().minus(&spread))
+        }
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.slower.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.slower
+            .count_of_unstable_bars()
+            .max(self.faster.count_of_unstable_bars())
+    }
+}
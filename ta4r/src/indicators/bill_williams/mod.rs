@@ -0,0 +1,67 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+pub mod accelerator_oscillator_indicator;
+pub mod alligator_indicator;
+pub mod awesome_oscillator_indicator;
+pub mod gator_oscillator_indicator;
+
+/// 鳄鱼线的三条线
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlligatorLine {
+    /// 下颚（蓝线）：SMMA(13) 向前平移 8 根 bar
+    Jaw,
+    /// 牙齿（红线）：SMMA(8) 向前平移 5 根 bar
+    Teeth,
+    /// 嘴唇（绿线）：SMMA(5) 向前平移 3 根 bar
+    Lips,
+}
+
+impl AlligatorLine {
+    /// 默认的平滑周期
+    pub fn bar_count(&self) -> usize {
+        match self {
+            AlligatorLine::Jaw => 13,
+            AlligatorLine::Teeth => 8,
+            AlligatorLine::Lips => 5,
+        }
+    }
+
+    /// 默认向前平移的 bar 数
+    pub fn shift(&self) -> usize {
+        match self {
+            AlligatorLine::Jaw => 8,
+            AlligatorLine::Teeth => 5,
+            AlligatorLine::Lips => 3,
+        }
+    }
+}
+
+/// 动量震荡指标（AO）的默认短周期
+pub const DEFAULT_AWESOME_SHORT_BAR_COUNT: usize = 5;
+/// 动量震荡指标（AO）的默认长周期
+pub const DEFAULT_AWESOME_LONG_BAR_COUNT: usize = 34;
+/// 加速震荡指标（AC）对 AO 取均值的默认周期
+pub const DEFAULT_ACCELERATOR_BAR_COUNT: usize = 5;
@@ -0,0 +1,168 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::bollinger::bollinger_bands_lower_indicator::BollingerBandsLowerIndicator;
+use crate::indicators::bollinger::bollinger_bands_middle_indicator::BollingerBandsMiddleIndicator;
+use crate::indicators::bollinger::bollinger_bands_upper_indicator::BollingerBandsUpperIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// BollingerBandWidthCalculator：(upper - lower) / middle * 100
+pub struct BollingerBandWidthCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    upper: Arc<BollingerBandsUpperIndicator<T, S, I>>,
+    middle: Arc<BollingerBandsMiddleIndicator<T, S, I>>,
+    lower: Arc<BollingerBandsLowerIndicator<T, S, I>>,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for BollingerBandWidthCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            upper: Arc::clone(&self.upper),
+            middle: Arc::clone(&self.middle),
+            lower: Arc::clone(&self.lower),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for BollingerBandWidthCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let upper = self.upper.get_value(index)?;
+        let middle = self.middle.get_value(index)?;
+        let lower = self.lower.get_value(index)?;
+        let hundred = base
+            .bar_series()
+            .with_ref(|s| s.num_factory().hundred().as_ref().clone())?;
+        Ok(upper
+            .minus(&lower)
+            .divided_by(&middle)?
+            .multiplied_by(&hundred))
+    }
+}
+
+/// 布林带宽度指标（百分比）
+pub struct BollingerBandWidthIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, BollingerBandWidthCalculator<T, S, I>>,
+    unstable_bars: usize,
+}
+
+impl<T, S, I> Clone for BollingerBandWidthIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I> BollingerBandWidthIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    pub fn new(
+        upper: Arc<BollingerBandsUpperIndicator<T, S, I>>,
+        middle: Arc<BollingerBandsMiddleIndicator<T, S, I>>,
+        lower: Arc<BollingerBandsLowerIndicator<T, S, I>>,
+    ) -> Self {
+        let unstable_bars = upper
+            .count_of_unstable_bars()
+            .max(middle.count_of_unstable_bars())
+            .max(lower.count_of_unstable_bars());
+        let calculator = BollingerBandWidthCalculator {
+            upper,
+            middle: Arc::clone(&middle),
+            lower,
+            _phantom: PhantomData,
+        };
+        Self {
+            cached: CachedIndicator::new_from_indicator(middle, calculator),
+            unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I> Indicator for BollingerBandWidthIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
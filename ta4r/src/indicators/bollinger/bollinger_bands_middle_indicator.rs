@@ -0,0 +1,163 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::averages::ema_indicator::EmaIndicator;
+use crate::indicators::averages::sma_indicator::SmaIndicator;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use std::sync::Arc;
+
+/// 中轨使用的移动平均类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MiddleBandType {
+    /// 简单移动平均（标准布林带）
+    #[default]
+    Sma,
+    /// 指数移动平均
+    Ema,
+}
+
+enum MiddleBand<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    Sma(SmaIndicator<T, S, I>),
+    Ema(EmaIndicator<T, S, I>),
+}
+
+impl<T, S, I> Clone for MiddleBand<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        match self {
+            MiddleBand::Sma(sma) => MiddleBand::Sma(sma.clone()),
+            MiddleBand::Ema(ema) => MiddleBand::Ema(ema.clone()),
+        }
+    }
+}
+
+/// 布林带中轨：indicator 的 SMA 或 EMA
+pub struct BollingerBandsMiddleIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    band: MiddleBand<T, S, I>,
+    band_type: MiddleBandType,
+    bar_count: usize,
+}
+
+impl<T, S, I> Clone for BollingerBandsMiddleIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            band: self.band.clone(),
+            band_type: self.band_type,
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S, I> BollingerBandsMiddleIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    pub fn new(indicator: Arc<I>, bar_count: usize, band_type: MiddleBandType) -> Self {
+        let band = match band_type {
+            MiddleBandType::Sma => MiddleBand::Sma(SmaIndicator::new(indicator, bar_count)),
+            MiddleBandType::Ema => MiddleBand::Ema(EmaIndicator::new(indicator, bar_count)),
+        };
+        Self {
+            band,
+            band_type,
+            bar_count,
+        }
+    }
+
+    /// 快捷方式：SMA 中轨
+    pub fn sma(indicator: Arc<I>, bar_count: usize) -> Self {
+        Self::new(indicator, bar_count, MiddleBandType::Sma)
+    }
+
+    /// 快捷方式：EMA 中轨
+    pub fn ema(indicator: Arc<I>, bar_count: usize) -> Self {
+        Self::new(indicator, bar_count, MiddleBandType::Ema)
+    }
+
+    pub fn band_type(&self) -> MiddleBandType {
+        self.band_type
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+}
+
+impl<T, S, I> Indicator for BollingerBandsMiddleIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        match &self.band {
+            MiddleBand::Sma(sma) => sma.get_value(index),
+            MiddleBand::Ema(ema) => ema.get_value(index),
+        }
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        match &self.band {
+            MiddleBand::Sma(sma) => sma.bar_series(),
+            MiddleBand::Ema(ema) => ema.bar_series(),
+        }
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        match &self.band {
+            MiddleBand::Sma(sma) => sma.count_of_unstable_bars(),
+            MiddleBand::Ema(ema) => ema.count_of_unstable_bars(),
+        }
+    }
+}
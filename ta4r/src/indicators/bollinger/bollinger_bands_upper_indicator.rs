@@ -0,0 +1,172 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::bollinger::bollinger_bands_middle_indicator::BollingerBandsMiddleIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::statistics::standard_deviation_indicator::StandardDeviationIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// BollingerBandsUpperCalculator：中轨 + k * 标准差
+pub struct BollingerBandsUpperCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    middle: Arc<BollingerBandsMiddleIndicator<T, S, I>>,
+    deviation: Arc<StandardDeviationIndicator<T, S, I>>,
+    k: T,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for BollingerBandsUpperCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            middle: Arc::clone(&self.middle),
+            deviation: Arc::clone(&self.deviation),
+            k: self.k.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for BollingerBandsUpperCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let middle = self.middle.get_value(index)?;
+        let deviation = self.deviation.get_value(index)?;
+        Ok(middle.plus(&deviation.multiplied_by(&self.k)))
+    }
+}
+
+/// 布林带上轨
+pub struct BollingerBandsUpperIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, BollingerBandsUpperCalculator<T, S, I>>,
+    unstable_bars: usize,
+}
+
+impl<T, S, I> Clone for BollingerBandsUpperIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I> BollingerBandsUpperIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 使用默认倍数 k = 2 构造
+    pub fn with_default_k(
+        middle: Arc<BollingerBandsMiddleIndicator<T, S, I>>,
+        deviation: Arc<StandardDeviationIndicator<T, S, I>>,
+    ) -> Self {
+        let k = T::from_usize(2).unwrap_or_else(T::one);
+        Self::new(middle, deviation, k)
+    }
+
+    pub fn new(
+        middle: Arc<BollingerBandsMiddleIndicator<T, S, I>>,
+        deviation: Arc<StandardDeviationIndicator<T, S, I>>,
+        k: T,
+    ) -> Self {
+        let unstable_bars = middle
+            .count_of_unstable_bars()
+            .max(deviation.count_of_unstable_bars());
+        let calculator = BollingerBandsUpperCalculator {
+            middle: Arc::clone(&middle),
+            deviation,
+            k,
+            _phantom: PhantomData,
+        };
+        Self {
+            cached: CachedIndicator::new_from_indicator(middle, calculator),
+            unstable_bars,
+        }
+    }
+
+    pub fn k(&self) -> &T {
+        &self.cached.calculator().k
+    }
+}
+
+impl<T, S, I> Indicator for BollingerBandsUpperIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -0,0 +1,194 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::bollinger::bollinger_bands_lower_indicator::BollingerBandsLowerIndicator;
+use crate::indicators::bollinger::bollinger_bands_middle_indicator::{
+    BollingerBandsMiddleIndicator, MiddleBandType,
+};
+use crate::indicators::bollinger::bollinger_bands_upper_indicator::BollingerBandsUpperIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::statistics::standard_deviation_indicator::StandardDeviationIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// PercentBCalculator：(x - lower) / (upper - lower)
+pub struct PercentBCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    indicator: Arc<I>,
+    upper: Arc<BollingerBandsUpperIndicator<T, S, I>>,
+    lower: Arc<BollingerBandsLowerIndicator<T, S, I>>,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for PercentBCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            indicator: Arc::clone(&self.indicator),
+            upper: Arc::clone(&self.upper),
+            lower: Arc::clone(&self.lower),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for PercentBCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let value = self.indicator.get_value(index)?;
+        let upper = self.upper.get_value(index)?;
+        let lower = self.lower.get_value(index)?;
+        let width = upper.minus(&lower);
+        if width.is_zero() {
+            // 带宽为 0（窗口内数值完全相同）时视为位于中轨
+            let factory = base.bar_series().with_ref(|s| s.num_factory())?;
+            return Ok(T::one().divided_by(factory.two().as_ref())?);
+        }
+        Ok(value.minus(&lower).divided_by(&width)?)
+    }
+}
+
+/// %B 指标：当前值在布林带中的相对位置
+///
+/// 0 表示位于下轨，1 表示位于上轨
+pub struct PercentBIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, PercentBCalculator<T, S, I>>,
+    unstable_bars: usize,
+}
+
+impl<T, S, I> Clone for PercentBIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I> PercentBIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 以 SMA 中轨、bar_count 周期、k 倍标准差构建完整布林带
+    pub fn new(indicator: Arc<I>, bar_count: usize, k: T) -> Result<Self, IndicatorError> {
+        let deviation = Arc::new(StandardDeviationIndicator::new(
+            Arc::clone(&indicator),
+            bar_count,
+        )?);
+        let middle = Arc::new(BollingerBandsMiddleIndicator::new(
+            Arc::clone(&indicator),
+            bar_count,
+            MiddleBandType::Sma,
+        ));
+        let upper = Arc::new(BollingerBandsUpperIndicator::new(
+            Arc::clone(&middle),
+            Arc::clone(&deviation),
+            k.clone(),
+        ));
+        let lower = Arc::new(BollingerBandsLowerIndicator::new(middle, deviation, k));
+        Ok(Self::from_bands(indicator, upper, lower))
+    }
+
+    /// 复用已有的上下轨
+    pub fn from_bands(
+        indicator: Arc<I>,
+        upper: Arc<BollingerBandsUpperIndicator<T, S, I>>,
+        lower: Arc<BollingerBandsLowerIndicator<T, S, I>>,
+    ) -> Self {
+        let unstable_bars = indicator
+            .count_of_unstable_bars()
+            .max(upper.count_of_unstable_bars())
+            .max(lower.count_of_unstable_bars());
+        let calculator = PercentBCalculator {
+            indicator: Arc::clone(&indicator),
+            upper,
+            lower,
+            _phantom: PhantomData,
+        };
+        Self {
+            cached: CachedIndicator::new_from_indicator(indicator, calculator),
+            unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I> Indicator for PercentBIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -115,23 +115,10 @@ where
         let max_count = series_guard.with_ref_or(usize::MAX, |s| s.get_maximum_bar_count());
         let end_index = series_guard.with_ref_or(None, |s| s.get_end_index());
 
-        // 请求索引在被移除的范围
+        // 请求索引在被移除的范围：按最早的 bar 直接计算
+        // 不改动 highest_result_index，否则已缓存结果会与索引错位
         if index < removed_count {
-            self.increase_length_to(removed_count, max_count);
-            *self.highest_result_index.borrow_mut() = removed_count as isize;
-
-            let mut results = self.results.borrow_mut();
-            if results.is_empty() {
-                results.push(None);
-            }
-
-            if let Some(Some(value)) = results.get(0) {
-                return Ok(value.clone());
-            }
-
-            let val = self.calculate(0)?;
-            results[0] = Some(val.clone());
-            return Ok(val);
+            return self.calculate(0);
         }
 
         // 最新 bar 不缓存
@@ -146,31 +133,27 @@ where
 
         if (index as isize) > *highest_index {
             // 新索引超过缓存最高索引
+            // increase_length_to 已为新索引预留了末尾的槽位
             *highest_index = index as isize;
             let val = self.calculate(index)?;
-
-            if results.len() < max_count {
-                results.push(Some(val.clone()));
-            } else {
-                // 循环缓冲写入
-                let write_pos = results.len() % max_count;
-                results[write_pos] = Some(val.clone());
+            if let Some(slot) = results.last_mut() {
+                *slot = Some(val.clone());
             }
-
             return Ok(val);
         }
 
-        // 索引已缓存，从缓存取值
+        // 缓存末尾对应 highest_index，向前按偏移取值
         let offset = (*highest_index as usize) - index;
-        let inner_index = (results.len() + results.len() - 1 - offset) % results.len();
+        let Some(inner_index) = results.len().checked_sub(offset + 1) else {
+            // 超出缓存窗口，直接计算
+            return self.calculate(index);
+        };
 
         if let Some(Some(value)) = results.get(inner_index) {
             Ok(value.clone())
         } else {
             let val = self.calculate(index)?;
-            if let Some(slot) = results.get_mut(inner_index) {
-                *slot = Some(val.clone());
-            }
+            results[inner_index] = Some(val.clone());
             Ok(val)
         }
     }
@@ -186,11 +169,9 @@ where
             if index > highest_index_usize {
                 // 计算需要新增多少缓存槽
                 let needed = index - highest_index_usize;
-                let current_len = results.len();
 
-                // 限制新增数量，不超过 max_length - current_len
-                let available_space = max_length.saturating_sub(current_len);
-                let add_count = std::cmp::min(needed, available_space);
+                // 新增数量最多为 max_length，超出的旧结果随后移除
+                let add_count = std::cmp::min(needed, max_length);
 
                 if add_count == max_length {
                     // 需要清空缓存，重新分配 max_length 个 None
@@ -0,0 +1,88 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::candles::recent_candles;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// BearishHaramiCalculator：阴线实体位于前一根阳线实体之内
+#[derive(Clone)]
+pub struct BearishHaramiCalculator;
+
+impl<T, S> IndicatorCalculator<T, S> for BearishHaramiCalculator
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = bool;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let Some(candles) = recent_candles(base, index, 2)? else {
+            return Ok(false);
+        };
+        let (previous, current) = (&candles[0], &candles[1]);
+        Ok(previous.is_bullish()
+            && current.is_bearish()
+            && current.open.is_less_than(&previous.body_top())
+            && current.close.is_greater_than(&previous.body_bottom()))
+    }
+}
+
+/// 看跌孕线（Bearish Harami）
+///
+/// 前一根为阳线，当前为阴线，且当前实体完全位于前一根实体之内
+pub type BearishHaramiIndicator<T, S> = CachedIndicator<T, S, BearishHaramiCalculator>;
+
+impl<T, S> BearishHaramiIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，直接传入 BarSeriesRef
+    pub fn new(series_ref: BarSeriesRef<S>) -> Self {
+        CachedIndicator::new_from_series(series_ref, BearishHaramiCalculator)
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>) -> Self {
+        Self::new(BarSeriesRef::Shared(series))
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>) -> Self {
+        Self::new(BarSeriesRef::Mut(series))
+    }
+}
@@ -0,0 +1,105 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::candles::{check_factor, recent_candles};
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// DojiCalculator：实体不超过振幅的 body_factor
+#[derive(Clone)]
+pub struct DojiCalculator<T> {
+    body_factor: T,
+}
+
+impl<T, S> IndicatorCalculator<T, S> for DojiCalculator<T>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = bool;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let Some(candles) = recent_candles(base, index, 1)? else {
+            return Ok(false);
+        };
+        let current = &candles[0];
+        Ok(current
+            .body_height()
+            .is_less_than_or_equal(&current.range().multiplied_by(&self.body_factor)))
+    }
+}
+
+/// 十字星（Doji）
+///
+/// 实体 |close - open| 不超过振幅 high - low 的 body_factor 倍，开盘价等于收盘价的一字线也视为十字星
+pub type DojiIndicator<T, S> = CachedIndicator<T, S, DojiCalculator<T>>;
+
+impl<T, S> DojiIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 使用默认阈值构造：body_factor = 0.1
+    pub fn new(series_ref: BarSeriesRef<S>) -> Result<Self, IndicatorError> {
+        let body_factor = series_ref.with_ref(|s| s.num_factory().num_of_f64(0.1))?;
+        Self::with_thresholds(series_ref, body_factor)
+    }
+
+    /// 自定义阈值构造，阈值不能为负数
+    pub fn with_thresholds(
+        series_ref: BarSeriesRef<S>,
+        body_factor: T,
+    ) -> Result<Self, IndicatorError> {
+        check_factor(&body_factor)?;
+        let calculator = DojiCalculator { body_factor };
+        Ok(CachedIndicator::new_from_series(series_ref, calculator))
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series))
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series))
+    }
+
+    /// 实体占振幅的最大比例
+    pub fn body_factor(&self) -> &T {
+        &self.calculator().body_factor
+    }
+}
@@ -0,0 +1,167 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+pub mod bearish_engulfing_indicator;
+pub mod bearish_harami_indicator;
+pub mod bullish_engulfing_indicator;
+pub mod bullish_harami_indicator;
+pub mod doji_indicator;
+pub mod evening_star_indicator;
+pub mod hammer_indicator;
+pub mod hanging_man_indicator;
+pub mod inside_bar_indicator;
+pub mod morning_star_indicator;
+pub mod outside_bar_indicator;
+pub mod three_black_crows_indicator;
+pub mod three_white_soldiers_indicator;
+
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::{BarPrice, BaseIndicator, bar_price};
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+
+/// K 线形态计算所需的 OHLC
+pub(crate) struct Candle<T> {
+    pub(crate) open: T,
+    pub(crate) high: T,
+    pub(crate) low: T,
+    pub(crate) close: T,
+}
+
+impl<T> Candle<T>
+where
+    T: TrNum + Clone + 'static,
+{
+    pub(crate) fn is_bullish(&self) -> bool {
+        self.close.is_greater_than(&self.open)
+    }
+
+    pub(crate) fn is_bearish(&self) -> bool {
+        self.close.is_less_than(&self.open)
+    }
+
+    /// 实体上沿 max(open, close)
+    pub(crate) fn body_top(&self) -> T {
+        self.open.max(&self.close)
+    }
+
+    /// 实体下沿 min(open, close)
+    pub(crate) fn body_bottom(&self) -> T {
+        self.open.min(&self.close)
+    }
+
+    /// 实体高度 |close - open|
+    pub(crate) fn body_height(&self) -> T {
+        self.close.minus(&self.open).abs()
+    }
+
+    /// 实体中点
+    pub(crate) fn body_middle(&self) -> Result<T, IndicatorError> {
+        let two = T::from_usize(2).unwrap_or_else(T::one);
+        Ok(self.open.plus(&self.close).divided_by(&two)?)
+    }
+
+    /// 振幅 high - low
+    pub(crate) fn range(&self) -> T {
+        self.high.minus(&self.low)
+    }
+
+    /// 上影线 high - max(open, close)
+    pub(crate) fn upper_shadow(&self) -> T {
+        self.high.minus(&self.body_top())
+    }
+
+    /// 下影线 min(open, close) - low
+    pub(crate) fn lower_shadow(&self) -> T {
+        self.body_bottom().minus(&self.low)
+    }
+
+    /// 锤子线形态：实体不超过振幅的 body_factor，下影线至少为实体的 shadow_factor 倍，上影线不长于实体
+    pub(crate) fn has_hammer_shape(&self, body_factor: &T, shadow_factor: &T) -> bool {
+        let range = self.range();
+        let body = self.body_height();
+        range.is_greater_than(&T::zero())
+            && body.is_less_than_or_equal(&range.multiplied_by(body_factor))
+            && self
+                .lower_shadow()
+                .is_greater_than_or_equal(&body.multiplied_by(shadow_factor))
+            && self.upper_shadow().is_less_than_or_equal(&body)
+    }
+}
+
+/// 读取 index 处 bar 的 OHLC
+pub(crate) fn candle<T, S>(
+    base: &BaseIndicator<T, S>,
+    index: usize,
+) -> Result<Candle<T>, IndicatorError>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    base.bar_series().with_ref(|s| {
+        Ok(Candle {
+            open: bar_price(s, index, BarPrice::Open)?,
+            high: bar_price(s, index, BarPrice::High)?,
+            low: bar_price(s, index, BarPrice::Low)?,
+            close: bar_price(s, index, BarPrice::Close)?,
+        })
+    })?
+}
+
+/// 以 index 结尾的最近 count 根 bar，不足 count 根时返回 None
+pub(crate) fn recent_candles<T, S>(
+    base: &BaseIndicator<T, S>,
+    index: usize,
+    count: usize,
+) -> Result<Option<Vec<Candle<T>>>, IndicatorError>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    let first = base
+        .bar_series()
+        .with_ref(|s| s.get_begin_index().unwrap_or(0))?;
+    if index + 1 < first + count {
+        return Ok(None);
+    }
+    (index + 1 - count..=index)
+        .map(|i| candle(base, i))
+        .collect::<Result<Vec<_>, _>>()
+        .map(Some)
+}
+
+/// 阈值不能为负数
+pub(crate) fn check_factor<T>(factor: &T) -> Result<(), IndicatorError>
+where
+    T: TrNum + Clone + 'static,
+{
+    if factor.is_less_than(&T::zero()) {
+        return Err(IndicatorError::CalculationError {
+            message: "Factor must not be negative".to_string(),
+        });
+    }
+    Ok(())
+}
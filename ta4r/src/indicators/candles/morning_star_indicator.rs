@@ -0,0 +1,126 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::candles::{check_factor, recent_candles};
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// MorningStarCalculator：长阴线、向下跳空的星线、收复过半的阳线
+#[derive(Clone)]
+pub struct MorningStarCalculator<T> {
+    long_body_factor: T,
+    small_body_factor: T,
+}
+
+impl<T, S> IndicatorCalculator<T, S> for MorningStarCalculator<T>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = bool;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let Some(candles) = recent_candles(base, index, 3)? else {
+            return Ok(false);
+        };
+        let (first, star, last) = (&candles[0], &candles[1], &candles[2]);
+        Ok(first.is_bearish()
+            && first
+                .body_height()
+                .is_greater_than_or_equal(&first.range().multiplied_by(&self.long_body_factor))
+            && star
+                .body_height()
+                .is_less_than_or_equal(&star.range().multiplied_by(&self.small_body_factor))
+            && star.body_top().is_less_than(&first.close)
+            && last.is_bullish()
+            && last.close.is_greater_than(&first.body_middle()?))
+    }
+}
+
+/// 启明星（Morning Star）
+///
+/// - 第一根为长阴线：实体不小于振幅的 long_body_factor
+/// - 第二根为星线：实体不超过振幅的 small_body_factor，且实体低于第一根的收盘价
+/// - 第三根为阳线，收盘价高于第一根实体的中点
+pub type MorningStarIndicator<T, S> = CachedIndicator<T, S, MorningStarCalculator<T>>;
+
+impl<T, S> MorningStarIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 使用默认阈值构造：long_body_factor = 0.6，small_body_factor = 0.3
+    pub fn new(series_ref: BarSeriesRef<S>) -> Result<Self, IndicatorError> {
+        let long_body_factor = series_ref.with_ref(|s| s.num_factory().num_of_f64(0.6))?;
+        let small_body_factor = series_ref.with_ref(|s| s.num_factory().num_of_f64(0.3))?;
+        Self::with_thresholds(series_ref, long_body_factor, small_body_factor)
+    }
+
+    /// 自定义阈值构造，阈值不能为负数
+    pub fn with_thresholds(
+        series_ref: BarSeriesRef<S>,
+        long_body_factor: T,
+        small_body_factor: T,
+    ) -> Result<Self, IndicatorError> {
+        check_factor(&long_body_factor)?;
+        check_factor(&small_body_factor)?;
+        let calculator = MorningStarCalculator {
+            long_body_factor,
+            small_body_factor,
+        };
+        Ok(CachedIndicator::new_from_series(series_ref, calculator))
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series))
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series))
+    }
+
+    /// 第一根长实体占振幅的最小比例
+    pub fn long_body_factor(&self) -> &T {
+        &self.calculator().long_body_factor
+    }
+
+    /// 星线实体占振幅的最大比例
+    pub fn small_body_factor(&self) -> &T {
+        &self.calculator().small_body_factor
+    }
+}
@@ -0,0 +1,129 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::candles::{Candle, check_factor, recent_candles};
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// ThreeBlackCrowsCalculator：连续三根稳步下跌的长阴线
+#[derive(Clone)]
+pub struct ThreeBlackCrowsCalculator<T> {
+    body_factor: T,
+    shadow_factor: T,
+}
+
+impl<T, S> IndicatorCalculator<T, S> for ThreeBlackCrowsCalculator<T>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = bool;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let Some(candles) = recent_candles(base, index, 3)? else {
+            return Ok(false);
+        };
+        let crow = |candle: &Candle<T>| {
+            let body = candle.body_height();
+            candle.is_bearish()
+                && body.is_greater_than_or_equal(&candle.range().multiplied_by(&self.body_factor))
+                && candle
+                    .lower_shadow()
+                    .is_less_than_or_equal(&body.multiplied_by(&self.shadow_factor))
+        };
+        Ok(candles.iter().all(crow)
+            && candles.windows(2).all(|pair| {
+                let (previous, current) = (&pair[0], &pair[1]);
+                current.close.is_less_than(&previous.close)
+                    && current.open.is_less_than_or_equal(&previous.open)
+                    && current.open.is_greater_than_or_equal(&previous.close)
+            }))
+    }
+}
+
+/// 三只乌鸦（Three Black Crows）
+///
+/// - 连续三根阴线，收盘价依次走低，后两根的开盘价位于前一根实体之内
+/// - 每根实体不小于振幅的 body_factor，下影线不超过实体的 shadow_factor 倍
+pub type ThreeBlackCrowsIndicator<T, S> = CachedIndicator<T, S, ThreeBlackCrowsCalculator<T>>;
+
+impl<T, S> ThreeBlackCrowsIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 使用默认阈值构造：body_factor = 0.5，shadow_factor = 0.3
+    pub fn new(series_ref: BarSeriesRef<S>) -> Result<Self, IndicatorError> {
+        let body_factor = series_ref.with_ref(|s| s.num_factory().num_of_f64(0.5))?;
+        let shadow_factor = series_ref.with_ref(|s| s.num_factory().num_of_f64(0.3))?;
+        Self::with_thresholds(series_ref, body_factor, shadow_factor)
+    }
+
+    /// 自定义阈值构造，阈值不能为负数
+    pub fn with_thresholds(
+        series_ref: BarSeriesRef<S>,
+        body_factor: T,
+        shadow_factor: T,
+    ) -> Result<Self, IndicatorError> {
+        check_factor(&body_factor)?;
+        check_factor(&shadow_factor)?;
+        let calculator = ThreeBlackCrowsCalculator {
+            body_factor,
+            shadow_factor,
+        };
+        Ok(CachedIndicator::new_from_series(series_ref, calculator))
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series))
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series))
+    }
+
+    /// 实体占振幅的最小比例
+    pub fn body_factor(&self) -> &T {
+        &self.calculator().body_factor
+    }
+
+    /// 下影线相对实体的最大比例
+    pub fn shadow_factor(&self) -> &T {
+        &self.calculator().shadow_factor
+    }
+}
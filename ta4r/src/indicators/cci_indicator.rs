@@ -0,0 +1,210 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::averages::sma_indicator::SmaIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::typical_price_indicator::TypicalPriceIndicator;
+use crate::indicators::statistics::mean_deviation_indicator::MeanDeviationIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// CciCalculator：价格偏离均值的程度，以平均绝对偏差为单位
+pub struct CciCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    indicator: Arc<I>,
+    sma: Arc<SmaIndicator<T, S, I>>,
+    mean_deviation: Arc<MeanDeviationIndicator<T, S, I>>,
+    factor: T,
+    bar_count: usize,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for CciCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            indicator: Arc::clone(&self.indicator),
+            sma: Arc::clone(&self.sma),
+            mean_deviation: Arc::clone(&self.mean_deviation),
+            factor: self.factor.clone(),
+            bar_count: self.bar_count,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for CciCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let price = self.indicator.get_value(index)?;
+        let sma = self.sma.get_value(index)?;
+        let mean_deviation = self.mean_deviation.get_value(index)?;
+        // 与 ta4j 一致：窗口内价格没有偏差时返回 0
+        if mean_deviation.is_zero() {
+            return Ok(T::zero());
+        }
+
+        Ok(price
+            .minus(&sma)
+            .divided_by(&mean_deviation.multiplied_by(&self.factor))?)
+    }
+}
+
+/// 顺势指标（Commodity Channel Index）
+///
+/// CCI = (TP - SMA(TP, n)) / (0.015 * MD(TP, n))，TP 默认为典型价格 (high + low + close) / 3
+pub struct CciIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, CciCalculator<T, S, I>>,
+    unstable_bars: usize,
+}
+
+impl<T, S, I> Clone for CciIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S> CciIndicator<T, S, TypicalPriceIndicator<T, S>>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造：基于典型价格
+    pub fn new(series_ref: BarSeriesRef<S>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::with_indicator(Arc::new(TypicalPriceIndicator::new(series_ref)), bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), bar_count)
+    }
+}
+
+impl<T, S, I> CciIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 使用自定义的价格指标（默认为典型价格）
+    pub fn with_indicator(indicator: Arc<I>, bar_count: usize) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+
+        let factor = indicator
+            .bar_series()
+            .with_ref(|s| s.num_factory().num_of_f64(0.015))?;
+        let calculator = CciCalculator {
+            indicator: Arc::clone(&indicator),
+            sma: Arc::new(SmaIndicator::new(Arc::clone(&indicator), bar_count)),
+            mean_deviation: Arc::new(MeanDeviationIndicator::new(
+                Arc::clone(&indicator),
+                bar_count,
+            )?),
+            factor,
+            bar_count,
+            _phantom: PhantomData,
+        };
+        let unstable_bars = indicator.count_of_unstable_bars() + bar_count - 1;
+        Ok(Self {
+            cached: CachedIndicator::new_from_indicator(indicator, calculator),
+            unstable_bars,
+        })
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.cached.calculator().bar_count
+    }
+}
+
+impl<T, S, I> Indicator for CciIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
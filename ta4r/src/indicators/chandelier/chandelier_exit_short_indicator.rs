@@ -0,0 +1,190 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::atr_indicator::ATRIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::low_price_indicator::LowPriceIndicator;
+use crate::indicators::helpers::lowest_value_indicator::LowestValueIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// ChandelierExitShortCalculator：最近 n 根 bar 的最低价 + k * ATR
+pub struct ChandelierExitShortCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    lowest: Arc<LowestValueIndicator<T, S, LowPriceIndicator<T, S>>>,
+    atr: Arc<ATRIndicator<T, S>>,
+    k: T,
+}
+
+impl<T, S> Clone for ChandelierExitShortCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            lowest: Arc::clone(&self.lowest),
+            atr: Arc::clone(&self.atr),
+            k: self.k.clone(),
+        }
+    }
+}
+
+impl<T, S> IndicatorCalculator<T, S> for ChandelierExitShortCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let lowest = self.lowest.get_value(index)?;
+        let atr = self.atr.get_value(index)?;
+        Ok(lowest.plus(&atr.multiplied_by(&self.k)))
+    }
+}
+
+/// 吊灯止损（Chandelier Exit）空头离场价位
+///
+/// ChandelierExitShort = min(low, n) + k * ATR(n)，收盘价上穿该价位时空头离场
+pub struct ChandelierExitShortIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, ChandelierExitShortCalculator<T, S>>,
+    bar_count: usize,
+}
+
+impl<T, S> Clone for ChandelierExitShortIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S> ChandelierExitShortIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 使用默认参数构造：n = 22，k = 3
+    pub fn with_defaults(series_ref: BarSeriesRef<S>) -> Result<Self, IndicatorError> {
+        let k = series_ref.with_ref(|s| s.num_factory().num_of_f64(3.0))?;
+        Self::new(series_ref, 22, k)
+    }
+
+    /// 通用构造，直接传入 BarSeriesRef
+    pub fn new(
+        series_ref: BarSeriesRef<S>,
+        bar_count: usize,
+        k: T,
+    ) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+        let low = Arc::new(LowPriceIndicator::new(series_ref.clone()));
+        let calculator = ChandelierExitShortCalculator {
+            lowest: Arc::new(LowestValueIndicator::new(low, bar_count)),
+            atr: Arc::new(ATRIndicator::new(series_ref.clone(), bar_count)),
+            k,
+        };
+        Ok(Self {
+            cached: CachedIndicator::new_from_series(series_ref, calculator),
+            bar_count,
+        })
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(
+        series: Arc<RwLock<S>>,
+        bar_count: usize,
+        k: T,
+    ) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), bar_count, k)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(
+        series: Arc<RefCell<S>>,
+        bar_count: usize,
+        k: T,
+    ) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), bar_count, k)
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+
+    pub fn k(&self) -> &T {
+        &self.cached.calculator().k
+    }
+}
+
+impl<T, S> Indicator for ChandelierExitShortIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    /// ATR 的不稳定期覆盖了最低价窗口
+    fn count_of_unstable_bars(&self) -> usize {
+        self.cached.calculator().atr.count_of_unstable_bars()
+    }
+}
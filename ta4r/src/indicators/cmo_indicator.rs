@@ -0,0 +1,184 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::gain_indicator::GainIndicator;
+use crate::indicators::helpers::loss_indicator::LossIndicator;
+use crate::indicators::helpers::running_total_indicator::RunningTotalIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// CmoCalculator：窗口内涨幅和与跌幅和之差占总波动的百分比
+pub struct CmoCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    gains: Arc<RunningTotalIndicator<T, S, GainIndicator<T, S, I>>>,
+    losses: Arc<RunningTotalIndicator<T, S, LossIndicator<T, S, I>>>,
+    bar_count: usize,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for CmoCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            gains: Arc::clone(&self.gains),
+            losses: Arc::clone(&self.losses),
+            bar_count: self.bar_count,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for CmoCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let gains = self.gains.get_value(index)?;
+        let losses = self.losses.get_value(index)?;
+        let total = gains.plus(&losses);
+        // 窗口内价格没有变化时返回 0（ta4j 此时得到 NaN）
+        if total.is_zero() {
+            return Ok(T::zero());
+        }
+
+        let hundred = T::from_usize(100).unwrap_or_else(T::one);
+        Ok(gains
+            .minus(&losses)
+            .divided_by(&total)?
+            .multiplied_by(&hundred))
+    }
+}
+
+/// 钱德动量摆动指标（Chande Momentum Oscillator）
+///
+/// CMO = (ΣGain - ΣLoss) / (ΣGain + ΣLoss) * 100，取值范围 [-100, 100]
+pub struct CmoIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, CmoCalculator<T, S, I>>,
+    unstable_bars: usize,
+}
+
+impl<T, S, I> Clone for CmoIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I> CmoIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    pub fn new(indicator: Arc<I>, bar_count: usize) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+
+        let gains = RunningTotalIndicator::new(
+            Arc::new(GainIndicator::new(Arc::clone(&indicator))),
+            bar_count,
+        );
+        let losses = RunningTotalIndicator::new(
+            Arc::new(LossIndicator::new(Arc::clone(&indicator))),
+            bar_count,
+        );
+        let calculator = CmoCalculator {
+            gains: Arc::new(gains),
+            losses: Arc::new(losses),
+            bar_count,
+            _phantom: PhantomData,
+        };
+        let unstable_bars = indicator.count_of_unstable_bars() + bar_count;
+        Ok(Self {
+            cached: CachedIndicator::new_from_indicator(indicator, calculator),
+            unstable_bars,
+        })
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.cached.calculator().bar_count
+    }
+}
+
+impl<T, S, I> Indicator for CmoIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -0,0 +1,233 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::streak_indicator::StreakIndicator;
+use crate::indicators::rsi_indicator::RsiIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// ConnorsRsiCalculator：三个分量的算术平均
+pub struct ConnorsRsiCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    indicator: Arc<I>,
+    price_rsi: Arc<RsiIndicator<T, S, I>>,
+    streak_rsi: Arc<RsiIndicator<T, S, StreakIndicator<T, S, I>>>,
+    percent_rank_bar_count: usize,
+    _phantom: PhantomData<(T, S)>,
+}
+
+impl<T, S, I> Clone for ConnorsRsiCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            indicator: Arc::clone(&self.indicator),
+            price_rsi: Arc::clone(&self.price_rsi),
+            streak_rsi: Arc::clone(&self.streak_rsi),
+            percent_rank_bar_count: self.percent_rank_bar_count,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> ConnorsRsiCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 单周期变化率 (value[i] - value[i-1]) / value[i-1]
+    ///
+    /// 前值为 0 时变化率无定义，按 0 处理，避免单个零价格使后续所有值出错
+    fn rate_of_change(&self, index: usize) -> Result<T, IndicatorError> {
+        let previous = self.indicator.get_value(index - 1)?;
+        if previous.is_zero() {
+            return Ok(T::zero());
+        }
+        let current = self.indicator.get_value(index)?;
+        Ok(current.minus(&previous).divided_by(&previous)?)
+    }
+
+    /// 当前变化率在之前 percent_rank_bar_count 个变化率中的百分位（严格小于的占比 * 100）
+    ///
+    /// 数据不足时只在已有的变化率中排名
+    fn percent_rank(&self, index: usize) -> Result<T, IndicatorError> {
+        let start = index.saturating_sub(self.percent_rank_bar_count).max(1);
+        if index <= start {
+            return Ok(T::zero());
+        }
+
+        let current = self.rate_of_change(index)?;
+        let mut lower = 0usize;
+        for i in start..index {
+            if self.rate_of_change(i)?.is_less_than(&current) {
+                lower += 1;
+            }
+        }
+
+        let hundred = T::from_usize(100).unwrap_or_else(T::one);
+        let count = T::from_usize(index - start).unwrap_or_else(T::one);
+        let lower = T::from_usize(lower).unwrap_or_else(T::zero);
+        Ok(lower.multiplied_by(&hundred).divided_by(&count)?)
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for ConnorsRsiCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let price_rsi = self.price_rsi.get_value(index)?;
+        let streak_rsi = self.streak_rsi.get_value(index)?;
+        let percent_rank = self.percent_rank(index)?;
+
+        let three = T::from_usize(3).unwrap_or_else(T::one);
+        Ok(price_rsi
+            .plus(&streak_rsi)
+            .plus(&percent_rank)
+            .divided_by(&three)?)
+    }
+}
+
+/// Connors RSI
+///
+/// CRSI = (RSI(price, 3) + RSI(streak, 2) + PercentRank(ROC(1), 100)) / 3
+pub struct ConnorsRsiIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, ConnorsRsiCalculator<T, S, I>>,
+    unstable_bars: usize,
+}
+
+impl<T, S, I> Clone for ConnorsRsiIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I> ConnorsRsiIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 使用标准参数 (3, 2, 100) 构造
+    pub fn with_default_periods(indicator: Arc<I>) -> Result<Self, IndicatorError> {
+        Self::new(indicator, 3, 2, 100)
+    }
+
+    /// 构造函数
+    ///
+    /// # Arguments
+    /// * `indicator` - 基础指标（通常为收盘价）
+    /// * `rsi_bar_count` - 价格 RSI 周期
+    /// * `streak_rsi_bar_count` - 连涨/连跌 RSI 周期
+    /// * `percent_rank_bar_count` - 变化率百分位的回看周期
+    pub fn new(
+        indicator: Arc<I>,
+        rsi_bar_count: usize,
+        streak_rsi_bar_count: usize,
+        percent_rank_bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        let price_rsi = RsiIndicator::new(Arc::clone(&indicator), rsi_bar_count)?;
+        let streak = Arc::new(StreakIndicator::new(Arc::clone(&indicator)));
+        let streak_rsi = RsiIndicator::new(streak, streak_rsi_bar_count)?;
+
+        // 百分位需要 percent_rank_bar_count 个完整的变化率，变化率本身需要 1 个前值
+        let unstable_bars = price_rsi
+            .count_of_unstable_bars()
+            .max(streak_rsi.count_of_unstable_bars())
+            .max(indicator.count_of_unstable_bars() + percent_rank_bar_count + 1);
+
+        let calculator = ConnorsRsiCalculator {
+            indicator: Arc::clone(&indicator),
+            price_rsi: Arc::new(price_rsi),
+            streak_rsi: Arc::new(streak_rsi),
+            percent_rank_bar_count,
+            _phantom: PhantomData,
+        };
+
+        Ok(Self {
+            cached: CachedIndicator::new_from_indicator(indicator, calculator),
+            unstable_bars,
+        })
+    }
+}
+
+impl<T, S, I> Indicator for ConnorsRsiIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
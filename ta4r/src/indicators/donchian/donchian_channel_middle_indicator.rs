@@ -0,0 +1,167 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::donchian::donchian_channel_lower_indicator::DonchianChannelLowerIndicator;
+use crate::indicators::donchian::donchian_channel_upper_indicator::DonchianChannelUpperIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// DonchianChannelMiddleCalculator：(上轨 + 下轨) / 2
+pub struct DonchianChannelMiddleCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    upper: Arc<DonchianChannelUpperIndicator<T, S>>,
+    lower: Arc<DonchianChannelLowerIndicator<T, S>>,
+}
+
+impl<T, S> Clone for DonchianChannelMiddleCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            upper: Arc::clone(&self.upper),
+            lower: Arc::clone(&self.lower),
+        }
+    }
+}
+
+impl<T, S> IndicatorCalculator<T, S> for DonchianChannelMiddleCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let upper = self.upper.get_value(index)?;
+        let lower = self.lower.get_value(index)?;
+        let two = base
+            .bar_series()
+            .with_ref(|s| s.num_factory().two().as_ref().clone())?;
+        Ok(upper.plus(&lower).divided_by(&two)?)
+    }
+}
+
+/// 唐奇安通道中轨：上轨与下轨的中点
+pub struct DonchianChannelMiddleIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, DonchianChannelMiddleCalculator<T, S>>,
+    unstable_bars: usize,
+}
+
+impl<T, S> Clone for DonchianChannelMiddleIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S> DonchianChannelMiddleIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，直接传入 BarSeriesRef
+    pub fn new(series_ref: BarSeriesRef<S>, bar_count: usize) -> Result<Self, IndicatorError> {
+        let upper = DonchianChannelUpperIndicator::new(series_ref.clone(), bar_count)?;
+        let lower = DonchianChannelLowerIndicator::new(series_ref, bar_count)?;
+        Ok(Self::from_channels(Arc::new(upper), Arc::new(lower)))
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), bar_count)
+    }
+
+    /// 基于已有的上轨与下轨构造
+    pub fn from_channels(
+        upper: Arc<DonchianChannelUpperIndicator<T, S>>,
+        lower: Arc<DonchianChannelLowerIndicator<T, S>>,
+    ) -> Self {
+        let unstable_bars = upper
+            .count_of_unstable_bars()
+            .max(lower.count_of_unstable_bars());
+        let calculator = DonchianChannelMiddleCalculator {
+            upper: Arc::clone(&upper),
+            lower,
+        };
+        Self {
+            cached: CachedIndicator::new_from_indicator(upper, calculator),
+            unstable_bars,
+        }
+    }
+}
+
+impl<T, S> Indicator for DonchianChannelMiddleIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -0,0 +1,191 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::atr_indicator::ATRIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::minus_di_indicator::MinusDiIndicator;
+use crate::indicators::plus_di_indicator::PlusDiIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// DxCalculator：|+DI - -DI| / (+DI + -DI) * 100
+pub struct DxCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    plus_di: Arc<PlusDiIndicator<T, S>>,
+    minus_di: Arc<MinusDiIndicator<T, S>>,
+}
+
+impl<T, S> Clone for DxCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            plus_di: Arc::clone(&self.plus_di),
+            minus_di: Arc::clone(&self.minus_di),
+        }
+    }
+}
+
+impl<T, S> IndicatorCalculator<T, S> for DxCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let plus_di = self.plus_di.get_value(index)?;
+        let minus_di = self.minus_di.get_value(index)?;
+        let sum = plus_di.plus(&minus_di);
+        // 两个方向都没有动向时 DX 为 0
+        if sum.is_zero() {
+            return Ok(T::zero());
+        }
+        let hundred = base
+            .bar_series()
+            .with_ref(|s| s.num_factory().hundred().as_ref().clone())?;
+        Ok(plus_di
+            .minus(&minus_di)
+            .abs()
+            .divided_by(&sum)?
+            .multiplied_by(&hundred))
+    }
+}
+
+/// 动向指数 DX
+pub struct DxIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, DxCalculator<T, S>>,
+    bar_count: usize,
+}
+
+impl<T, S> Clone for DxIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S> DxIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，+DI 与 -DI 共用同一个 ATR
+    pub fn new(series_ref: BarSeriesRef<S>, bar_count: usize) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+        let atr = Arc::new(ATRIndicator::new(series_ref, bar_count));
+        let plus_di = PlusDiIndicator::from_atr(Arc::clone(&atr), bar_count)?;
+        let minus_di = MinusDiIndicator::from_atr(atr, bar_count)?;
+        Ok(Self::from_di(Arc::new(plus_di), Arc::new(minus_di)))
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), bar_count)
+    }
+
+    /// 复用已有的 +DI 与 -DI
+    pub fn from_di(
+        plus_di: Arc<PlusDiIndicator<T, S>>,
+        minus_di: Arc<MinusDiIndicator<T, S>>,
+    ) -> Self {
+        let bar_count = plus_di.bar_count();
+        let series_ref = plus_di.bar_series();
+        let calculator = DxCalculator { plus_di, minus_di };
+        Self {
+            cached: CachedIndicator::new_from_series(series_ref, calculator),
+            bar_count,
+        }
+    }
+
+    pub fn plus_di(&self) -> Arc<PlusDiIndicator<T, S>> {
+        Arc::clone(&self.cached.calculator().plus_di)
+    }
+
+    pub fn minus_di(&self) -> Arc<MinusDiIndicator<T, S>> {
+        Arc::clone(&self.cached.calculator().minus_di)
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+}
+
+impl<T, S> Indicator for DxIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.bar_count
+    }
+}
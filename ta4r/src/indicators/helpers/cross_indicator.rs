@@ -80,7 +80,7 @@ where
 
 impl<T, S, IU, IL> IndicatorCalculator<T, S> for CrossCalculator<T, S, IU, IL>
 where
-    T: TrNum + Clone + From<bool> + 'static, // ✅ 新增 From<bool> 约束
+    T: TrNum + Clone + 'static,
     S: BarSeries<T> + 'static,
     IU: Indicator<Num = T, Output = T, Series = S>,
     IL: Indicator<Num = T, Output = T, Series = S>,
@@ -128,7 +128,7 @@ where
 // CrossIndicator 结构体，缓存交叉指标结果
 pub struct CrossIndicator<T, S, IU, IL>
 where
-    T: TrNum + Clone + From<bool> + 'static,
+    T: TrNum + Clone + 'static,
     S: BarSeries<T> + 'static,
     IU: Indicator<Num = T, Output = T, Series = S>,
     IL: Indicator<Num = T, Output = T, Series = S>,
@@ -140,7 +140,7 @@ where
 
 impl<T, S, IU, IL> Clone for CrossIndicator<T, S, IU, IL>
 where
-    T: TrNum + Clone + From<bool> + 'static,
+    T: TrNum + Clone + 'static,
     S: BarSeries<T> + 'static,
     IU: Indicator<Num = T, Output = T, Series = S>,
     IL: Indicator<Num = T, Output = T, Series = S>,
@@ -156,7 +156,7 @@ where
 
 impl<T, S, IU, IL> CrossIndicator<T, S, IU, IL>
 where
-    T: TrNum + Clone + From<bool> + 'static,
+    T: TrNum + Clone + 'static,
     S: BarSeries<T> + 'static,
     IU: Indicator<Num = T, Output = T, Series = S>,
     IL: Indicator<Num = T, Output = T, Series = S>,
@@ -182,7 +182,7 @@ where
 
 impl<T, S, IU, IL> Indicator for CrossIndicator<T, S, IU, IL>
 where
-    T: TrNum + Clone + From<bool> + 'static,
+    T: TrNum + Clone + 'static,
     S: BarSeries<T> + 'static,
     IU: Indicator<Num = T, Output = T, Series = S>,
     IL: Indicator<Num = T, Output = T, Series = S>,
@@ -206,7 +206,7 @@ where
 
 impl<T, S, IU, IL> std::fmt::Debug for CrossIndicator<T, S, IU, IL>
 where
-    T: TrNum + Clone + From<bool> + 'static,
+    T: TrNum + Clone + 'static,
     S: BarSeries<T> + 'static,
     IU: Indicator<Num = T, Output = T, Series = S> + std::fmt::Debug,
     IL: Indicator<Num = T, Output = T, Series = S> + std::fmt::Debug,
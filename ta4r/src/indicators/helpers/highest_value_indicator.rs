@@ -27,6 +27,7 @@ use crate::bar::types::BarSeries;
 use crate::indicators::Indicator;
 use crate::indicators::abstract_indicator::BaseIndicator;
 use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::ExtremeValue;
 use crate::indicators::types::{IndicatorCalculator, IndicatorError};
 use crate::num::TrNum;
 use std::marker::PhantomData;
@@ -65,7 +66,7 @@ where
     S: BarSeries<T> + 'static,
     I: Indicator<Num = T, Output = T, Series = S>,
 {
-    type Output = T;
+    type Output = ExtremeValue<T>;
     fn calculate(
         &self,
         base: &BaseIndicator<T, S>,
@@ -84,12 +85,13 @@ where
         }
 
         let end = index.saturating_sub(self.bar_count - 1); // Math.max(0, index - barCount + 1)
-        let mut highest = value;
+        let mut highest = ExtremeValue { value, index };
 
+        // 严格比较，相同的极值保留最近的 bar
         for i in (end..index).rev() {
             let v = self.indicator.get_value(i)?;
-            if highest.is_less_than(&v) {
-                highest = v;
+            if highest.value.is_less_than(&v) {
+                highest = ExtremeValue { value: v, index: i };
             }
         }
 
@@ -119,10 +121,29 @@ where
             _phantom: PhantomData,
         };
         Self {
-            cached: CachedIndicator::new_from_indicator(indicator, calculator),
+            cached: CachedIndicator::new_from_series(indicator.bar_series(), calculator),
             bar_count,
         }
     }
+
+    /// 获取 index 处窗口内的最高值及其所在的 bar
+    pub fn get_extreme(&self, index: usize) -> Result<ExtremeValue<T>, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    /// 最高值所在 bar 的索引
+    pub fn highest_index(&self, index: usize) -> Result<usize, IndicatorError> {
+        Ok(self.get_extreme(index)?.index)
+    }
+
+    /// 距离最高值出现已经过去的 bar 数，当前 bar 即为极值时为 0
+    pub fn bars_since_highest(&self, index: usize) -> Result<usize, IndicatorError> {
+        Ok(index.saturating_sub(self.highest_index(index)?))
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
 }
 
 impl<'a, T, S, I> Clone for HighestValueIndicator<T, S, I>
@@ -150,7 +171,7 @@ where
     type Series = S;
 
     fn get_value(&self, index: usize) -> Result<Self::Output, IndicatorError> {
-        self.cached.get_cached_value(index)
+        Ok(self.get_extreme(index)?.value)
     }
 
     fn bar_series(&self) -> BarSeriesRef<Self::Series> {
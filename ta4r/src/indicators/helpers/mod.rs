@@ -30,11 +30,36 @@ pub mod fixed_indicator;
 pub mod gain_indicator;
 pub mod high_price_indicator;
 pub mod highest_value_indicator;
+pub mod loss_indicator;
 pub mod low_price_indicator;
 pub mod lowest_value_indicator;
+pub mod median_price_indicator;
 pub mod open_price_indicator;
 pub mod previous_value_indicator;
+pub mod rolling_sums;
 pub mod running_total_indicator;
+pub mod streak_indicator;
 pub mod tr_indicator;
 pub mod typical_price_indicator;
 pub mod volume_indicator;
+
+use crate::indicators::types::IndicatorError;
+
+/// 窗口内的极值及其所在的 bar
+///
+/// 极值出现多次时取最近的一次
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExtremeValue<T> {
+    pub value: T,
+    pub index: usize,
+}
+
+/// 取 index 往前 displacement 根 bar 的索引；不足时返回 NotAvailable，而不是向未来取值
+pub(crate) fn displaced_index(index: usize, displacement: usize) -> Result<usize, IndicatorError> {
+    index
+        .checked_sub(displacement)
+        .ok_or(IndicatorError::NotAvailable {
+            index,
+            available_from: displacement,
+        })
+}
@@ -0,0 +1,106 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use std::cell::RefCell;
+
+/// 滚动窗口内 N 个分量各自的和，顺序计算时 O(1) 更新
+///
+/// 只以已收盘 bar 的结果作为滑动基础：最新 bar 仍可能被 add_trade / add_price 修改
+pub(crate) struct RollingSums<T, const N: usize> {
+    bar_count: usize,
+    previous: RefCell<Option<(usize, [T; N])>>,
+}
+
+impl<T: Clone, const N: usize> Clone for RollingSums<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            bar_count: self.bar_count,
+            previous: RefCell::new(self.previous.borrow().clone()),
+        }
+    }
+}
+
+impl<T, const N: usize> RollingSums<T, N>
+where
+    T: TrNum + Clone + 'static,
+{
+    pub(crate) fn new(bar_count: usize) -> Self {
+        Self {
+            bar_count,
+            previous: RefCell::new(None),
+        }
+    }
+
+    /// 计算最近 bar_count 个 bar（含 index）上 terms 的逐分量之和
+    pub(crate) fn sums<S, F>(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+        terms: F,
+    ) -> Result<[T; N], IndicatorError>
+    where
+        S: BarSeries<T> + 'static,
+        F: Fn(usize) -> Result<[T; N], IndicatorError>,
+    {
+        let previous = self.previous.borrow().clone();
+
+        let sums = match previous {
+            // Fast path: 加入新值，移出滑出窗口的值
+            Some((previous_index, mut sums)) if index == previous_index + 1 => {
+                for (sum, term) in sums.iter_mut().zip(terms(index)?) {
+                    *sum = sum.plus(&term);
+                }
+                if index >= self.bar_count {
+                    for (sum, term) in sums.iter_mut().zip(terms(index - self.bar_count)?) {
+                        *sum = sum.minus(&term);
+                    }
+                }
+                sums
+            }
+            // Slow path: 重新计算整个窗口
+            _ => {
+                let mut sums = std::array::from_fn(|_| T::zero());
+                for i in index.saturating_sub(self.bar_count - 1)..=index {
+                    for (sum, term) in sums.iter_mut().zip(terms(i)?) {
+                        *sum = sum.plus(&term);
+                    }
+                }
+                sums
+            }
+        };
+
+        // 最新 bar 未收盘，不记录，避免其旧值在后续滑动中残留
+        let end_index = base.bar_series().with_ref_or(None, |s| s.get_end_index());
+        if end_index.is_some_and(|end| index < end) {
+            *self.previous.borrow_mut() = Some((index, sums.clone()));
+        }
+        Ok(sums)
+    }
+}
@@ -0,0 +1,164 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::recursive_cached_indicator::RecursiveCachedIndicator;
+use crate::indicators::types::{IndicatorError, RecursiveIndicatorCalculator};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// StreakCalculator：连续上涨 / 下跌的 bar 数
+pub struct StreakCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    indicator: Arc<I>,
+    _phantom: PhantomData<(T, S)>,
+}
+
+impl<T, S, I> Clone for StreakCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            indicator: Arc::clone(&self.indicator),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> RecursiveIndicatorCalculator<T, S> for StreakCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+        previous: Option<&T>,
+    ) -> Result<Self::Output, IndicatorError> {
+        let Some(previous_streak) = previous else {
+            return Ok(T::zero());
+        };
+
+        let actual_value = self.indicator.get_value(index)?;
+        let previous_value = self.indicator.get_value(index - 1)?;
+
+        if actual_value.is_greater_than(&previous_value) {
+            // 上涨：延续正向计数，否则重新从 1 开始
+            if previous_streak.is_positive() {
+                Ok(previous_streak.plus(&T::one()))
+            } else {
+                Ok(T::one())
+            }
+        } else if actual_value.is_less_than(&previous_value) {
+            if previous_streak.is_negative() {
+                Ok(previous_streak.minus(&T::one()))
+            } else {
+                Ok(-T::one())
+            }
+        } else {
+            Ok(T::zero())
+        }
+    }
+}
+
+/// StreakIndicator：连续上涨为正数（1, 2, 3...），连续下跌为负数（-1, -2...），持平为 0
+///
+/// Connors RSI 的组成部分之一
+pub struct StreakIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    inner: RecursiveCachedIndicator<T, S, StreakCalculator<T, S, I>>,
+}
+
+impl<T, S, I> Clone for StreakIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T, S, I> StreakIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    pub fn new(indicator: Arc<I>) -> Self {
+        let calculator = StreakCalculator {
+            indicator: Arc::clone(&indicator),
+            _phantom: PhantomData,
+        };
+        Self {
+            inner: RecursiveCachedIndicator::from_indicator(indicator, calculator),
+        }
+    }
+}
+
+impl<T, S, I> Indicator for StreakIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.inner.get_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.inner.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        1
+    }
+}
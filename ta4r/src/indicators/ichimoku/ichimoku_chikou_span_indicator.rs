@@ -0,0 +1,165 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::close_price_indicator::ClosePriceIndicator;
+use crate::indicators::helpers::displaced_index;
+use crate::indicators::ichimoku::DEFAULT_DISPLACEMENT;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// IchimokuChikouSpanCalculator：index 处的收盘价，绘制在 index - displacement 处
+pub struct IchimokuChikouSpanCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    close: Arc<ClosePriceIndicator<T, S>>,
+    displacement: usize,
+}
+
+impl<T, S> Clone for IchimokuChikouSpanCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            close: Arc::clone(&self.close),
+            displacement: self.displacement,
+        }
+    }
+}
+
+impl<T, S> IndicatorCalculator<T, S> for IchimokuChikouSpanCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        // 迟行带的点绘制在 index - displacement 处，不存在该位置时没有可用的值
+        displaced_index(index, self.displacement)?;
+        self.close.get_value(index)
+    }
+}
+
+/// 迟行带（Chikou Span）
+///
+/// 迟行带把收盘价向后平移 displacement 根 bar 绘制。按绘制位置取值（ta4j 的做法）
+/// 需要读取未来 bar 的收盘价，回测时会引入未来数据，因此这里按计算时刻取值：
+///
+/// - index 处的值为 index 处的收盘价，对应图上 index - displacement 的位置
+/// - 与之比较的价格应取 displacement 根 bar 之前的数据
+/// - index < displacement 时没有对应的绘制位置，返回 `IndicatorError::NotAvailable`
+pub struct IchimokuChikouSpanIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, IchimokuChikouSpanCalculator<T, S>>,
+}
+
+impl<T, S> Clone for IchimokuChikouSpanIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+        }
+    }
+}
+
+impl<T, S> IchimokuChikouSpanIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 默认参数构造：平移 26
+    pub fn new(series_ref: BarSeriesRef<S>) -> Self {
+        Self::with_displacement(series_ref, DEFAULT_DISPLACEMENT)
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>) -> Self {
+        Self::new(BarSeriesRef::Shared(series))
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>) -> Self {
+        Self::new(BarSeriesRef::Mut(series))
+    }
+
+    /// 自定义平移 bar 数
+    pub fn with_displacement(series_ref: BarSeriesRef<S>, displacement: usize) -> Self {
+        let calculator = IchimokuChikouSpanCalculator {
+            close: Arc::new(ClosePriceIndicator::new(series_ref.clone())),
+            displacement,
+        };
+        Self {
+            cached: CachedIndicator::new_from_series(series_ref, calculator),
+        }
+    }
+
+    pub fn displacement(&self) -> usize {
+        self.cached.calculator().displacement
+    }
+}
+
+impl<T, S> Indicator for IchimokuChikouSpanIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.displacement()
+    }
+}
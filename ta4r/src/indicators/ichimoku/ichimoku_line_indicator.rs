@@ -0,0 +1,192 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::high_price_indicator::HighPriceIndicator;
+use crate::indicators::helpers::highest_value_indicator::HighestValueIndicator;
+use crate::indicators::helpers::low_price_indicator::LowPriceIndicator;
+use crate::indicators::helpers::lowest_value_indicator::LowestValueIndicator;
+use crate::indicators::ichimoku::{DEFAULT_KIJUN_SEN_BAR_COUNT, DEFAULT_TENKAN_SEN_BAR_COUNT};
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// IchimokuLineCalculator：(max(high, n) + min(low, n)) / 2
+pub struct IchimokuLineCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    highest: Arc<HighestValueIndicator<T, S, HighPriceIndicator<T, S>>>,
+    lowest: Arc<LowestValueIndicator<T, S, LowPriceIndicator<T, S>>>,
+}
+
+impl<T, S> Clone for IchimokuLineCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            highest: Arc::clone(&self.highest),
+            lowest: Arc::clone(&self.lowest),
+        }
+    }
+}
+
+impl<T, S> IndicatorCalculator<T, S> for IchimokuLineCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let highest = self.highest.get_value(index)?;
+        let lowest = self.lowest.get_value(index)?;
+        let two = base
+            .bar_series()
+            .with_ref(|s| s.num_factory().two().as_ref().clone())?;
+        Ok(highest.plus(&lowest).divided_by(&two)?)
+    }
+}
+
+/// 一目均衡表的中点线：最近 n 根 bar 最高价与最低价的中点
+///
+/// 转换线（Tenkan-sen）、基准线（Kijun-sen）以及先行带 B 均由它构成
+pub struct IchimokuLineIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, IchimokuLineCalculator<T, S>>,
+    bar_count: usize,
+}
+
+/// 转换线（Tenkan-sen），默认周期 9
+pub type IchimokuTenkanSenIndicator<T, S> = IchimokuLineIndicator<T, S>;
+/// 基准线（Kijun-sen），默认周期 26
+pub type IchimokuKijunSenIndicator<T, S> = IchimokuLineIndicator<T, S>;
+
+impl<T, S> Clone for IchimokuLineIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S> IchimokuLineIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，直接传入 BarSeriesRef
+    pub fn new(series_ref: BarSeriesRef<S>, bar_count: usize) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+
+        let calculator = IchimokuLineCalculator {
+            highest: Arc::new(HighestValueIndicator::new(
+                Arc::new(HighPriceIndicator::new(series_ref.clone())),
+                bar_count,
+            )),
+            lowest: Arc::new(LowestValueIndicator::new(
+                Arc::new(LowPriceIndicator::new(series_ref.clone())),
+                bar_count,
+            )),
+        };
+        Ok(Self {
+            cached: CachedIndicator::new_from_series(series_ref, calculator),
+            bar_count,
+        })
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), bar_count)
+    }
+
+    /// 默认周期的转换线（Tenkan-sen）
+    pub fn tenkan_sen(series_ref: BarSeriesRef<S>) -> Self {
+        Self::new(series_ref, DEFAULT_TENKAN_SEN_BAR_COUNT)
+            .expect("default tenkan-sen bar count is valid")
+    }
+
+    /// 默认周期的基准线（Kijun-sen）
+    pub fn kijun_sen(series_ref: BarSeriesRef<S>) -> Self {
+        Self::new(series_ref, DEFAULT_KIJUN_SEN_BAR_COUNT)
+            .expect("default kijun-sen bar count is valid")
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+}
+
+impl<T, S> Indicator for IchimokuLineIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.bar_count - 1
+    }
+}
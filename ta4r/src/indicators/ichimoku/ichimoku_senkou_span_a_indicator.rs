@@ -0,0 +1,222 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::displaced_index;
+use crate::indicators::ichimoku::ichimoku_line_indicator::{
+    IchimokuKijunSenIndicator, IchimokuLineIndicator, IchimokuTenkanSenIndicator,
+};
+use crate::indicators::ichimoku::{
+    DEFAULT_DISPLACEMENT, DEFAULT_KIJUN_SEN_BAR_COUNT, DEFAULT_TENKAN_SEN_BAR_COUNT,
+};
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// IchimokuSenkouSpanACalculator：displacement 根 bar 之前的 (转换线 + 基准线) / 2
+pub struct IchimokuSenkouSpanACalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    tenkan_sen: Arc<IchimokuTenkanSenIndicator<T, S>>,
+    kijun_sen: Arc<IchimokuKijunSenIndicator<T, S>>,
+    displacement: usize,
+}
+
+impl<T, S> Clone for IchimokuSenkouSpanACalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            tenkan_sen: Arc::clone(&self.tenkan_sen),
+            kijun_sen: Arc::clone(&self.kijun_sen),
+            displacement: self.displacement,
+        }
+    }
+}
+
+impl<T, S> IchimokuSenkouSpanACalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 未平移的中点：(转换线 + 基准线) / 2
+    fn midpoint(&self, base: &BaseIndicator<T, S>, index: usize) -> Result<T, IndicatorError> {
+        let two = base
+            .bar_series()
+            .with_ref(|s| s.num_factory().two().as_ref().clone())?;
+        Ok(self
+            .tenkan_sen
+            .get_value(index)?
+            .plus(&self.kijun_sen.get_value(index)?)
+            .divided_by(&two)?)
+    }
+}
+
+impl<T, S> IndicatorCalculator<T, S> for IchimokuSenkouSpanACalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        self.midpoint(base, displaced_index(index, self.displacement)?)
+    }
+}
+
+/// 先行带 A（Senkou Span A）
+///
+/// - index 处的值为 displacement 根 bar 之前计算出的 (转换线 + 基准线) / 2，即云层中当前 bar 对应的位置
+/// - 只使用 index 及之前的数据；index < displacement 时没有可用的值，返回 `IndicatorError::NotAvailable`
+/// - `leading_value(index)` 返回 index 处计算、将显示在 index + displacement 处的值
+pub struct IchimokuSenkouSpanAIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, IchimokuSenkouSpanACalculator<T, S>>,
+    unstable_bars: usize,
+}
+
+impl<T, S> Clone for IchimokuSenkouSpanAIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S> IchimokuSenkouSpanAIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 默认参数构造：转换线 9，基准线 26，平移 26
+    pub fn new(series_ref: BarSeriesRef<S>) -> Result<Self, IndicatorError> {
+        Self::with_periods(
+            series_ref,
+            DEFAULT_TENKAN_SEN_BAR_COUNT,
+            DEFAULT_KIJUN_SEN_BAR_COUNT,
+            DEFAULT_DISPLACEMENT,
+        )
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series))
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series))
+    }
+
+    /// 自定义转换线、基准线周期与平移 bar 数
+    pub fn with_periods(
+        series_ref: BarSeriesRef<S>,
+        tenkan_sen_bar_count: usize,
+        kijun_sen_bar_count: usize,
+        displacement: usize,
+    ) -> Result<Self, IndicatorError> {
+        let tenkan_sen = IchimokuLineIndicator::new(series_ref.clone(), tenkan_sen_bar_count)?;
+        let kijun_sen = IchimokuLineIndicator::new(series_ref, kijun_sen_bar_count)?;
+        Ok(Self::from_lines(
+            Arc::new(tenkan_sen),
+            Arc::new(kijun_sen),
+            displacement,
+        ))
+    }
+
+    /// 基于已有的转换线与基准线构造
+    pub fn from_lines(
+        tenkan_sen: Arc<IchimokuTenkanSenIndicator<T, S>>,
+        kijun_sen: Arc<IchimokuKijunSenIndicator<T, S>>,
+        displacement: usize,
+    ) -> Self {
+        let unstable_bars = tenkan_sen
+            .count_of_unstable_bars()
+            .max(kijun_sen.count_of_unstable_bars())
+            + displacement;
+        let calculator = IchimokuSenkouSpanACalculator {
+            tenkan_sen: Arc::clone(&tenkan_sen),
+            kijun_sen,
+            displacement,
+        };
+        Self {
+            cached: CachedIndicator::new_from_indicator(tenkan_sen, calculator),
+            unstable_bars,
+        }
+    }
+
+    /// index 处计算、向前平移后显示在 index + displacement 处的值
+    pub fn leading_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.calculator().midpoint(&self.cached.base, index)
+    }
+
+    pub fn displacement(&self) -> usize {
+        self.cached.calculator().displacement
+    }
+}
+
+impl<T, S> Indicator for IchimokuSenkouSpanAIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
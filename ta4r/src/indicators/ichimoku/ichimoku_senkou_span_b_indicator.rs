@@ -0,0 +1,184 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::displaced_index;
+use crate::indicators::ichimoku::ichimoku_line_indicator::IchimokuLineIndicator;
+use crate::indicators::ichimoku::{DEFAULT_DISPLACEMENT, DEFAULT_SENKOU_SPAN_B_BAR_COUNT};
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// IchimokuSenkouSpanBCalculator：displacement 根 bar 之前的长周期中点线
+pub struct IchimokuSenkouSpanBCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    line: Arc<IchimokuLineIndicator<T, S>>,
+    displacement: usize,
+}
+
+impl<T, S> Clone for IchimokuSenkouSpanBCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            line: Arc::clone(&self.line),
+            displacement: self.displacement,
+        }
+    }
+}
+
+impl<T, S> IndicatorCalculator<T, S> for IchimokuSenkouSpanBCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        self.line
+            .get_value(displaced_index(index, self.displacement)?)
+    }
+}
+
+/// 先行带 B（Senkou Span B）
+///
+/// - index 处的值为 displacement 根 bar 之前、最近 n 根 bar 最高价与最低价的中点
+/// - 只使用 index 及之前的数据；index < displacement 时没有可用的值，返回 `IndicatorError::NotAvailable`
+/// - `leading_value(index)` 返回 index 处计算、将显示在 index + displacement 处的值
+pub struct IchimokuSenkouSpanBIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, IchimokuSenkouSpanBCalculator<T, S>>,
+    unstable_bars: usize,
+}
+
+impl<T, S> Clone for IchimokuSenkouSpanBIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S> IchimokuSenkouSpanBIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 默认参数构造：周期 52，平移 26
+    pub fn new(series_ref: BarSeriesRef<S>) -> Result<Self, IndicatorError> {
+        Self::with_periods(
+            series_ref,
+            DEFAULT_SENKOU_SPAN_B_BAR_COUNT,
+            DEFAULT_DISPLACEMENT,
+        )
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series))
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series))
+    }
+
+    /// 自定义中点线周期与平移 bar 数
+    pub fn with_periods(
+        series_ref: BarSeriesRef<S>,
+        bar_count: usize,
+        displacement: usize,
+    ) -> Result<Self, IndicatorError> {
+        let line = IchimokuLineIndicator::new(series_ref, bar_count)?;
+        Ok(Self::from_line(Arc::new(line), displacement))
+    }
+
+    /// 基于已有的中点线构造
+    pub fn from_line(line: Arc<IchimokuLineIndicator<T, S>>, displacement: usize) -> Self {
+        let unstable_bars = line.count_of_unstable_bars() + displacement;
+        let calculator = IchimokuSenkouSpanBCalculator {
+            line: Arc::clone(&line),
+            displacement,
+        };
+        Self {
+            cached: CachedIndicator::new_from_indicator(line, calculator),
+            unstable_bars,
+        }
+    }
+
+    /// index 处计算、向前平移后显示在 index + displacement 处的值
+    pub fn leading_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.calculator().line.get_value(index)
+    }
+
+    pub fn displacement(&self) -> usize {
+        self.cached.calculator().displacement
+    }
+}
+
+impl<T, S> Indicator for IchimokuSenkouSpanBIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
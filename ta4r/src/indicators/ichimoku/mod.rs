@@ -0,0 +1,38 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+pub mod ichimoku_chikou_span_indicator;
+pub mod ichimoku_line_indicator;
+pub mod ichimoku_senkou_span_a_indicator;
+pub mod ichimoku_senkou_span_b_indicator;
+
+/// 转换线（Tenkan-sen）默认周期
+pub const DEFAULT_TENKAN_SEN_BAR_COUNT: usize = 9;
+/// 基准线（Kijun-sen）默认周期
+pub const DEFAULT_KIJUN_SEN_BAR_COUNT: usize = 26;
+/// 先行带 B（Senkou Span B）默认周期
+pub const DEFAULT_SENKOU_SPAN_B_BAR_COUNT: usize = 52;
+/// 先行带向前、迟行带向后平移的默认 bar 数
+pub const DEFAULT_DISPLACEMENT: usize = 26;
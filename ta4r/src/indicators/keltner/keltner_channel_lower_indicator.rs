@@ -0,0 +1,183 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::atr_indicator::ATRIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::keltner::keltner_channel_middle_indicator::KeltnerChannelMiddleIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// KeltnerChannelLowerCalculator：中轨 - ratio * ATR
+pub struct KeltnerChannelLowerCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    middle: Arc<KeltnerChannelMiddleIndicator<T, S, I>>,
+    atr: Arc<ATRIndicator<T, S>>,
+    ratio: T,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for KeltnerChannelLowerCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            middle: Arc::clone(&self.middle),
+            atr: Arc::clone(&self.atr),
+            ratio: self.ratio.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for KeltnerChannelLowerCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let middle = self.middle.get_value(index)?;
+        let atr = self.atr.get_value(index)?;
+        Ok(middle.minus(&atr.multiplied_by(&self.ratio)))
+    }
+}
+
+/// 肯特纳通道下轨
+pub struct KeltnerChannelLowerIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, KeltnerChannelLowerCalculator<T, S, I>>,
+    unstable_bars: usize,
+}
+
+impl<T, S, I> Clone for KeltnerChannelLowerIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I> KeltnerChannelLowerIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 基于中轨的序列构造 ATR(atr_bar_count)
+    pub fn new(
+        middle: Arc<KeltnerChannelMiddleIndicator<T, S, I>>,
+        ratio: T,
+        atr_bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        if atr_bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+        let atr = Arc::new(ATRIndicator::new(middle.bar_series(), atr_bar_count));
+        Ok(Self::from_atr(middle, atr, ratio))
+    }
+
+    /// 基于已有的 ATR 构造，可与另一条轨道共享同一个 ATR
+    pub fn from_atr(
+        middle: Arc<KeltnerChannelMiddleIndicator<T, S, I>>,
+        atr: Arc<ATRIndicator<T, S>>,
+        ratio: T,
+    ) -> Self {
+        let unstable_bars = middle
+            .count_of_unstable_bars()
+            .max(atr.count_of_unstable_bars());
+        let calculator = KeltnerChannelLowerCalculator {
+            middle: Arc::clone(&middle),
+            atr,
+            ratio,
+            _phantom: PhantomData,
+        };
+        Self {
+            cached: CachedIndicator::new_from_indicator(middle, calculator),
+            unstable_bars,
+        }
+    }
+
+    pub fn ratio(&self) -> &T {
+        &self.cached.calculator().ratio
+    }
+
+    pub fn atr(&self) -> Arc<ATRIndicator<T, S>> {
+        Arc::clone(&self.cached.calculator().atr)
+    }
+}
+
+impl<T, S, I> Indicator for KeltnerChannelLowerIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -0,0 +1,134 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::averages::ema_indicator::EmaIndicator;
+use crate::indicators::helpers::typical_price_indicator::TypicalPriceIndicator;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// 肯特纳通道中轨：价格的 EMA（默认基于典型价格）
+pub struct KeltnerChannelMiddleIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    ema: EmaIndicator<T, S, I>,
+}
+
+impl<T, S, I> Clone for KeltnerChannelMiddleIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            ema: self.ema.clone(),
+        }
+    }
+}
+
+impl<T, S> KeltnerChannelMiddleIndicator<T, S, TypicalPriceIndicator<T, S>>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造：典型价格 (high + low + close) / 3 的 EMA
+    pub fn from_series(
+        series_ref: BarSeriesRef<S>,
+        ema_bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        Self::new(
+            Arc::new(TypicalPriceIndicator::new(series_ref)),
+            ema_bar_count,
+        )
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(
+        series: Arc<RwLock<S>>,
+        ema_bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        Self::from_series(BarSeriesRef::Shared(series), ema_bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, ema_bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::from_series(BarSeriesRef::Mut(series), ema_bar_count)
+    }
+}
+
+impl<T, S, I> KeltnerChannelMiddleIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 在任意价格指标上计算 EMA 中轨
+    pub fn new(indicator: Arc<I>, ema_bar_count: usize) -> Result<Self, IndicatorError> {
+        if ema_bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+        Ok(Self {
+            ema: EmaIndicator::new(indicator, ema_bar_count),
+        })
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.ema.bar_count()
+    }
+}
+
+impl<T, S, I> Indicator for KeltnerChannelMiddleIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.ema.get_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.ema.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.ema.count_of_unstable_bars()
+    }
+}
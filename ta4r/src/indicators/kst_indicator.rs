@@ -0,0 +1,190 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::averages::sma_indicator::SmaIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::roc_indicator::RocIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+type SmoothedRoc<T, S, I> = SmaIndicator<T, S, RocIndicator<T, S, I>>;
+
+/// KstCalculator：四个平滑 ROC 按 1、2、3、4 加权求和
+pub struct KstCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    smoothed_rocs: [Arc<SmoothedRoc<T, S, I>>; 4],
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for KstCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            smoothed_rocs: self.smoothed_rocs.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for KstCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let mut kst = T::zero();
+        for (i, smoothed_roc) in self.smoothed_rocs.iter().enumerate() {
+            let weight = T::from_usize(i + 1).unwrap_or_else(T::one);
+            kst = kst.plus(&weight.multiplied_by(&smoothed_roc.get_value(index)?));
+        }
+        Ok(kst)
+    }
+}
+
+/// 确然指标（Know Sure Thing，Pring）
+///
+/// KST = 1 * SMA(ROC1) + 2 * SMA(ROC2) + 3 * SMA(ROC3) + 4 * SMA(ROC4)，
+/// 默认 ROC 周期 (10, 15, 20, 30)，SMA 周期 (10, 10, 10, 15)
+pub struct KstIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, KstCalculator<T, S, I>>,
+    unstable_bars: usize,
+}
+
+impl<T, S, I> Clone for KstIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I> KstIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 使用标准周期构造
+    pub fn with_defaults(indicator: Arc<I>) -> Result<Self, IndicatorError> {
+        Self::new(indicator, [10, 15, 20, 30], [10, 10, 10, 15])
+    }
+
+    /// roc_bar_counts 与 sma_bar_counts 按权重 1 到 4 一一对应
+    pub fn new(
+        indicator: Arc<I>,
+        roc_bar_counts: [usize; 4],
+        sma_bar_counts: [usize; 4],
+    ) -> Result<Self, IndicatorError> {
+        if roc_bar_counts.contains(&0) || sma_bar_counts.contains(&0) {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+
+        let smoothed_roc = |k: usize| -> Result<Arc<SmoothedRoc<T, S, I>>, IndicatorError> {
+            let roc = RocIndicator::new(Arc::clone(&indicator), roc_bar_counts[k])?;
+            Ok(Arc::new(SmaIndicator::new(
+                Arc::new(roc),
+                sma_bar_counts[k],
+            )))
+        };
+        let calculator = KstCalculator {
+            smoothed_rocs: [
+                smoothed_roc(0)?,
+                smoothed_roc(1)?,
+                smoothed_roc(2)?,
+                smoothed_roc(3)?,
+            ],
+            _phantom: PhantomData,
+        };
+        // 取四条平滑 ROC 中最长的预热期
+        let unstable_bars = indicator.count_of_unstable_bars()
+            + roc_bar_counts
+                .iter()
+                .zip(sma_bar_counts.iter())
+                .map(|(roc, sma)| roc + sma - 1)
+                .max()
+                .unwrap_or(0);
+        Ok(Self {
+            cached: CachedIndicator::new_from_indicator(indicator, calculator),
+            unstable_bars,
+        })
+    }
+}
+
+impl<T, S, I> Indicator for KstIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -0,0 +1,162 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::macd_signal_indicator::MacdSignalIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// MacdHistogramCalculator：MACD - 信号线
+pub struct MacdHistogramCalculator<T, S, M>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    M: Indicator<Num = T, Output = T, Series = S>,
+{
+    macd: Arc<M>,
+    signal: Arc<MacdSignalIndicator<T, S, M>>,
+    _phantom: PhantomData<(T, S)>,
+}
+
+impl<T, S, M> Clone for MacdHistogramCalculator<T, S, M>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    M: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            macd: Arc::clone(&self.macd),
+            signal: Arc::clone(&self.signal),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, M> IndicatorCalculator<T, S> for MacdHistogramCalculator<T, S, M>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    M: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let macd = self.macd.get_value(index)?;
+        let signal = self.signal.get_value(index)?;
+        Ok(macd.minus(&signal))
+    }
+}
+
+/// MACD 柱状图：MACD（或 PPO）与其信号线之差
+pub struct MacdHistogramIndicator<T, S, M>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    M: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, MacdHistogramCalculator<T, S, M>>,
+    signal: Arc<MacdSignalIndicator<T, S, M>>,
+}
+
+impl<T, S, M> Clone for MacdHistogramIndicator<T, S, M>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    M: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            signal: Arc::clone(&self.signal),
+        }
+    }
+}
+
+impl<T, S, M> MacdHistogramIndicator<T, S, M>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    M: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 在 macd 上构造周期为 signal_bar_count 的信号线
+    pub fn new(macd: Arc<M>, signal_bar_count: usize) -> Self {
+        let signal = Arc::new(MacdSignalIndicator::new(
+            Arc::clone(&macd),
+            signal_bar_count,
+        ));
+        Self::from_signal(macd, signal)
+    }
+
+    /// 复用已有的信号线
+    pub fn from_signal(macd: Arc<M>, signal: Arc<MacdSignalIndicator<T, S, M>>) -> Self {
+        let calculator = MacdHistogramCalculator {
+            macd: Arc::clone(&macd),
+            signal: Arc::clone(&signal),
+            _phantom: PhantomData,
+        };
+        Self {
+            cached: CachedIndicator::new_from_indicator(macd, calculator),
+            signal,
+        }
+    }
+
+    pub fn signal(&self) -> Arc<MacdSignalIndicator<T, S, M>> {
+        Arc::clone(&self.signal)
+    }
+}
+
+impl<T, S, M> Indicator for MacdHistogramIndicator<T, S, M>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    M: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.signal.count_of_unstable_bars()
+    }
+}
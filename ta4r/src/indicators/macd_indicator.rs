@@ -0,0 +1,189 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::averages::ema_indicator::EmaIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// MacdCalculator：短周期 EMA - 长周期 EMA
+pub struct MacdCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    short_term_ema: Arc<EmaIndicator<T, S, I>>,
+    long_term_ema: Arc<EmaIndicator<T, S, I>>,
+    _phantom: PhantomData<(T, S)>,
+}
+
+impl<T, S, I> Clone for MacdCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            short_term_ema: Arc::clone(&self.short_term_ema),
+            long_term_ema: Arc::clone(&self.long_term_ema),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for MacdCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let short_term = self.short_term_ema.get_value(index)?;
+        let long_term = self.long_term_ema.get_value(index)?;
+        Ok(short_term.minus(&long_term))
+    }
+}
+
+/// Moving Average Convergence Divergence (MACD)
+///
+/// MACD = EMA(short) - EMA(long)，默认周期 (12, 26)
+pub struct MacdIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, MacdCalculator<T, S, I>>,
+    short_term_ema: Arc<EmaIndicator<T, S, I>>,
+    long_term_ema: Arc<EmaIndicator<T, S, I>>,
+}
+
+impl<T, S, I> Clone for MacdIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            short_term_ema: Arc::clone(&self.short_term_ema),
+            long_term_ema: Arc::clone(&self.long_term_ema),
+        }
+    }
+}
+
+impl<T, S, I> MacdIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 使用标准周期 (12, 26) 构造
+    pub fn with_default_periods(indicator: Arc<I>) -> Result<Self, IndicatorError> {
+        Self::new(indicator, 12, 26)
+    }
+
+    /// 构造函数
+    ///
+    /// # Arguments
+    /// * `indicator` - 基础指标（通常为收盘价）
+    /// * `short_bar_count` - 短周期 EMA
+    /// * `long_bar_count` - 长周期 EMA，必须大于短周期
+    pub fn new(
+        indicator: Arc<I>,
+        short_bar_count: usize,
+        long_bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        if short_bar_count == 0 || short_bar_count >= long_bar_count {
+            return Err(IndicatorError::CalculationError {
+                message: format!(
+                    "Long term period count ({}) must be greater than short term period count ({})",
+                    long_bar_count, short_bar_count
+                ),
+            });
+        }
+
+        let short_term_ema = Arc::new(EmaIndicator::new(Arc::clone(&indicator), short_bar_count));
+        let long_term_ema = Arc::new(EmaIndicator::new(Arc::clone(&indicator), long_bar_count));
+        let calculator = MacdCalculator {
+            short_term_ema: Arc::clone(&short_term_ema),
+            long_term_ema: Arc::clone(&long_term_ema),
+            _phantom: PhantomData,
+        };
+
+        Ok(Self {
+            cached: CachedIndicator::new_from_indicator(indicator, calculator),
+            short_term_ema,
+            long_term_ema,
+        })
+    }
+
+    pub fn short_term_ema(&self) -> Arc<EmaIndicator<T, S, I>> {
+        Arc::clone(&self.short_term_ema)
+    }
+
+    pub fn long_term_ema(&self) -> Arc<EmaIndicator<T, S, I>> {
+        Arc::clone(&self.long_term_ema)
+    }
+}
+
+impl<T, S, I> Indicator for MacdIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    /// 由长周期 EMA 决定
+    fn count_of_unstable_bars(&self) -> usize {
+        self.long_term_ema.count_of_unstable_bars()
+    }
+}
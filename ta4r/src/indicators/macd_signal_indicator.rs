@@ -0,0 +1,105 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::averages::ema_indicator::EmaIndicator;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use std::sync::Arc;
+
+/// MACD 信号线：MACD（或 PPO）的 EMA，默认周期 9
+pub struct MacdSignalIndicator<T, S, M>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    M: Indicator<Num = T, Output = T, Series = S>,
+{
+    ema: EmaIndicator<T, S, M>,
+    unstable_bars: usize,
+}
+
+impl<T, S, M> Clone for MacdSignalIndicator<T, S, M>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    M: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            ema: self.ema.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S, M> MacdSignalIndicator<T, S, M>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    M: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 使用标准周期 9 构造
+    pub fn with_default_period(macd: Arc<M>) -> Self {
+        Self::new(macd, 9)
+    }
+
+    pub fn new(macd: Arc<M>, bar_count: usize) -> Self {
+        let unstable_bars = macd.count_of_unstable_bars() + bar_count;
+        Self {
+            ema: EmaIndicator::new(macd, bar_count),
+            unstable_bars,
+        }
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.ema.bar_count()
+    }
+}
+
+impl<T, S, M> Indicator for MacdSignalIndicator<T, S, M>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    M: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.ema.get_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.ema.bar_series()
+    }
+
+    /// MACD 的不稳定期 + 信号线周期
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -31,13 +31,55 @@ use crate::num::TrNum;
 use crate::num::types::NumError;
 
 pub mod abstract_indicator;
+pub mod adx_indicator;
+pub mod aroon;
 pub mod atr_indicator;
 pub mod averages;
+pub mod bill_williams;
+pub mod bollinger;
 pub mod cached_indicator;
+pub mod candles;
+pub mod cci_indicator;
+pub mod chandelier;
+pub mod cmo_indicator;
+pub mod connors_rsi_indicator;
+pub mod donchian;
+pub mod dpo_indicator;
+pub mod dx_indicator;
 pub mod helpers;
+pub mod ichimoku;
+pub mod keltner;
+pub mod kst_indicator;
+pub mod kst_signal_indicator;
+pub mod macd_histogram_indicator;
+pub mod macd_indicator;
+pub mod macd_signal_indicator;
+pub mod minus_di_indicator;
+pub mod minus_dm_indicator;
+pub mod momentum_indicator;
 pub mod numeric;
+pub mod parabolic_sar_indicator;
+pub mod pivot_points;
+pub mod plus_di_indicator;
+pub mod plus_dm_indicator;
+pub mod ppo_indicator;
 pub mod recursive_cached_indicator;
+pub mod regression;
+pub mod roc_indicator;
+pub mod rsi_indicator;
+pub mod statistics;
+pub mod stochastic_oscillator_d_indicator;
+pub mod stochastic_oscillator_k_indicator;
+pub mod stochastic_rsi_indicator;
+pub mod supertrend_indicator;
+pub mod swing;
+pub mod trix_indicator;
 pub mod types;
+pub mod volatility;
+pub mod volume;
+pub mod vortex;
+pub mod vwap;
+pub mod williams_r_indicator;
 
 pub trait Indicator: Clone {
     type Num: TrNum + 'static;
@@ -0,0 +1,291 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::{BarPrice, BaseIndicator, bar_price};
+use crate::indicators::recursive_cached_indicator::RecursiveCachedIndicator;
+use crate::indicators::types::{IndicatorError, RecursiveIndicatorCalculator};
+use crate::num::{NumFactory, TrNum};
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// Parabolic SAR 在某根 bar 上的完整状态
+#[derive(Clone, Debug)]
+pub struct ParabolicSarState<T: TrNum> {
+    /// 当前 SAR 值
+    pub sar: T,
+    /// 当前趋势的极值点：上升趋势为最高价，下降趋势为最低价
+    pub extreme_point: T,
+    /// 当前加速因子
+    pub acceleration: T,
+    /// 是否处于上升趋势
+    pub is_uptrend: bool,
+}
+
+/// ParabolicSarCalculator：按 Wilder 规则由前一根 bar 的状态递推当前状态
+#[derive(Clone)]
+pub struct ParabolicSarCalculator<T: TrNum> {
+    start: T,
+    increment: T,
+    maximum: T,
+}
+
+impl<T, S> RecursiveIndicatorCalculator<T, S> for ParabolicSarCalculator<T>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = ParabolicSarState<T>;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+        previous: Option<&Self::Output>,
+    ) -> Result<Self::Output, IndicatorError> {
+        base.bar_series().with_ref(|s| {
+            let high = |i: usize| bar_price(s, i, BarPrice::High);
+            let low = |i: usize| bar_price(s, i, BarPrice::Low);
+            let close = |i: usize| bar_price(s, i, BarPrice::Close);
+
+            let first = s.get_begin_index().unwrap_or(0);
+            let Some(previous) = previous.filter(|_| index > first) else {
+                // 第一根 bar 无法判断趋势，先假定上升趋势，SAR 取最低价
+                return Ok(ParabolicSarState {
+                    sar: low(index)?,
+                    extreme_point: high(index)?,
+                    acceleration: self.start.clone(),
+                    is_uptrend: true,
+                });
+            };
+
+            if index == first + 1 {
+                // 第二根 bar 按收盘价方向确定初始趋势，SAR 取前一根 bar 的反向极值
+                return Ok(if close(index)?.is_greater_than_or_equal(&close(first)?) {
+                    ParabolicSarState {
+                        sar: low(first)?,
+                        extreme_point: high(index)?,
+                        acceleration: self.start.clone(),
+                        is_uptrend: true,
+                    }
+                } else {
+                    ParabolicSarState {
+                        sar: high(first)?,
+                        extreme_point: low(index)?,
+                        acceleration: self.start.clone(),
+                        is_uptrend: false,
+                    }
+                });
+            }
+
+            let next_sar = previous.sar.plus(
+                &previous
+                    .acceleration
+                    .multiplied_by(&previous.extreme_point.minus(&previous.sar)),
+            );
+            let next_acceleration = previous
+                .acceleration
+                .plus(&self.increment)
+                .min(&self.maximum);
+
+            if previous.is_uptrend {
+                // SAR 不能高于前两根 bar 的最低价
+                let sar = next_sar.min(&low(index - 1)?).min(&low(index - 2)?);
+                let current_low = low(index)?;
+                if current_low.is_less_than(&sar) {
+                    // 跌破 SAR：反转为下降趋势，SAR 取上一趋势的极值点
+                    return Ok(ParabolicSarState {
+                        sar: previous.extreme_point.clone(),
+                        extreme_point: current_low,
+                        acceleration: self.start.clone(),
+                        is_uptrend: false,
+                    });
+                }
+                let current_high = high(index)?;
+                let (extreme_point, acceleration) =
+                    if current_high.is_greater_than(&previous.extreme_point) {
+                        (current_high, next_acceleration)
+                    } else {
+                        (
+                            previous.extreme_point.clone(),
+                            previous.acceleration.clone(),
+                        )
+                    };
+                Ok(ParabolicSarState {
+                    sar,
+                    extreme_point,
+                    acceleration,
+                    is_uptrend: true,
+                })
+            } else {
+                // SAR 不能低于前两根 bar 的最高价
+                let sar = next_sar.max(&high(index - 1)?).max(&high(index - 2)?);
+                let current_high = high(index)?;
+                if current_high.is_greater_than(&sar) {
+                    // 突破 SAR：反转为上升趋势，SAR 取上一趋势的极值点
+                    return Ok(ParabolicSarState {
+                        sar: previous.extreme_point.clone(),
+                        extreme_point: current_high,
+                        acceleration: self.start.clone(),
+                        is_uptrend: true,
+                    });
+                }
+                let current_low = low(index)?;
+                let (extreme_point, acceleration) =
+                    if current_low.is_less_than(&previous.extreme_point) {
+                        (current_low, next_acceleration)
+                    } else {
+                        (
+                            previous.extreme_point.clone(),
+                            previous.acceleration.clone(),
+                        )
+                    };
+                Ok(ParabolicSarState {
+                    sar,
+                    extreme_point,
+                    acceleration,
+                    is_uptrend: false,
+                })
+            }
+        })?
+    }
+}
+
+/// Parabolic SAR（抛物线转向指标）
+///
+/// - 加速因子从 start 开始，每创新极值增加 increment，最大为 maximum
+/// - 价格穿越 SAR 时趋势反转，SAR 重置为上一趋势的极值点
+/// - 可作为入场信号（收盘价下穿 / 上穿 SAR），也可作为跟踪止损价位
+pub struct ParabolicSarIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    inner: RecursiveCachedIndicator<T, S, ParabolicSarCalculator<T>>,
+}
+
+impl<T, S> Clone for ParabolicSarIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T, S> ParabolicSarIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 使用默认加速因子构造：start = 0.02，increment = 0.02，maximum = 0.2
+    pub fn new(series_ref: BarSeriesRef<S>) -> Result<Self, IndicatorError> {
+        let factory = series_ref
+            .with_ref(|s| s.num_factory())
+            .map_err(|e| IndicatorError::Other { message: e })?;
+        Self::with_factors(
+            series_ref,
+            factory.num_of_f64(0.02),
+            factory.num_of_f64(0.02),
+            factory.num_of_f64(0.2),
+        )
+    }
+
+    /// 自定义加速因子构造，要求 0 < start <= maximum 且 increment > 0
+    pub fn with_factors(
+        series_ref: BarSeriesRef<S>,
+        start: T,
+        increment: T,
+        maximum: T,
+    ) -> Result<Self, IndicatorError> {
+        let zero = T::zero();
+        if !start.is_greater_than(&zero)
+            || !increment.is_greater_than(&zero)
+            || start.is_greater_than(&maximum)
+        {
+            return Err(IndicatorError::CalculationError {
+                message: "Acceleration factors must satisfy 0 < start <= maximum and increment > 0"
+                    .to_string(),
+            });
+        }
+        let calculator = ParabolicSarCalculator {
+            start,
+            increment,
+            maximum,
+        };
+        Ok(Self {
+            inner: RecursiveCachedIndicator::new(series_ref, calculator),
+        })
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series))
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series))
+    }
+
+    /// 获取 index 处的完整状态（趋势方向、极值点、加速因子）
+    pub fn state(&self, index: usize) -> Result<ParabolicSarState<T>, IndicatorError> {
+        self.inner.get_value(index)
+    }
+
+    /// index 处是否处于上升趋势（SAR 位于价格下方）
+    pub fn is_uptrend(&self, index: usize) -> Result<bool, IndicatorError> {
+        Ok(self.state(index)?.is_uptrend)
+    }
+}
+
+impl<T, S> Indicator for ParabolicSarIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        Ok(self.state(index)?.sar)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.inner.bar_series()
+    }
+
+    /// 第二根 bar 才能确定初始趋势
+    fn count_of_unstable_bars(&self) -> usize {
+        2
+    }
+}
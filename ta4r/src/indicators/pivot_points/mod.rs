@@ -0,0 +1,172 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+pub mod pivot_level_indicator;
+pub mod pivot_point_indicator;
+
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+
+/// 枢轴点的计算方法
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PivotMethod {
+    /// P = (H + L + C) / 3，R1 = 2P - L，R2 = P + (H - L)，R3 = H + 2(P - L)
+    Standard,
+    /// P = (H + L + C) / 3，R/S = P ± 0.382 / 0.618 / 1.0 * (H - L)
+    Fibonacci,
+    /// R/S = C ± 1.1 * (H - L) / 12、/ 6、/ 4
+    Camarilla,
+    /// P = (H + L + 2C) / 4，支撑与阻力同 Standard
+    Woodie,
+    /// 按前一周期开盘与收盘的关系加权，只有 R1 / S1
+    DeMark,
+}
+
+/// 支撑 / 阻力位
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PivotLevel {
+    Resistance3,
+    Resistance2,
+    Resistance1,
+    Support1,
+    Support2,
+    Support3,
+}
+
+/// 由前一周期的 OHLC 得到的枢轴点与支撑 / 阻力位
+#[derive(Clone, Debug)]
+pub struct PivotLevels<T> {
+    pub pivot: T,
+    /// R1、R2、R3，DeMark 只有 R1
+    pub resistances: Vec<T>,
+    /// S1、S2、S3，DeMark 只有 S1
+    pub supports: Vec<T>,
+}
+
+impl<T> PivotLevels<T>
+where
+    T: TrNum + Clone + 'static,
+{
+    /// 获取指定的支撑 / 阻力位，计算方法没有定义该价位时返回 None
+    pub fn level(&self, level: PivotLevel) -> Option<&T> {
+        match level {
+            PivotLevel::Resistance1 => self.resistances.first(),
+            PivotLevel::Resistance2 => self.resistances.get(1),
+            PivotLevel::Resistance3 => self.resistances.get(2),
+            PivotLevel::Support1 => self.supports.first(),
+            PivotLevel::Support2 => self.supports.get(1),
+            PivotLevel::Support3 => self.supports.get(2),
+        }
+    }
+}
+
+impl PivotMethod {
+    /// 该方法是否定义了指定价位
+    pub fn defines(&self, level: PivotLevel) -> bool {
+        *self != PivotMethod::DeMark
+            || matches!(level, PivotLevel::Resistance1 | PivotLevel::Support1)
+    }
+
+    /// 由前一周期的最高价、最低价、开盘价与收盘价计算各价位；num 用于构造常数
+    pub(crate) fn levels<T>(
+        &self,
+        high: T,
+        low: T,
+        open: T,
+        close: T,
+        num: impl Fn(f64) -> T,
+    ) -> Result<PivotLevels<T>, IndicatorError>
+    where
+        T: TrNum + Clone + 'static,
+    {
+        let range = high.minus(&low);
+        let classic_pivot = high.plus(&low).plus(&close).divided_by(&num(3.0))?;
+        // Standard 与 Woodie 共用的支撑 / 阻力公式
+        let classic = |pivot: T| {
+            let double = pivot.multiplied_by(&num(2.0));
+            PivotLevels {
+                resistances: vec![
+                    double.minus(&low),
+                    pivot.plus(&range),
+                    high.plus(&pivot.minus(&low).multiplied_by(&num(2.0))),
+                ],
+                supports: vec![
+                    double.minus(&high),
+                    pivot.minus(&range),
+                    low.minus(&high.minus(&pivot).multiplied_by(&num(2.0))),
+                ],
+                pivot,
+            }
+        };
+
+        match self {
+            PivotMethod::Standard => Ok(classic(classic_pivot)),
+            PivotMethod::Woodie => {
+                let pivot = high
+                    .plus(&low)
+                    .plus(&close.multiplied_by(&num(2.0)))
+                    .divided_by(&num(4.0))?;
+                Ok(classic(pivot))
+            }
+            PivotMethod::Fibonacci => {
+                let offsets: Vec<T> = [0.382, 0.618, 1.0]
+                    .iter()
+                    .map(|factor| range.multiplied_by(&num(*factor)))
+                    .collect();
+                Ok(PivotLevels {
+                    resistances: offsets.iter().map(|o| classic_pivot.plus(o)).collect(),
+                    supports: offsets.iter().map(|o| classic_pivot.minus(o)).collect(),
+                    pivot: classic_pivot,
+                })
+            }
+            PivotMethod::Camarilla => {
+                let offsets: Vec<T> = [12.0, 6.0, 4.0]
+                    .iter()
+                    .map(|divisor| range.multiplied_by(&num(1.1)).divided_by(&num(*divisor)))
+                    .collect::<Result<_, _>>()?;
+                Ok(PivotLevels {
+                    resistances: offsets.iter().map(|o| close.plus(o)).collect(),
+                    supports: offsets.iter().map(|o| close.minus(o)).collect(),
+                    pivot: classic_pivot,
+                })
+            }
+            PivotMethod::DeMark => {
+                let x = if close.is_less_than(&open) {
+                    high.plus(&low.multiplied_by(&num(2.0))).plus(&close)
+                } else if close.is_greater_than(&open) {
+                    high.multiplied_by(&num(2.0)).plus(&low).plus(&close)
+                } else {
+                    high.plus(&low).plus(&close.multiplied_by(&num(2.0)))
+                };
+                let half = x.divided_by(&num(2.0))?;
+                Ok(PivotLevels {
+                    pivot: x.divided_by(&num(4.0))?,
+                    resistances: vec![half.minus(&low)],
+                    supports: vec![half.minus(&high)],
+                })
+            }
+        }
+    }
+}
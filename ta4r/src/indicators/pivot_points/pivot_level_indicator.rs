@@ -0,0 +1,113 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::pivot_points::PivotLevel;
+use crate::indicators::pivot_points::pivot_point_indicator::PivotPointIndicator;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use std::sync::Arc;
+
+/// 枢轴点的支撑 / 阻力位（S1–S3、R1–R3）
+///
+/// 与 PivotPointIndicator 共享同一份计算结果，可直接用于 OverIndicatorRule / UnderIndicatorRule
+pub struct PivotLevelIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    pivot: Arc<PivotPointIndicator<T, S>>,
+    level: PivotLevel,
+}
+
+impl<T, S> Clone for PivotLevelIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pivot: Arc::clone(&self.pivot),
+            level: self.level,
+        }
+    }
+}
+
+impl<T, S> PivotLevelIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 计算方法没有定义该价位时返回错误（DeMark 只有 R1 / S1）
+    pub fn new(
+        pivot: Arc<PivotPointIndicator<T, S>>,
+        level: PivotLevel,
+    ) -> Result<Self, IndicatorError> {
+        if !pivot.method().defines(level) {
+            return Err(IndicatorError::CalculationError {
+                message: format!(
+                    "{:?} pivot points do not define {:?}",
+                    pivot.method(),
+                    level
+                ),
+            });
+        }
+        Ok(Self { pivot, level })
+    }
+
+    pub fn level(&self) -> PivotLevel {
+        self.level
+    }
+}
+
+impl<T, S> Indicator for PivotLevelIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        let levels = self.pivot.levels(index)?;
+        levels
+            .level(self.level)
+            .cloned()
+            .ok_or_else(|| IndicatorError::CalculationError {
+                message: format!("{:?} is not available at index {}", self.level, index),
+            })
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.pivot.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.pivot.count_of_unstable_bars()
+    }
+}
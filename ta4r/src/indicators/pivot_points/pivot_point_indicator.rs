@@ -0,0 +1,195 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::{Bar, BarSeries};
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::{BarPrice, BaseIndicator, bar_at, bar_price};
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::pivot_points::{PivotLevels, PivotMethod};
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::indicators::vwap::AnchorPeriod;
+use crate::num::{NumFactory, TrNum};
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// PivotPointCalculator：按 bar 的开始时间分组，取前一个日 / 周 / 月的 OHLC 计算各价位
+#[derive(Clone)]
+pub struct PivotPointCalculator {
+    period: AnchorPeriod,
+    method: PivotMethod,
+}
+
+impl<T, S> IndicatorCalculator<T, S> for PivotPointCalculator
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = PivotLevels<T>;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        base.bar_series().with_ref(|s| {
+            let same_period = |a: usize, b: usize| -> Result<bool, IndicatorError> {
+                let (a, b) = (
+                    bar_at(s, a)?.get_begin_time(),
+                    bar_at(s, b)?.get_begin_time(),
+                );
+                Ok(self.period.same_period(a, b))
+            };
+
+            let first = s.get_begin_index().unwrap_or(0);
+            let index = index.max(first);
+
+            // 当前周期的第一根 bar
+            let mut start = index;
+            while start > first && same_period(start - 1, index)? {
+                start -= 1;
+            }
+            if start == first {
+                // 序列中没有更早的周期，下一个周期开始才有值
+                let end = s.get_end_index().unwrap_or(index);
+                let mut available_from = index + 1;
+                while available_from <= end && same_period(available_from, index)? {
+                    available_from += 1;
+                }
+                return Err(IndicatorError::NotAvailable {
+                    index,
+                    available_from,
+                });
+            }
+
+            // 前一个周期：[previous_start, start)
+            let mut previous_start = start - 1;
+            while previous_start > first && same_period(previous_start - 1, start - 1)? {
+                previous_start -= 1;
+            }
+
+            let mut high = bar_price(s, previous_start, BarPrice::High)?;
+            let mut low = bar_price(s, previous_start, BarPrice::Low)?;
+            for i in previous_start + 1..start {
+                high = high.max(&bar_price(s, i, BarPrice::High)?);
+                low = low.min(&bar_price(s, i, BarPrice::Low)?);
+            }
+            let open = bar_price(s, previous_start, BarPrice::Open)?;
+            let close = bar_price(s, start - 1, BarPrice::Close)?;
+
+            let factory = s.num_factory();
+            self.method
+                .levels(high, low, open, close, |value| factory.num_of_f64(value))
+        })?
+    }
+}
+
+/// 枢轴点（Pivot Point）
+///
+/// - 以 bar 的开始时间按日 / 周 / 月分组，使用前一个周期的最高价、最低价、开盘价与收盘价
+/// - 结果映射回当前周期的每一根 bar，第一个周期没有前值，返回 NotAvailable
+/// - 支撑 / 阻力位通过 PivotLevelIndicator 获取
+pub struct PivotPointIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, PivotPointCalculator>,
+}
+
+impl<T, S> Clone for PivotPointIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+        }
+    }
+}
+
+impl<T, S> PivotPointIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，直接传入 BarSeriesRef
+    pub fn new(series_ref: BarSeriesRef<S>, period: AnchorPeriod, method: PivotMethod) -> Self {
+        Self {
+            cached: CachedIndicator::new_from_series(
+                series_ref,
+                PivotPointCalculator { period, method },
+            ),
+        }
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>, period: AnchorPeriod, method: PivotMethod) -> Self {
+        Self::new(BarSeriesRef::Shared(series), period, method)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, period: AnchorPeriod, method: PivotMethod) -> Self {
+        Self::new(BarSeriesRef::Mut(series), period, method)
+    }
+
+    /// 获取 index 处的枢轴点与全部支撑 / 阻力位
+    pub fn levels(&self, index: usize) -> Result<PivotLevels<T>, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    pub fn period(&self) -> AnchorPeriod {
+        self.cached.calculator().period
+    }
+
+    pub fn method(&self) -> PivotMethod {
+        self.cached.calculator().method
+    }
+}
+
+impl<T, S> Indicator for PivotPointIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        Ok(self.levels(index)?.pivot)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    /// 可用的起点取决于 bar 的时间，第一个周期内返回 NotAvailable
+    fn count_of_unstable_bars(&self) -> usize {
+        0
+    }
+}
@@ -0,0 +1,152 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::{BarPrice, BaseIndicator, bar_price};
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// 计算 index 处的 (上升动向, 下降动向)：high - 前高，前低 - low
+///
+/// index 为 0 时没有前一根 bar，返回 None
+pub(crate) fn directional_moves<T, S>(
+    base: &BaseIndicator<T, S>,
+    index: usize,
+) -> Result<Option<(T, T)>, IndicatorError>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    if index == 0 {
+        return Ok(None);
+    }
+    base.bar_series().with_ref(|s| {
+        let high = |i: usize| bar_price(s, i, BarPrice::High);
+        let low = |i: usize| bar_price(s, i, BarPrice::Low);
+        let up_move = high(index)?.minus(&high(index - 1)?);
+        let down_move = low(index - 1)?.minus(&low(index)?);
+        Ok(Some((up_move, down_move)))
+    })?
+}
+
+/// PlusDmIndicatorCalculator：正向动向 +DM：上升动向大于下降动向且为正时取上升动向，否则为 0
+#[derive(Clone)]
+pub struct PlusDmIndicatorCalculator;
+
+impl<T, S> IndicatorCalculator<T, S> for PlusDmIndicatorCalculator
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let Some((up_move, down_move)) = directional_moves(base, index)? else {
+            return Ok(T::zero());
+        };
+        if up_move.is_greater_than(&down_move) && up_move.is_positive() {
+            Ok(up_move)
+        } else {
+            Ok(T::zero())
+        }
+    }
+}
+
+/// PlusDmIndicator：正向动向 +DM：上升动向大于下降动向且为正时取上升动向，否则为 0
+pub struct PlusDmIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, PlusDmIndicatorCalculator>,
+}
+
+impl<T, S> Clone for PlusDmIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+        }
+    }
+}
+
+impl<T, S> PlusDmIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，直接传入 BarSeriesRef
+    pub fn new(series_ref: BarSeriesRef<S>) -> Self {
+        Self {
+            cached: CachedIndicator::new_from_series(series_ref, PlusDmIndicatorCalculator),
+        }
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>) -> Self {
+        Self::new(BarSeriesRef::Shared(series))
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>) -> Self {
+        Self::new(BarSeriesRef::Mut(series))
+    }
+}
+
+impl<T, S> Indicator for PlusDmIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    /// 需要前一根 bar
+    fn count_of_unstable_bars(&self) -> usize {
+        1
+    }
+}
@@ -27,20 +27,33 @@ use crate::bar::builder::types::BarSeriesRef;
 use crate::bar::types::BarSeries;
 use crate::indicators::Indicator;
 use crate::indicators::abstract_indicator::BaseIndicator;
-use crate::indicators::cached_indicator::CachedIndicator;
-use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::indicators::types::{IndicatorCalculator, IndicatorError, RecursiveIndicatorCalculator};
 use crate::num::TrNum;
 use parking_lot::RwLock;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
 use std::sync::Arc;
 
-const RECURSION_THRESHOLD: usize = 100;
-
+/// 把不读取前值的 IndicatorCalculator 包装为 RecursiveIndicatorCalculator
+///
+/// 旧版 RecursiveCachedIndicator 使用 IndicatorCalculator 与递归阈值，保留此包装便于迁移；
+/// 新代码应直接实现 RecursiveIndicatorCalculator
+#[deprecated(
+    note = "implement RecursiveIndicatorCalculator instead; the threshold is no longer used"
+)]
 pub struct RecursiveCalcWrapper<C> {
     pub(crate) inner: C,
     pub(crate) threshold: usize,
 }
 
+#[allow(deprecated)]
+impl<C> RecursiveCalcWrapper<C> {
+    pub fn new(inner: C, threshold: usize) -> Self {
+        Self { inner, threshold }
+    }
+}
+
+#[allow(deprecated)]
 impl<C> Clone for RecursiveCalcWrapper<C>
 where
     C: Clone,
@@ -53,7 +66,8 @@ where
     }
 }
 
-impl<'a, T, S, C> IndicatorCalculator<T, S> for RecursiveCalcWrapper<C>
+#[allow(deprecated)]
+impl<T, S, C> RecursiveIndicatorCalculator<T, S> for RecursiveCalcWrapper<C>
 where
     T: TrNum + Clone + 'static,
     S: BarSeries<T> + 'static,
@@ -65,30 +79,42 @@ where
         &self,
         base: &BaseIndicator<T, S>,
         index: usize,
+        _previous: Option<&Self::Output>,
     ) -> Result<Self::Output, IndicatorError> {
-        // 不负责递归预计算，直接调用内层计算器
         self.inner.calculate(base, index)
     }
 }
 
+/// 递归缓存指标：index 的值依赖于自身 index - 1 的值（如 EMA、SAR）
+///
+/// - 缓存从第一个未被移除的 bar 开始连续存放，按顺序迭代补齐，不会产生深递归
+/// - 与 CachedIndicator 一致，最新的 bar 不缓存
+/// - 已被移除的 bar 的缓存随序列一起丢弃
 pub struct RecursiveCachedIndicator<T, S, C>
 where
     T: TrNum + Clone + 'static,
     S: BarSeries<T> + 'static,
-    C: IndicatorCalculator<T, S> + Clone,
+    C: RecursiveIndicatorCalculator<T, S> + Clone,
 {
-    pub(crate) cached: CachedIndicator<T, S, RecursiveCalcWrapper<C>>,
+    pub(crate) base: BaseIndicator<T, S>,
+    pub(crate) calculator: C,
+    results: RefCell<VecDeque<C::Output>>,
+    /// results[0] 对应的序列索引
+    first_index: Cell<usize>,
 }
 
 impl<T, S, C> Clone for RecursiveCachedIndicator<T, S, C>
 where
     T: TrNum + Clone + 'static,
     S: BarSeries<T> + 'static,
-    C: IndicatorCalculator<T, S> + Clone,
+    C: RecursiveIndicatorCalculator<T, S> + Clone,
 {
     fn clone(&self) -> Self {
         Self {
-            cached: self.cached.clone(),
+            base: self.base.clone(),
+            calculator: self.calculator.clone(),
+            results: RefCell::new(self.results.borrow().clone()),
+            first_index: Cell::new(self.first_index.get()),
         }
     }
 }
@@ -97,25 +123,26 @@ impl<T, S, C> RecursiveCachedIndicator<T, S, C>
 where
     T: TrNum + Clone + 'static,
     S: BarSeries<T> + 'static,
-    C: IndicatorCalculator<T, S, Output = T> + Clone,
+    C: RecursiveIndicatorCalculator<T, S> + Clone,
 {
     /// General construction Creates indicator based on the given bar series.
     pub fn new(series_ref: BarSeriesRef<S>, calculator: C) -> Self {
-        Self::new_with_threshold(series_ref, calculator, RECURSION_THRESHOLD)
+        Self {
+            base: BaseIndicator::new(series_ref),
+            calculator,
+            results: RefCell::new(VecDeque::new()),
+            first_index: Cell::new(0),
+        }
     }
 
+    /// 旧版构造：缓存已按顺序迭代补齐，threshold 不再使用
+    #[deprecated(note = "use `RecursiveCachedIndicator::new`; the threshold is no longer used")]
     pub fn new_with_threshold(
         series_ref: BarSeriesRef<S>,
         calculator: C,
-        threshold: usize,
+        _threshold: usize,
     ) -> Self {
-        let wrapper = RecursiveCalcWrapper {
-            inner: calculator,
-            threshold,
-        };
-        Self {
-            cached: CachedIndicator::new_from_series(series_ref, wrapper),
-        }
+        Self::new(series_ref, calculator)
     }
 
     /// 快捷方式：从 Arc<RwLock<S>> 构造
@@ -128,54 +155,81 @@ where
         Self::new(BarSeriesRef::Mut(series), calculator)
     }
 
-    /// 从现有 Indicator 构造，使用默认阈值
+    /// 从现有 Indicator 构造，复用其 BarSeries
     pub fn from_indicator<I>(indicator: Arc<I>, calculator: C) -> Self
     where
-        I: Indicator<Num = T, Output = T, Series = S>,
+        I: Indicator<Num = T, Series = S>,
     {
-        Self::from_indicator_with_threshold(indicator, calculator, RECURSION_THRESHOLD)
+        Self::new(indicator.bar_series(), calculator)
     }
 
-    /// 从现有 Indicator 构造，自定义阈值
+    /// 旧版构造：缓存已按顺序迭代补齐，threshold 不再使用
+    #[deprecated(
+        note = "use `RecursiveCachedIndicator::from_indicator`; the threshold is no longer used"
+    )]
     pub fn from_indicator_with_threshold<I>(
         indicator: Arc<I>,
         calculator: C,
-        threshold: usize,
+        _threshold: usize,
     ) -> Self
     where
-        I: Indicator<Num = T, Output = T, Series = S>,
+        I: Indicator<Num = T, Series = S>,
     {
-        let wrapper = RecursiveCalcWrapper {
-            inner: calculator,
-            threshold,
-        };
-        let cached = CachedIndicator::new_from_indicator(indicator, wrapper);
-        Self { cached }
+        Self::from_indicator(indicator, calculator)
+    }
+
+    pub fn calculator(&self) -> &C {
+        &self.calculator
     }
 
     pub fn get_value(&self, index: usize) -> Result<C::Output, IndicatorError> {
-        let series_ref = self.cached.base.bar_series();
+        let (removed, end_index) = self
+            .base
+            .bar_series()
+            .with_ref(|s| (s.get_removed_bars_count(), s.get_end_index()))
+            .map_err(|e| IndicatorError::Other { message: e })?;
 
-        series_ref.with_ref(|s| {
-            if s.get_bar_count() == 0 || index > s.get_end_index().unwrap_or(usize::MAX) {
-                // 超出范围，直接计算
-                return self.cached.get_cached_value(index);
-            }
+        // 已被移除的 bar 按第一个剩余的 bar 计算（与 BarSeries::get_bar 一致）
+        let index = index.max(removed);
+        // 只缓存最新 bar 之前的值；空序列不缓存
+        let cache_limit = end_index.unwrap_or(removed);
 
-            let removed = s.get_removed_bars_count();
-            let highest = *self.cached.highest_result_index.borrow();
+        let mut results = self.results.borrow_mut();
+        let first = self.first_index.get();
+        if first < removed || results.is_empty() {
+            let drop_count = (removed - first.min(removed)).min(results.len());
+            results.drain(..drop_count);
+            if results.is_empty() {
+                self.first_index.set(removed);
+            } else {
+                self.first_index.set(first + drop_count);
+            }
+        }
+        let first = self.first_index.get();
 
-            let start = std::cmp::max(removed, if highest < 0 { 0 } else { highest as usize });
+        if index < first + results.len() {
+            return Ok(results[index - first].clone());
+        }
 
-            if index > start && (index - start) > self.cached.calculator.threshold {
-                // 迭代计算避免深递归
-                for i in start..index {
-                    self.cached.get_cached_value(i)?;
-                }
+        // 从最后一个缓存值开始顺序补齐到 index
+        let mut previous = results.back().cloned();
+        for i in (first + results.len())..index {
+            let value = self
+                .calculator
+                .calculate(&self.base, i, previous.as_ref())?;
+            if i < cache_limit {
+                results.push_back(value.clone());
             }
+            previous = Some(value);
+        }
 
-            self.cached.get_cached_value(index)
-        })?
+        let value = self
+            .calculator
+            .calculate(&self.base, index, previous.as_ref())?;
+        if index < cache_limit && index == first + results.len() {
+            results.push_back(value.clone());
+        }
+        Ok(value)
     }
 }
 
@@ -183,10 +237,10 @@ impl<T, S, C> Indicator for RecursiveCachedIndicator<T, S, C>
 where
     T: TrNum + Clone + 'static,
     S: BarSeries<T> + 'static,
-    C: IndicatorCalculator<T, S, Output = T> + Clone,
+    C: RecursiveIndicatorCalculator<T, S> + Clone,
 {
     type Num = T;
-    type Output = T;
+    type Output = C::Output;
     type Series = S;
 
     fn get_value(&self, index: usize) -> Result<C::Output, IndicatorError> {
@@ -194,7 +248,7 @@ where
     }
 
     fn bar_series(&self) -> BarSeriesRef<Self::Series> {
-        self.cached.bar_series()
+        self.base.bar_series()
     }
 
     fn count_of_unstable_bars(&self) -> usize {
@@ -0,0 +1,174 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::regression::linear_regression_indicator::LinearRegressionIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// LinearRegressionForecastIndicatorCalculator：回归直线向后延伸 offset 根 bar 的值
+pub struct LinearRegressionForecastIndicatorCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    regression: Arc<LinearRegressionIndicator<T, S, I>>,
+    offset: usize,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for LinearRegressionForecastIndicatorCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            regression: Arc::clone(&self.regression),
+            offset: self.offset,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for LinearRegressionForecastIndicatorCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let regression = self.regression.get_value(index)?;
+        let x = T::from_usize(regression.observations - 1 + self.offset).unwrap_or_else(T::zero);
+        Ok(regression.value_at(&x))
+    }
+}
+
+/// LinearRegressionForecastIndicator：回归直线的端点预测值
+///
+/// offset = 0 时即为 LSMA（最小二乘移动平均）
+pub struct LinearRegressionForecastIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, LinearRegressionForecastIndicatorCalculator<T, S, I>>,
+    unstable_bars: usize,
+}
+
+impl<T, S, I> Clone for LinearRegressionForecastIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I> LinearRegressionForecastIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// LSMA：回归直线在当前 bar 处的值
+    pub fn new(indicator: Arc<I>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::with_offset(indicator, bar_count, 0)
+    }
+
+    /// 预测当前 bar 之后第 offset 根 bar 的值
+    pub fn with_offset(
+        indicator: Arc<I>,
+        bar_count: usize,
+        offset: usize,
+    ) -> Result<Self, IndicatorError> {
+        let regression = LinearRegressionIndicator::new(indicator, bar_count)?;
+        Ok(Self::from_regression(Arc::new(regression), offset))
+    }
+
+    /// 复用已有的回归指标
+    pub fn from_regression(
+        regression: Arc<LinearRegressionIndicator<T, S, I>>,
+        offset: usize,
+    ) -> Self {
+        let unstable_bars = regression.count_of_unstable_bars();
+        let calculator = LinearRegressionForecastIndicatorCalculator {
+            regression: Arc::clone(&regression),
+            offset,
+            _phantom: PhantomData,
+        };
+        Self {
+            cached: CachedIndicator::new_from_series(regression.bar_series(), calculator),
+            unstable_bars,
+        }
+    }
+
+    pub fn offset(&self) -> usize {
+        self.cached.calculator().offset
+    }
+}
+
+impl<T, S, I> Indicator for LinearRegressionForecastIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -0,0 +1,259 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::rolling_sums::RollingSums;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// 滚动窗口的最小二乘回归结果
+///
+/// x 为窗口内的相对位置：最早的 bar 为 0，当前 bar 为 observations - 1
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearRegression<T> {
+    /// 斜率（每根 bar 的变化量）
+    pub slope: T,
+    /// 截距（窗口最早一根 bar 处的回归值）
+    pub intercept: T,
+    /// 决定系数 R²，无法定义时为 0
+    pub r_squared: T,
+    /// 残差的总体标准差
+    pub residual_deviation: T,
+    /// 参与回归的观测数
+    pub observations: usize,
+}
+
+impl<T: TrNum + Clone> LinearRegression<T> {
+    /// 回归直线在相对位置 x 处的值
+    pub fn value_at(&self, x: &T) -> T {
+        self.intercept.plus(&self.slope.multiplied_by(x))
+    }
+
+    /// 回归直线在当前 bar 处的值（LSMA）
+    pub fn end_point(&self) -> T {
+        let x = T::from_usize(self.observations.saturating_sub(1)).unwrap_or_else(T::zero);
+        self.value_at(&x)
+    }
+}
+
+/// LinearRegressionCalculator：最近 bar_count 个值的最小二乘回归
+pub struct LinearRegressionCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    indicator: Arc<I>,
+    bar_count: usize,
+    /// 窗口内 y、index*y 与 y*y 的和
+    sums: RollingSums<T, 3>,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for LinearRegressionCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            indicator: Arc::clone(&self.indicator),
+            bar_count: self.bar_count,
+            sums: self.sums.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> LinearRegressionCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    pub fn new(indicator: Arc<I>, bar_count: usize) -> Self {
+        Self {
+            indicator,
+            bar_count,
+            sums: RollingSums::new(bar_count),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for LinearRegressionCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = LinearRegression<T>;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let from_usize = |n: usize| T::from_usize(n).unwrap_or_else(T::zero);
+        // 按绝对索引累加 index*y，窗口滑动时各项不必随相对位置调整
+        let [sum_y, sum_iy, sum_yy] = self.sums.sums(base, index, |i| {
+            let y = self.indicator.get_value(i)?;
+            let iy = from_usize(i).multiplied_by(&y);
+            let yy = y.multiplied_by(&y);
+            Ok([y, iy, yy])
+        })?;
+
+        // 不足 bar_count 时按已有的观测数回归
+        let observations = (index + 1).min(self.bar_count);
+        let count = from_usize(observations);
+        // 相对位置 x = i - start
+        let start = from_usize(index + 1 - observations);
+        let sum_xy = sum_iy.minus(&start.multiplied_by(&sum_y));
+        // x = 0..k-1 的和与平方和有闭式解
+        let sum_x = from_usize(observations * (observations - 1) / 2);
+        let sum_xx = from_usize((observations - 1) * observations * (2 * observations - 1) / 6);
+
+        let mean_x = sum_x.divided_by(&count)?;
+        let mean_y = sum_y.divided_by(&count)?;
+        let centered_xx = sum_xx.minus(&sum_x.multiplied_by(&mean_x));
+        let centered_xy = sum_xy.minus(&sum_x.multiplied_by(&mean_y));
+        let centered_yy = sum_yy.minus(&sum_y.multiplied_by(&mean_y));
+
+        let slope = if centered_xx.is_zero() {
+            T::zero()
+        } else {
+            centered_xy.divided_by(&centered_xx)?
+        };
+        let intercept = mean_y.minus(&slope.multiplied_by(&mean_x));
+
+        // 残差平方和 = Syy - slope * Sxy，浮点误差可能产生极小的负数
+        let squared_residuals = centered_yy
+            .minus(&slope.multiplied_by(&centered_xy))
+            .max(&T::zero());
+        let r_squared = if centered_yy.is_zero() {
+            T::zero()
+        } else {
+            T::one()
+                .minus(&squared_residuals.divided_by(&centered_yy)?)
+                .max(&T::zero())
+        };
+        let residual_deviation = squared_residuals.divided_by(&count)?.sqrt()?;
+
+        Ok(LinearRegression {
+            slope,
+            intercept,
+            r_squared,
+            residual_deviation,
+            observations,
+        })
+    }
+}
+
+/// LinearRegressionIndicator：最近 bar_count 个值的滚动最小二乘回归
+///
+/// 输出完整的回归结果，斜率、截距、LSMA、R² 与回归通道指标均基于它计算
+pub struct LinearRegressionIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, LinearRegressionCalculator<T, S, I>>,
+    unstable_bars: usize,
+    bar_count: usize,
+}
+
+impl<T, S, I> Clone for LinearRegressionIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S, I> LinearRegressionIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    pub fn new(indicator: Arc<I>, bar_count: usize) -> Result<Self, IndicatorError> {
+        if bar_count < 2 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be at least 2 for a regression".to_string(),
+            });
+        }
+
+        let unstable_bars = indicator.count_of_unstable_bars() + bar_count - 1;
+        let calculator = LinearRegressionCalculator::new(Arc::clone(&indicator), bar_count);
+        Ok(Self {
+            cached: CachedIndicator::new_from_series(indicator.bar_series(), calculator),
+            unstable_bars,
+            bar_count,
+        })
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+}
+
+impl<T, S, I> Indicator for LinearRegressionIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = LinearRegression<T>;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<LinearRegression<T>, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    /// 基础指标的不稳定期 + 填满窗口所需的 bar_count - 1
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -0,0 +1,189 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::averages::mma_indicator::MMAIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::gain_indicator::GainIndicator;
+use crate::indicators::helpers::loss_indicator::LossIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// RsiCalculator：由平均涨幅 / 平均跌幅（MMA 平滑）计算 RSI
+pub struct RsiCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    average_gain: Arc<MMAIndicator<T, S, GainIndicator<T, S, I>>>,
+    average_loss: Arc<MMAIndicator<T, S, LossIndicator<T, S, I>>>,
+    _phantom: PhantomData<(T, S)>,
+}
+
+impl<T, S, I> Clone for RsiCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            average_gain: Arc::clone(&self.average_gain),
+            average_loss: Arc::clone(&self.average_loss),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for RsiCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let factory = base.bar_series().with_ref(|s| s.num_factory())?;
+        let hundred = factory.hundred().as_ref().clone();
+
+        let average_gain = self.average_gain.get_value(index)?;
+        let average_loss = self.average_loss.get_value(index)?;
+
+        // 与 ta4j 一致：没有跌幅时，无涨幅为 0，否则为 100
+        if average_loss.is_zero() {
+            return if average_gain.is_zero() {
+                Ok(factory.zero().as_ref().clone())
+            } else {
+                Ok(hundred)
+            };
+        }
+
+        let relative_strength = average_gain.divided_by(&average_loss)?;
+        let one = factory.one().as_ref().clone();
+        Ok(hundred.minus(&hundred.divided_by(&one.plus(&relative_strength))?))
+    }
+}
+
+/// Relative Strength Index (RSI)
+///
+/// RSI = 100 - 100 / (1 + MMA(gain, n) / MMA(loss, n))
+pub struct RsiIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, RsiCalculator<T, S, I>>,
+    unstable_bars: usize,
+    bar_count: usize,
+}
+
+impl<T, S, I> Clone for RsiIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S, I> RsiIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 构造函数
+    ///
+    /// # Arguments
+    /// * `indicator` - 计算 RSI 的基础指标（通常为收盘价）
+    /// * `bar_count` - MMA 平滑周期
+    pub fn new(indicator: Arc<I>, bar_count: usize) -> Result<Self, IndicatorError> {
+        let average_gain = MMAIndicator::new(
+            Arc::new(GainIndicator::new(Arc::clone(&indicator))),
+            bar_count,
+        )?;
+        let average_loss = MMAIndicator::new(
+            Arc::new(LossIndicator::new(Arc::clone(&indicator))),
+            bar_count,
+        )?;
+        let calculator = RsiCalculator {
+            average_gain: Arc::new(average_gain),
+            average_loss: Arc::new(average_loss),
+            _phantom: PhantomData,
+        };
+
+        Ok(Self {
+            unstable_bars: indicator.count_of_unstable_bars() + bar_count,
+            cached: CachedIndicator::new_from_indicator(indicator, calculator),
+            bar_count,
+        })
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+}
+
+impl<T, S, I> Indicator for RsiIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    /// 基础指标的不稳定期 + MMA 周期
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
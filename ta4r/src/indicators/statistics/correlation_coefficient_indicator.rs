@@ -0,0 +1,176 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::statistics::covariance_indicator::CovarianceIndicator;
+use crate::indicators::statistics::variance_indicator::VarianceIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// CorrelationCoefficientCalculator：cov(x, y) / sqrt(var(x) * var(y))
+pub struct CorrelationCoefficientCalculator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    covariance: Arc<CovarianceIndicator<T, S, I1, I2>>,
+    first_variance: Arc<VarianceIndicator<T, S, I1>>,
+    second_variance: Arc<VarianceIndicator<T, S, I2>>,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I1, I2> Clone for CorrelationCoefficientCalculator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            covariance: Arc::clone(&self.covariance),
+            first_variance: Arc::clone(&self.first_variance),
+            second_variance: Arc::clone(&self.second_variance),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I1, I2> IndicatorCalculator<T, S> for CorrelationCoefficientCalculator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let covariance = self.covariance.get_value(index)?;
+        let denominator = self
+            .first_variance
+            .get_value(index)?
+            .multiplied_by(&self.second_variance.get_value(index)?)
+            .sqrt()?;
+        // 任一序列在窗口内恒定时相关系数无定义，返回 0
+        if denominator.is_zero() {
+            return Ok(T::zero());
+        }
+        Ok(covariance.divided_by(&denominator)?)
+    }
+}
+
+/// CorrelationCoefficientIndicator：两个指标在滚动窗口上的皮尔逊相关系数
+pub struct CorrelationCoefficientIndicator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, CorrelationCoefficientCalculator<T, S, I1, I2>>,
+    unstable_bars: usize,
+}
+
+impl<T, S, I1, I2> Clone for CorrelationCoefficientIndicator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I1, I2> CorrelationCoefficientIndicator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    pub fn new(first: Arc<I1>, second: Arc<I2>, bar_count: usize) -> Result<Self, IndicatorError> {
+        let covariance = Arc::new(CovarianceIndicator::new(
+            Arc::clone(&first),
+            Arc::clone(&second),
+            bar_count,
+        )?);
+        let first_variance = Arc::new(VarianceIndicator::new(first, bar_count)?);
+        let second_variance = Arc::new(VarianceIndicator::new(second, bar_count)?);
+
+        let unstable_bars = covariance.count_of_unstable_bars();
+        let calculator = CorrelationCoefficientCalculator {
+            covariance: Arc::clone(&covariance),
+            first_variance,
+            second_variance,
+            _phantom: PhantomData,
+        };
+        Ok(Self {
+            cached: CachedIndicator::new_from_indicator(covariance, calculator),
+            unstable_bars,
+        })
+    }
+}
+
+impl<T, S, I1, I2> Indicator for CorrelationCoefficientIndicator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -0,0 +1,205 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::rolling_sums::RollingSums;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// CovarianceCalculator：两个指标在滚动窗口上的总体协方差
+pub struct CovarianceCalculator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    first: Arc<I1>,
+    second: Arc<I2>,
+    bar_count: usize,
+    /// 窗口内 x、y 与 x*y 的和
+    sums: RollingSums<T, 3>,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I1, I2> Clone for CovarianceCalculator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            first: Arc::clone(&self.first),
+            second: Arc::clone(&self.second),
+            bar_count: self.bar_count,
+            sums: self.sums.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I1, I2> CovarianceCalculator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    pub fn new(first: Arc<I1>, second: Arc<I2>, bar_count: usize) -> Self {
+        Self {
+            first,
+            second,
+            bar_count,
+            sums: RollingSums::new(bar_count),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I1, I2> IndicatorCalculator<T, S> for CovarianceCalculator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let [sum_x, sum_y, sum_xy] = self.sums.sums(base, index, |i| {
+            let x = self.first.get_value(i)?;
+            let y = self.second.get_value(i)?;
+            let xy = x.multiplied_by(&y);
+            Ok([x, y, xy])
+        })?;
+
+        // 不足 bar_count 时按已有的观测数计算（与 VarianceIndicator 一致）
+        let observations = (index + 1).min(self.bar_count);
+        let count = T::from_usize(observations).unwrap_or_else(T::one);
+
+        let mean_x = sum_x.divided_by(&count)?;
+        let mean_y = sum_y.divided_by(&count)?;
+        Ok(sum_xy
+            .divided_by(&count)?
+            .minus(&mean_x.multiplied_by(&mean_y)))
+    }
+}
+
+/// CovarianceIndicator：两个指标最近 bar_count 个值的总体协方差
+pub struct CovarianceIndicator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, CovarianceCalculator<T, S, I1, I2>>,
+    unstable_bars: usize,
+    bar_count: usize,
+}
+
+impl<T, S, I1, I2> Clone for CovarianceIndicator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S, I1, I2> CovarianceIndicator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    pub fn new(first: Arc<I1>, second: Arc<I2>, bar_count: usize) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+
+        let unstable_bars = first
+            .count_of_unstable_bars()
+            .max(second.count_of_unstable_bars())
+            + bar_count
+            - 1;
+        let calculator = CovarianceCalculator::new(Arc::clone(&first), second, bar_count);
+        Ok(Self {
+            cached: CachedIndicator::new_from_indicator(first, calculator),
+            unstable_bars,
+            bar_count,
+        })
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+}
+
+impl<T, S, I1, I2> Indicator for CovarianceIndicator<T, S, I1, I2>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I1: Indicator<Num = T, Output = T, Series = S>,
+    I2: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -0,0 +1,109 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::averages::sma_indicator::SmaIndicator;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use std::sync::Arc;
+
+/// 随机振荡器 %D：%K 的 SMA，默认平滑周期 3
+pub struct StochasticOscillatorDIndicator<T, S, K>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    K: Indicator<Num = T, Output = T, Series = S>,
+{
+    sma: SmaIndicator<T, S, K>,
+    unstable_bars: usize,
+    smoothing: usize,
+}
+
+impl<T, S, K> Clone for StochasticOscillatorDIndicator<T, S, K>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    K: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            sma: self.sma.clone(),
+            unstable_bars: self.unstable_bars,
+            smoothing: self.smoothing,
+        }
+    }
+}
+
+impl<T, S, K> StochasticOscillatorDIndicator<T, S, K>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    K: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 使用标准平滑周期 3 构造
+    pub fn with_default_smoothing(k: Arc<K>) -> Self {
+        Self::new(k, 3)
+    }
+
+    pub fn new(k: Arc<K>, smoothing: usize) -> Self {
+        let smoothing = smoothing.max(1);
+        let unstable_bars = k.count_of_unstable_bars() + smoothing - 1;
+        Self {
+            sma: SmaIndicator::new(k, smoothing),
+            unstable_bars,
+            smoothing,
+        }
+    }
+
+    pub fn smoothing(&self) -> usize {
+        self.smoothing
+    }
+}
+
+impl<T, S, K> Indicator for StochasticOscillatorDIndicator<T, S, K>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    K: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.sma.get_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.sma.bar_series()
+    }
+
+    /// %K 的不稳定期 + 平滑窗口所需的 smoothing - 1
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
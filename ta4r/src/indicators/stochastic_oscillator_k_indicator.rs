@@ -0,0 +1,220 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::close_price_indicator::ClosePriceIndicator;
+use crate::indicators::helpers::high_price_indicator::HighPriceIndicator;
+use crate::indicators::helpers::highest_value_indicator::HighestValueIndicator;
+use crate::indicators::helpers::low_price_indicator::LowPriceIndicator;
+use crate::indicators::helpers::lowest_value_indicator::LowestValueIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::{NumFactory, TrNum};
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// StochasticOscillatorKIndicatorCalculator：(close - 最低价) / (最高价 - 最低价) * 100
+pub struct StochasticOscillatorKIndicatorCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    indicator: Arc<I>,
+    highest: Arc<HighestValueIndicator<T, S, HighPriceIndicator<T, S>>>,
+    lowest: Arc<LowestValueIndicator<T, S, LowPriceIndicator<T, S>>>,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for StochasticOscillatorKIndicatorCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            indicator: Arc::clone(&self.indicator),
+            highest: Arc::clone(&self.highest),
+            lowest: Arc::clone(&self.lowest),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for StochasticOscillatorKIndicatorCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let value = self.indicator.get_value(index)?;
+        let highest = self.highest.get_value(index)?;
+        let lowest = self.lowest.get_value(index)?;
+        let hundred = base
+            .bar_series()
+            .with_ref(|s| s.num_factory().hundred().as_ref().clone())?;
+
+        let range = highest.minus(&lowest);
+        // 区间内价格没有波动时无法定位，返回中性值 50（ta4j 此时得到 NaN）
+        if range.is_zero() {
+            return Ok(hundred.divided_by(&T::from_usize(2).unwrap_or_else(T::one))?);
+        }
+
+        Ok(value
+            .minus(&lowest)
+            .divided_by(&range)?
+            .multiplied_by(&hundred))
+    }
+}
+
+/// 随机振荡器 %K
+///
+/// %K = (close - min(low, n)) / (max(high, n) - min(low, n)) * 100，取值范围 [0, 100]
+pub struct StochasticOscillatorKIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, StochasticOscillatorKIndicatorCalculator<T, S, I>>,
+    unstable_bars: usize,
+    bar_count: usize,
+}
+
+impl<T, S, I> Clone for StochasticOscillatorKIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+            bar_count: self.bar_count,
+        }
+    }
+}
+
+impl<T, S> StochasticOscillatorKIndicator<T, S, ClosePriceIndicator<T, S>>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造：基于收盘价、最高价与最低价
+    pub fn new(series_ref: BarSeriesRef<S>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::with_indicators(
+            Arc::new(ClosePriceIndicator::new(series_ref.clone())),
+            bar_count,
+            Arc::new(HighPriceIndicator::new(series_ref.clone())),
+            Arc::new(LowPriceIndicator::new(series_ref)),
+        )
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), bar_count)
+    }
+}
+
+impl<T, S, I> StochasticOscillatorKIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 使用自定义的参考值指标（默认为收盘价）
+    pub fn with_indicators(
+        indicator: Arc<I>,
+        bar_count: usize,
+        high: Arc<HighPriceIndicator<T, S>>,
+        low: Arc<LowPriceIndicator<T, S>>,
+    ) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+
+        let unstable_bars = indicator.count_of_unstable_bars() + bar_count - 1;
+        let calculator = StochasticOscillatorKIndicatorCalculator {
+            indicator: Arc::clone(&indicator),
+            highest: Arc::new(HighestValueIndicator::new(high, bar_count)),
+            lowest: Arc::new(LowestValueIndicator::new(low, bar_count)),
+            _phantom: PhantomData,
+        };
+        Ok(Self {
+            cached: CachedIndicator::new_from_indicator(indicator, calculator),
+            unstable_bars,
+            bar_count,
+        })
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.bar_count
+    }
+}
+
+impl<T, S, I> Indicator for StochasticOscillatorKIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    /// 参考值指标的不稳定期 + 填满窗口所需的 bar_count - 1
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
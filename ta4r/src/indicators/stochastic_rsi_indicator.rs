@@ -0,0 +1,173 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::helpers::highest_value_indicator::HighestValueIndicator;
+use crate::indicators::helpers::lowest_value_indicator::LowestValueIndicator;
+use crate::indicators::rsi_indicator::RsiIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// StochasticRsiCalculator：RSI 在最近 n 个 RSI 值区间中的相对位置
+pub struct StochasticRsiCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    rsi: Arc<RsiIndicator<T, S, I>>,
+    min_rsi: Arc<LowestValueIndicator<T, S, RsiIndicator<T, S, I>>>,
+    max_rsi: Arc<HighestValueIndicator<T, S, RsiIndicator<T, S, I>>>,
+    _phantom: PhantomData<(T, S)>,
+}
+
+impl<T, S, I> Clone for StochasticRsiCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            rsi: Arc::clone(&self.rsi),
+            min_rsi: Arc::clone(&self.min_rsi),
+            max_rsi: Arc::clone(&self.max_rsi),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> IndicatorCalculator<T, S> for StochasticRsiCalculator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        _base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let rsi = self.rsi.get_value(index)?;
+        let min_rsi = self.min_rsi.get_value(index)?;
+        let max_rsi = self.max_rsi.get_value(index)?;
+
+        let range = max_rsi.minus(&min_rsi);
+        // 区间内 RSI 没有变化时无法定位，返回 0（ta4j 此时得到 NaN）
+        if range.is_zero() {
+            return Ok(T::zero());
+        }
+
+        Ok(rsi.minus(&min_rsi).divided_by(&range)?)
+    }
+}
+
+/// Stochastic RSI
+///
+/// StochRSI = (RSI - min(RSI, n)) / (max(RSI, n) - min(RSI, n))，取值范围 [0, 1]
+pub struct StochasticRsiIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    cached: CachedIndicator<T, S, StochasticRsiCalculator<T, S, I>>,
+    unstable_bars: usize,
+}
+
+impl<T, S, I> Clone for StochasticRsiIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+            unstable_bars: self.unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I> StochasticRsiIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 在 indicator 上构造 RSI(bar_count)，再以相同周期计算 StochRSI
+    pub fn new(indicator: Arc<I>, bar_count: usize) -> Result<Self, IndicatorError> {
+        let rsi = RsiIndicator::new(indicator, bar_count)?;
+        Ok(Self::from_rsi(Arc::new(rsi), bar_count))
+    }
+
+    /// 基于已有的 RSI 指标构造
+    pub fn from_rsi(rsi: Arc<RsiIndicator<T, S, I>>, bar_count: usize) -> Self {
+        let calculator = StochasticRsiCalculator {
+            rsi: Arc::clone(&rsi),
+            min_rsi: Arc::new(LowestValueIndicator::new(Arc::clone(&rsi), bar_count)),
+            max_rsi: Arc::new(HighestValueIndicator::new(Arc::clone(&rsi), bar_count)),
+            _phantom: PhantomData,
+        };
+
+        // RSI 稳定之后还需要 bar_count - 1 个 RSI 值才能填满窗口
+        let unstable_bars = rsi.count_of_unstable_bars() + bar_count.saturating_sub(1);
+        Self {
+            cached: CachedIndicator::new_from_indicator(rsi, calculator),
+            unstable_bars,
+        }
+    }
+}
+
+impl<T, S, I> Indicator for StochasticRsiIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.unstable_bars
+    }
+}
@@ -0,0 +1,277 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::{BarPrice, BaseIndicator, bar_price};
+use crate::indicators::atr_indicator::ATRIndicator;
+use crate::indicators::recursive_cached_indicator::RecursiveCachedIndicator;
+use crate::indicators::types::{IndicatorError, RecursiveIndicatorCalculator};
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// Supertrend 在某根 bar 上的完整状态
+#[derive(Clone, Debug)]
+pub struct SupertrendState<T: TrNum> {
+    /// 当前 Supertrend 值：上升趋势取下轨，下降趋势取上轨
+    pub value: T,
+    /// 收紧后的上轨
+    pub upper_band: T,
+    /// 收紧后的下轨
+    pub lower_band: T,
+    /// 是否处于上升趋势
+    pub is_uptrend: bool,
+    /// 趋势是否在当前 bar 发生反转
+    pub trend_changed: bool,
+}
+
+/// SupertrendCalculator：由前一根 bar 的轨道与趋势递推当前状态
+pub struct SupertrendCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    atr: Arc<ATRIndicator<T, S>>,
+    multiplier: T,
+}
+
+impl<T, S> Clone for SupertrendCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            atr: Arc::clone(&self.atr),
+            multiplier: self.multiplier.clone(),
+        }
+    }
+}
+
+impl<T, S> RecursiveIndicatorCalculator<T, S> for SupertrendCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = SupertrendState<T>;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+        previous: Option<&Self::Output>,
+    ) -> Result<Self::Output, IndicatorError> {
+        let offset = self.atr.get_value(index)?.multiplied_by(&self.multiplier);
+        let two = T::from_usize(2).unwrap_or_else(T::one);
+
+        base.bar_series().with_ref(|s| {
+            let close = |i: usize| bar_price(s, i, BarPrice::Close);
+
+            let high = bar_price(s, index, BarPrice::High)?;
+            let low = bar_price(s, index, BarPrice::Low)?;
+            let median = high.plus(&low).divided_by(&two)?;
+            let basic_upper = median.plus(&offset);
+            let basic_lower = median.minus(&offset);
+            let current_close = close(index)?;
+
+            let first = s.get_begin_index().unwrap_or(0);
+            let Some(previous) = previous.filter(|_| index > first) else {
+                // 第一根 bar 无法判断趋势，先假定上升趋势
+                return Ok(SupertrendState {
+                    value: basic_lower.clone(),
+                    upper_band: basic_upper,
+                    lower_band: basic_lower,
+                    is_uptrend: true,
+                    trend_changed: false,
+                });
+            };
+
+            // 轨道只向趋势方向收紧，前一根收盘价突破轨道时才重新取基础轨道
+            let previous_close = close(index - 1)?;
+            let upper_band = if basic_upper.is_less_than(&previous.upper_band)
+                || previous_close.is_greater_than(&previous.upper_band)
+            {
+                basic_upper
+            } else {
+                previous.upper_band.clone()
+            };
+            let lower_band = if basic_lower.is_greater_than(&previous.lower_band)
+                || previous_close.is_less_than(&previous.lower_band)
+            {
+                basic_lower
+            } else {
+                previous.lower_band.clone()
+            };
+
+            let is_uptrend = if previous.is_uptrend {
+                !current_close.is_less_than(&lower_band)
+            } else {
+                current_close.is_greater_than(&upper_band)
+            };
+            let value = if is_uptrend {
+                lower_band.clone()
+            } else {
+                upper_band.clone()
+            };
+            Ok(SupertrendState {
+                value,
+                upper_band,
+                lower_band,
+                is_uptrend,
+                trend_changed: is_uptrend != previous.is_uptrend,
+            })
+        })?
+    }
+}
+
+/// Supertrend 指标
+///
+/// - 基础轨道 = (high + low) / 2 ± multiplier * ATR(n)
+/// - 上升趋势中 Supertrend 取下轨，收盘价跌破下轨时转为下降趋势并改取上轨，反之亦然
+/// - 可作为跟踪止损价位，与 CrossedDownIndicatorRule / CrossedUpIndicatorRule 组合使用
+pub struct SupertrendIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    inner: RecursiveCachedIndicator<T, S, SupertrendCalculator<T, S>>,
+}
+
+impl<T, S> Clone for SupertrendIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T, S> SupertrendIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，直接传入 BarSeriesRef
+    pub fn new(
+        series_ref: BarSeriesRef<S>,
+        bar_count: usize,
+        multiplier: T,
+    ) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+        Self::from_atr(
+            Arc::new(ATRIndicator::new(series_ref, bar_count)),
+            multiplier,
+        )
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(
+        series: Arc<RwLock<S>>,
+        bar_count: usize,
+        multiplier: T,
+    ) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), bar_count, multiplier)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(
+        series: Arc<RefCell<S>>,
+        bar_count: usize,
+        multiplier: T,
+    ) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), bar_count, multiplier)
+    }
+
+    /// 基于已有的 ATR 构造
+    pub fn from_atr(atr: Arc<ATRIndicator<T, S>>, multiplier: T) -> Result<Self, IndicatorError> {
+        if !multiplier.is_greater_than(&T::zero()) {
+            return Err(IndicatorError::CalculationError {
+                message: "Multiplier must be greater than 0".to_string(),
+            });
+        }
+        let series_ref = atr.bar_series();
+        Ok(Self {
+            inner: RecursiveCachedIndicator::new(
+                series_ref,
+                SupertrendCalculator { atr, multiplier },
+            ),
+        })
+    }
+
+    pub fn multiplier(&self) -> &T {
+        &self.inner.calculator().multiplier
+    }
+
+    pub fn atr(&self) -> Arc<ATRIndicator<T, S>> {
+        Arc::clone(&self.inner.calculator().atr)
+    }
+
+    /// 获取 index 处的完整状态（轨道、趋势方向、是否反转）
+    pub fn state(&self, index: usize) -> Result<SupertrendState<T>, IndicatorError> {
+        self.inner.get_value(index)
+    }
+
+    /// index 处是否处于上升趋势（Supertrend 位于价格下方）
+    pub fn is_uptrend(&self, index: usize) -> Result<bool, IndicatorError> {
+        Ok(self.state(index)?.is_uptrend)
+    }
+
+    /// index 处趋势是否发生反转
+    pub fn is_trend_changed(&self, index: usize) -> Result<bool, IndicatorError> {
+        Ok(self.state(index)?.trend_changed)
+    }
+}
+
+impl<T, S> Indicator for SupertrendIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        Ok(self.state(index)?.value)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.inner.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.inner.calculator().atr.count_of_unstable_bars()
+    }
+}
@@ -0,0 +1,132 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::swing::SwingSource;
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// 斐波那契回撤位
+///
+/// - 取最近一个已确认的摆动高点与摆动低点，range = 高点 - 低点
+/// - 高点确认晚于低点（上涨段）时：回撤位 = 高点 - range * ratio
+/// - 低点确认晚于高点（下跌段）时：回撤位 = 低点 + range * ratio
+/// - 常用 ratio 为 0.236、0.382、0.5、0.618、0.786，可直接用于 OverIndicatorRule / UnderIndicatorRule
+pub struct FibonacciRetracementIndicator<T, S, H, L>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    H: SwingSource<Num = T, Series = S>,
+    L: SwingSource<Num = T, Series = S>,
+{
+    high: Arc<H>,
+    low: Arc<L>,
+    ratio: T,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, H, L> Clone for FibonacciRetracementIndicator<T, S, H, L>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    H: SwingSource<Num = T, Series = S>,
+    L: SwingSource<Num = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            high: Arc::clone(&self.high),
+            low: Arc::clone(&self.low),
+            ratio: self.ratio.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, H, L> FibonacciRetracementIndicator<T, S, H, L>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    H: SwingSource<Num = T, Series = S>,
+    L: SwingSource<Num = T, Series = S>,
+{
+    /// high / low 分别提供摆动高点与摆动低点，ratio 不能为负
+    pub fn new(high: Arc<H>, low: Arc<L>, ratio: T) -> Result<Self, IndicatorError> {
+        if ratio.is_less_than(&T::zero()) {
+            return Err(IndicatorError::CalculationError {
+                message: "Retracement ratio must not be negative".to_string(),
+            });
+        }
+        Ok(Self {
+            high,
+            low,
+            ratio,
+            _phantom: PhantomData,
+        })
+    }
+
+    pub fn ratio(&self) -> &T {
+        &self.ratio
+    }
+}
+
+impl<T, S, H, L> Indicator for FibonacciRetracementIndicator<T, S, H, L>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    H: SwingSource<Num = T, Series = S>,
+    L: SwingSource<Num = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        let (Some(high), Some(low)) = (self.high.swing(index)?, self.low.swing(index)?) else {
+            // 任一摆动点尚未确认时，由对应指标给出不可用的原因
+            self.high.get_value(index)?;
+            return self.low.get_value(index);
+        };
+        let retracement = high.price.minus(&low.price).multiplied_by(&self.ratio);
+        if high.confirmed_index >= low.confirmed_index {
+            Ok(high.price.minus(&retracement))
+        } else {
+            Ok(low.price.plus(&retracement))
+        }
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.high.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.high
+            .count_of_unstable_bars()
+            .max(self.low.count_of_unstable_bars())
+    }
+}
@@ -0,0 +1,105 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+pub mod fibonacci_retracement_indicator;
+pub mod swing_point_indicator;
+pub mod zigzag_indicator;
+pub mod zigzag_swing_indicator;
+
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::{BarPrice, bar_price};
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+
+/// 摆动点类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwingKind {
+    /// 摆动高点，按 bar 的最高价判断
+    High,
+    /// 摆动低点，按 bar 的最低价判断
+    Low,
+}
+
+impl SwingKind {
+    /// 读取 bar 上用于判断该类摆动点的价格
+    pub(crate) fn price<T, S>(&self, series: &S, index: usize) -> Result<T, IndicatorError>
+    where
+        T: TrNum + Clone + 'static,
+        S: BarSeries<T> + 'static,
+    {
+        let price = match self {
+            SwingKind::High => BarPrice::High,
+            SwingKind::Low => BarPrice::Low,
+        };
+        bar_price(series, index, price)
+    }
+
+    /// a 是否比 b 更极端（高点更高 / 低点更低）
+    pub(crate) fn exceeds<T: TrNum>(&self, a: &T, b: &T) -> bool {
+        match self {
+            SwingKind::High => a.is_greater_than(b),
+            SwingKind::Low => a.is_less_than(b),
+        }
+    }
+}
+
+/// 已确认的摆动点
+///
+/// 摆动点只有在 confirmed_index 处才被确认，回测中在此之前的 bar 不能使用它
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwingPoint<T> {
+    pub price: T,
+    /// 摆动点所在的 bar
+    pub index: usize,
+    /// 确认摆动点的 bar
+    pub confirmed_index: usize,
+}
+
+impl<T> SwingPoint<T> {
+    /// 从摆动点出现到被确认经过的 bar 数
+    pub fn confirmation_delay(&self) -> usize {
+        self.confirmed_index - self.index
+    }
+}
+
+/// 能给出最近一个已确认摆动点的指标，get_value 返回该摆动点的价格
+pub trait SwingSource: Indicator<Output = <Self as Indicator>::Num> {
+    /// index 处（含）之前已确认的最近一个摆动点，尚未确认任何摆动点时为 None
+    fn swing(&self, index: usize) -> Result<Option<SwingPoint<Self::Num>>, IndicatorError>;
+
+    /// 最近一个摆动点所在的 bar
+    fn swing_index(&self, index: usize) -> Result<Option<usize>, IndicatorError> {
+        Ok(self.swing(index)?.map(|point| point.index))
+    }
+}
+
+/// 尚未确认任何摆动点时的错误，earliest 为最早可能确认摆动点的 bar
+pub(crate) fn swing_not_available(index: usize, earliest: usize) -> IndicatorError {
+    IndicatorError::NotAvailable {
+        index,
+        available_from: earliest.max(index + 1),
+    }
+}
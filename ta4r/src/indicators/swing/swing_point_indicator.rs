@@ -0,0 +1,231 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::recursive_cached_indicator::RecursiveCachedIndicator;
+use crate::indicators::swing::{SwingKind, SwingPoint, SwingSource, swing_not_available};
+use crate::indicators::types::{IndicatorError, RecursiveIndicatorCalculator};
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// SwingPointCalculator：在每根 bar 上检查 right 根之前的 bar 是否构成摆动点
+#[derive(Clone)]
+pub struct SwingPointCalculator {
+    kind: SwingKind,
+    left: usize,
+    right: usize,
+}
+
+impl<T, S> RecursiveIndicatorCalculator<T, S> for SwingPointCalculator
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = Option<SwingPoint<T>>;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+        previous: Option<&Self::Output>,
+    ) -> Result<Self::Output, IndicatorError> {
+        let previous = previous.cloned().flatten();
+
+        base.bar_series().with_ref(|s| {
+            let first = s.get_begin_index().unwrap_or(0);
+            if index < first + self.left + self.right {
+                return Ok(previous);
+            }
+
+            // 左侧必须严格被超越，右侧不超过即可，连续相同的极值只记第一根
+            let candidate = index - self.right;
+            let price = self.kind.price(s, candidate)?;
+            for i in candidate - self.left..candidate {
+                if !self.kind.exceeds(&price, &self.kind.price(s, i)?) {
+                    return Ok(previous);
+                }
+            }
+            for i in candidate + 1..=index {
+                if self.kind.exceeds(&self.kind.price(s, i)?, &price) {
+                    return Ok(previous);
+                }
+            }
+            Ok(Some(SwingPoint {
+                price,
+                index: candidate,
+                confirmed_index: index,
+            }))
+        })?
+    }
+}
+
+/// 摆动高点 / 低点指标
+///
+/// - 某根 bar 的最高价高于左侧 left 根、且不低于右侧 right 根 bar 时为摆动高点，低点同理
+/// - 摆动点要等右侧 right 根 bar 走完才能确认，即确认延迟为 right 根 bar，
+///   index 处只会返回在 index（含）之前已确认的摆动点，不会使用未来数据
+/// - get_value 返回最近一个已确认摆动点的价格，可作为支撑 / 阻力位用于 OverIndicatorRule 等规则
+pub struct SwingPointIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    inner: RecursiveCachedIndicator<T, S, SwingPointCalculator>,
+}
+
+impl<T, S> Clone for SwingPointIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T, S> SwingPointIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，left / right 为左右两侧比较的 bar 数
+    pub fn new(
+        series_ref: BarSeriesRef<S>,
+        kind: SwingKind,
+        left: usize,
+        right: usize,
+    ) -> Result<Self, IndicatorError> {
+        if left == 0 || right == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Left and right bar counts must be greater than 0".to_string(),
+            });
+        }
+        Ok(Self {
+            inner: RecursiveCachedIndicator::new(
+                series_ref,
+                SwingPointCalculator { kind, left, right },
+            ),
+        })
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(
+        series: Arc<RwLock<S>>,
+        kind: SwingKind,
+        left: usize,
+        right: usize,
+    ) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), kind, left, right)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(
+        series: Arc<RefCell<S>>,
+        kind: SwingKind,
+        left: usize,
+        right: usize,
+    ) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), kind, left, right)
+    }
+
+    /// Bill Williams 分形：左右各 2 根 bar 的摆动点（High 为向上分形，Low 为向下分形）
+    pub fn fractal(series_ref: BarSeriesRef<S>, kind: SwingKind) -> Self {
+        Self {
+            inner: RecursiveCachedIndicator::new(
+                series_ref,
+                SwingPointCalculator {
+                    kind,
+                    left: 2,
+                    right: 2,
+                },
+            ),
+        }
+    }
+
+    pub fn kind(&self) -> SwingKind {
+        self.inner.calculator().kind
+    }
+
+    pub fn left_bar_count(&self) -> usize {
+        self.inner.calculator().left
+    }
+
+    pub fn right_bar_count(&self) -> usize {
+        self.inner.calculator().right
+    }
+
+    /// 确认延迟：摆动点出现后还要再等待的 bar 数
+    pub fn confirmation_delay(&self) -> usize {
+        self.right_bar_count()
+    }
+
+    /// index 处是否刚好确认了一个新的摆动点
+    pub fn is_confirmed(&self, index: usize) -> Result<bool, IndicatorError> {
+        Ok(self
+            .swing(index)?
+            .is_some_and(|point| point.confirmed_index == index))
+    }
+}
+
+impl<T, S> SwingSource for SwingPointIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn swing(&self, index: usize) -> Result<Option<SwingPoint<T>>, IndicatorError> {
+        self.inner.get_value(index)
+    }
+}
+
+impl<T, S> Indicator for SwingPointIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.swing(index)?
+            .map(|point| point.price)
+            .ok_or_else(|| swing_not_available(index, self.count_of_unstable_bars()))
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.inner.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.left_bar_count() + self.right_bar_count()
+    }
+}
@@ -0,0 +1,368 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::atr_indicator::ATRIndicator;
+use crate::indicators::helpers::ExtremeValue;
+use crate::indicators::recursive_cached_indicator::RecursiveCachedIndicator;
+use crate::indicators::swing::{SwingKind, SwingPoint, SwingSource, swing_not_available};
+use crate::indicators::types::{IndicatorError, RecursiveIndicatorCalculator};
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// 之字形的反转阈值
+pub enum ZigZagThreshold<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 相对候选极值的百分比，如 5 表示 5%
+    Percentage(T),
+    /// ATR 的倍数
+    Atr {
+        atr: Arc<ATRIndicator<T, S>>,
+        multiplier: T,
+    },
+}
+
+impl<T, S> Clone for ZigZagThreshold<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        match self {
+            ZigZagThreshold::Percentage(percentage) => {
+                ZigZagThreshold::Percentage(percentage.clone())
+            }
+            ZigZagThreshold::Atr { atr, multiplier } => ZigZagThreshold::Atr {
+                atr: Arc::clone(atr),
+                multiplier: multiplier.clone(),
+            },
+        }
+    }
+}
+
+impl<T, S> ZigZagThreshold<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// index 处从 reference 反向运动多少才算反转
+    fn amount(&self, reference: &T, index: usize) -> Result<T, IndicatorError> {
+        match self {
+            ZigZagThreshold::Percentage(percentage) => {
+                let hundred = T::from_usize(100).unwrap_or_else(T::one);
+                Ok(reference
+                    .abs()
+                    .multiplied_by(percentage)
+                    .divided_by(&hundred)?)
+            }
+            ZigZagThreshold::Atr { atr, multiplier } => {
+                Ok(atr.get_value(index)?.multiplied_by(multiplier))
+            }
+        }
+    }
+}
+
+/// 之字形在某根 bar 上的状态
+#[derive(Clone, Debug)]
+pub struct ZigZagState<T> {
+    /// 当前这一段的方向：High 表示上行中、下一个确认的是高点；第一次反转之前为 None
+    pub direction: Option<SwingKind>,
+    /// 当前段内的最高价（上行段中即候选高点）
+    pub highest: ExtremeValue<T>,
+    /// 当前段内的最低价（下行段中即候选低点）
+    pub lowest: ExtremeValue<T>,
+    /// 最近一个已确认的高点
+    pub last_high: Option<SwingPoint<T>>,
+    /// 最近一个已确认的低点
+    pub last_low: Option<SwingPoint<T>>,
+}
+
+impl<T: Clone> ZigZagState<T> {
+    /// 最近一个已确认的转折点（高点或低点中确认较晚的一个）
+    pub fn last_pivot(&self) -> Option<SwingPoint<T>> {
+        match (&self.last_high, &self.last_low) {
+            (Some(high), Some(low)) if high.confirmed_index > low.confirmed_index => {
+                Some(high.clone())
+            }
+            (_, Some(low)) => Some(low.clone()),
+            (high, None) => high.clone(),
+        }
+    }
+}
+
+fn confirm<T: Clone>(extreme: &ExtremeValue<T>, index: usize) -> Option<SwingPoint<T>> {
+    Some(SwingPoint {
+        price: extreme.value.clone(),
+        index: extreme.index,
+        confirmed_index: index,
+    })
+}
+
+/// ZigZagCalculator：跟踪当前段的极值，价格反向运动超过阈值时确认该极值为转折点
+pub struct ZigZagCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    threshold: ZigZagThreshold<T, S>,
+}
+
+impl<T, S> Clone for ZigZagCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            threshold: self.threshold.clone(),
+        }
+    }
+}
+
+impl<T, S> RecursiveIndicatorCalculator<T, S> for ZigZagCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = ZigZagState<T>;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+        previous: Option<&Self::Output>,
+    ) -> Result<Self::Output, IndicatorError> {
+        let (first, high, low) = base.bar_series().with_ref(|s| {
+            Ok::<_, IndicatorError>((
+                s.get_begin_index().unwrap_or(0),
+                SwingKind::High.price(s, index)?,
+                SwingKind::Low.price(s, index)?,
+            ))
+        })??;
+        let high = ExtremeValue { value: high, index };
+        let low = ExtremeValue { value: low, index };
+
+        let Some(previous) = previous.filter(|_| index > first) else {
+            return Ok(ZigZagState {
+                direction: None,
+                highest: high,
+                lowest: low,
+                last_high: None,
+                last_low: None,
+            });
+        };
+
+        let mut state = previous.clone();
+        match state.direction {
+            None => {
+                if high.value.is_greater_than(&state.highest.value) {
+                    state.highest = high;
+                }
+                if low.value.is_less_than(&state.lowest.value) {
+                    state.lowest = low;
+                }
+                // 第一次反转：由先出现的极值决定第一个转折点
+                let range = state.highest.value.minus(&state.lowest.value);
+                if state.highest.index > state.lowest.index {
+                    let amount = self.threshold.amount(&state.lowest.value, index)?;
+                    if !range.is_less_than(&amount) {
+                        state.last_low = confirm(&state.lowest, index);
+                        state.direction = Some(SwingKind::High);
+                    }
+                } else if state.lowest.index > state.highest.index {
+                    let amount = self.threshold.amount(&state.highest.value, index)?;
+                    if !range.is_less_than(&amount) {
+                        state.last_high = confirm(&state.highest, index);
+                        state.direction = Some(SwingKind::Low);
+                    }
+                }
+            }
+            Some(SwingKind::High) => {
+                if high.value.is_greater_than(&state.highest.value) {
+                    state.highest = high;
+                } else {
+                    let amount = self.threshold.amount(&state.highest.value, index)?;
+                    let drop = state.highest.value.minus(&low.value);
+                    if !drop.is_less_than(&amount) {
+                        state.last_high = confirm(&state.highest, index);
+                        state.direction = Some(SwingKind::Low);
+                        state.lowest = low;
+                    }
+                }
+            }
+            Some(SwingKind::Low) => {
+                if low.value.is_less_than(&state.lowest.value) {
+                    state.lowest = low;
+                } else {
+                    let amount = self.threshold.amount(&state.lowest.value, index)?;
+                    let rise = high.value.minus(&state.lowest.value);
+                    if !rise.is_less_than(&amount) {
+                        state.last_low = confirm(&state.lowest, index);
+                        state.direction = Some(SwingKind::High);
+                        state.highest = high;
+                    }
+                }
+            }
+        }
+        Ok(state)
+    }
+}
+
+/// 之字形指标
+///
+/// - 上行段中跟踪最高价，价格自最高点回落超过阈值时确认该最高点为转折高点，并转入下行段，反之亦然
+/// - 转折点在价格反向运动超过阈值的那根 bar 上才被确认，确认延迟不固定，
+///   可通过 SwingPoint::confirmed_index 得到，index 处只会返回已确认的转折点
+/// - get_value 返回最近一个已确认转折点的价格；分别取高点 / 低点可使用 ZigZagSwingIndicator
+pub struct ZigZagIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    inner: RecursiveCachedIndicator<T, S, ZigZagCalculator<T, S>>,
+}
+
+impl<T, S> Clone for ZigZagIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T, S> ZigZagIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，直接传入 BarSeriesRef
+    pub fn new(
+        series_ref: BarSeriesRef<S>,
+        threshold: ZigZagThreshold<T, S>,
+    ) -> Result<Self, IndicatorError> {
+        let value = match &threshold {
+            ZigZagThreshold::Percentage(percentage) => percentage,
+            ZigZagThreshold::Atr { multiplier, .. } => multiplier,
+        };
+        if !value.is_greater_than(&T::zero()) {
+            return Err(IndicatorError::CalculationError {
+                message: "ZigZag threshold must be greater than 0".to_string(),
+            });
+        }
+        Ok(Self {
+            inner: RecursiveCachedIndicator::new(series_ref, ZigZagCalculator { threshold }),
+        })
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(
+        series: Arc<RwLock<S>>,
+        threshold: ZigZagThreshold<T, S>,
+    ) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), threshold)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(
+        series: Arc<RefCell<S>>,
+        threshold: ZigZagThreshold<T, S>,
+    ) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), threshold)
+    }
+
+    /// 基于 ATR 倍数阈值构造
+    pub fn from_atr(atr: Arc<ATRIndicator<T, S>>, multiplier: T) -> Result<Self, IndicatorError> {
+        let series_ref = atr.bar_series();
+        Self::new(series_ref, ZigZagThreshold::Atr { atr, multiplier })
+    }
+
+    pub fn threshold(&self) -> &ZigZagThreshold<T, S> {
+        &self.inner.calculator().threshold
+    }
+
+    /// 获取 index 处的完整状态
+    pub fn state(&self, index: usize) -> Result<ZigZagState<T>, IndicatorError> {
+        self.inner.get_value(index)
+    }
+
+    /// index 处最近一个已确认的高点
+    pub fn last_high(&self, index: usize) -> Result<Option<SwingPoint<T>>, IndicatorError> {
+        Ok(self.state(index)?.last_high)
+    }
+
+    /// index 处最近一个已确认的低点
+    pub fn last_low(&self, index: usize) -> Result<Option<SwingPoint<T>>, IndicatorError> {
+        Ok(self.state(index)?.last_low)
+    }
+}
+
+impl<T, S> SwingSource for ZigZagIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn swing(&self, index: usize) -> Result<Option<SwingPoint<T>>, IndicatorError> {
+        Ok(self.state(index)?.last_pivot())
+    }
+}
+
+impl<T, S> Indicator for ZigZagIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.swing(index)?
+            .map(|point| point.price)
+            .ok_or_else(|| swing_not_available(index, 1))
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.inner.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        match self.threshold() {
+            ZigZagThreshold::Percentage(_) => 0,
+            ZigZagThreshold::Atr { atr, .. } => atr.count_of_unstable_bars(),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::swing::zigzag_indicator::ZigZagIndicator;
+use crate::indicators::swing::{SwingKind, SwingPoint, SwingSource, swing_not_available};
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+use std::sync::Arc;
+
+/// 之字形最近一个已确认的高点或低点
+///
+/// 与 ZigZagIndicator 共享同一份计算结果，可作为 FibonacciRetracementIndicator 的高点 / 低点输入
+pub struct ZigZagSwingIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    zigzag: Arc<ZigZagIndicator<T, S>>,
+    kind: SwingKind,
+}
+
+impl<T, S> Clone for ZigZagSwingIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            zigzag: Arc::clone(&self.zigzag),
+            kind: self.kind,
+        }
+    }
+}
+
+impl<T, S> ZigZagSwingIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    pub fn new(zigzag: Arc<ZigZagIndicator<T, S>>, kind: SwingKind) -> Self {
+        Self { zigzag, kind }
+    }
+
+    pub fn kind(&self) -> SwingKind {
+        self.kind
+    }
+}
+
+impl<T, S> SwingSource for ZigZagSwingIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn swing(&self, index: usize) -> Result<Option<SwingPoint<T>>, IndicatorError> {
+        match self.kind {
+            SwingKind::High => self.zigzag.last_high(index),
+            SwingKind::Low => self.zigzag.last_low(index),
+        }
+    }
+}
+
+impl<T, S> Indicator for ZigZagSwingIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.swing(index)?
+            .map(|point| point.price)
+            .ok_or_else(|| swing_not_available(index, 1))
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.zigzag.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.zigzag.count_of_unstable_bars()
+    }
+}
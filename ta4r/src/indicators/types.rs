@@ -41,6 +41,10 @@ pub enum IndicatorError {
     #[error("OutOfBounds index: {index}")]
     OutOfBounds { index: usize },
 
+    /// 平移类指标在前几根 bar 上没有可用的值（不向未来取值）
+    #[error("Value not available at index {index} (available from index {available_from})")]
+    NotAvailable { index: usize, available_from: usize },
+
     #[error("Calculation error: {message}")]
     CalculationError { message: String },
 
@@ -128,6 +132,24 @@ where
     ) -> Result<Self::Output, IndicatorError>;
 }
 
+/// RecursiveIndicatorCalculator trait —— 计算 index 时可以读取自身在 index - 1 的结果
+///
+/// `previous` 为 `None` 表示 index 是序列中第一个可用的 bar
+pub trait RecursiveIndicatorCalculator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T>,
+{
+    /// 计算结果类型（数值指标 = T，带状态的指标可以是自定义结构体）
+    type Output: Clone + 'static;
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+        previous: Option<&Self::Output>,
+    ) -> Result<Self::Output, IndicatorError>;
+}
+
 pub enum IterMode<T, S> {
     /// Snapshot 模式，持有 Vec<T> 拷贝
     Snapshot(Vec<T>), // parking_lot::RwLockReadGuard<'a, S>
@@ -0,0 +1,137 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::{Bar, BarSeries};
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::bar_at;
+use crate::indicators::types::IndicatorError;
+use crate::num::{NumFactory, TrNum};
+use std::marker::PhantomData;
+use std::sync::Arc;
+use time::Duration;
+
+/// 年化波动率
+///
+/// - 年化波动率 = 单根 bar 波动率 * sqrt(一年的 bar 数)
+/// - 一年的 bar 数 = 年长度 / bar 的 time_period，默认按 365 天计算；
+///   只统计交易日的日线可使用 with_year(indicator, Duration::days(252))
+pub struct AnnualizedVolatilityIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    volatility: Arc<I>,
+    year: Duration,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, I> Clone for AnnualizedVolatilityIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            volatility: Arc::clone(&self.volatility),
+            year: self.year,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, I> AnnualizedVolatilityIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    /// 按 365 天的年长度年化
+    pub fn new(volatility: Arc<I>) -> Self {
+        Self {
+            volatility,
+            year: Duration::days(365),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// 自定义年长度
+    pub fn with_year(volatility: Arc<I>, year: Duration) -> Result<Self, IndicatorError> {
+        if !year.is_positive() {
+            return Err(IndicatorError::CalculationError {
+                message: "Year length must be greater than 0".to_string(),
+            });
+        }
+        Ok(Self {
+            volatility,
+            year,
+            _phantom: PhantomData,
+        })
+    }
+
+    pub fn year(&self) -> Duration {
+        self.year
+    }
+
+    /// index 处的年化系数 sqrt(年长度 / bar 的 time_period)
+    pub fn annualization_factor(&self, index: usize) -> Result<T, IndicatorError> {
+        self.volatility.bar_series().with_ref(|s| {
+            let period = bar_at(s, index)?.get_time_period();
+            if !period.is_positive() {
+                return Err(IndicatorError::CalculationError {
+                    message: format!("Bar time period must be greater than 0 at index {}", index),
+                });
+            }
+            let bars_per_year = self.year.as_seconds_f64() / period.as_seconds_f64();
+            Ok(s.num_factory().num_of_f64(bars_per_year.sqrt()))
+        })?
+    }
+}
+
+impl<T, S, I> Indicator for AnnualizedVolatilityIndicator<T, S, I>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    I: Indicator<Num = T, Output = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        let volatility = self.volatility.get_value(index)?;
+        Ok(volatility.multiplied_by(&self.annualization_factor(index)?))
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.volatility.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.volatility.count_of_unstable_bars()
+    }
+}
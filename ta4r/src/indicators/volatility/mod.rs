@@ -0,0 +1,209 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+pub mod annualized_volatility_indicator;
+pub mod realized_volatility_indicator;
+
+use crate::bar::types::BarSeries;
+use crate::indicators::abstract_indicator::{BarPrice, bar_price};
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+
+/// 已实现波动率的估计方法
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VolatilityEstimator {
+    /// 收盘价对数收益率的样本标准差
+    CloseToClose,
+    /// σ² = Σ ln(H/L)² / (4 ln2 * n)
+    Parkinson,
+    /// σ² = Σ [0.5 ln(H/L)² - (2 ln2 - 1) ln(C/O)²] / n
+    GarmanKlass,
+    /// σ² = Σ [ln(H/C) ln(H/O) + ln(L/C) ln(L/O)] / n
+    RogersSatchell,
+    /// σ² = σ²(隔夜) + k σ²(开盘到收盘) + (1 - k) σ²(RS)，k = 0.34 / (1.34 + (n + 1) / (n - 1))
+    YangZhang,
+}
+
+impl VolatilityEstimator {
+    /// 是否需要前一根 bar 的收盘价
+    pub fn uses_previous_close(&self) -> bool {
+        matches!(
+            self,
+            VolatilityEstimator::CloseToClose | VolatilityEstimator::YangZhang
+        )
+    }
+
+    /// 由窗口内各 bar 的对数价格比计算单根 bar 的方差；num 用于构造常数
+    pub(crate) fn variance<T>(
+        &self,
+        bars: &[LogRatios<T>],
+        num: impl Fn(f64) -> T,
+    ) -> Result<T, IndicatorError>
+    where
+        T: TrNum + Clone + 'static,
+    {
+        let count = T::from_usize(bars.len()).unwrap_or_else(T::one);
+        let mean = |values: Vec<T>| -> Result<T, IndicatorError> {
+            let sum = values.iter().fold(T::zero(), |sum, value| sum.plus(value));
+            Ok(sum.divided_by(&count)?)
+        };
+        let rogers_satchell = |bar: &LogRatios<T>| {
+            let high = bar
+                .high_open
+                .minus(&bar.close_open)
+                .multiplied_by(&bar.high_open);
+            let low = bar
+                .low_open
+                .minus(&bar.close_open)
+                .multiplied_by(&bar.low_open);
+            high.plus(&low)
+        };
+
+        match self {
+            VolatilityEstimator::CloseToClose => {
+                let returns: Vec<T> = bars.iter().filter_map(|bar| bar.close_close()).collect();
+                sample_variance(&returns)
+            }
+            VolatilityEstimator::Parkinson => {
+                let factor = num(4.0 * std::f64::consts::LN_2);
+                let values = bars
+                    .iter()
+                    .map(|bar| {
+                        let range = bar.high_open.minus(&bar.low_open);
+                        range.multiplied_by(&range)
+                    })
+                    .collect();
+                Ok(mean(values)?.divided_by(&factor)?)
+            }
+            VolatilityEstimator::GarmanKlass => {
+                let half = num(0.5);
+                let factor = num(2.0 * std::f64::consts::LN_2 - 1.0);
+                let values = bars
+                    .iter()
+                    .map(|bar| {
+                        let range = bar.high_open.minus(&bar.low_open);
+                        let body = bar.close_open.multiplied_by(&bar.close_open);
+                        range
+                            .multiplied_by(&range)
+                            .multiplied_by(&half)
+                            .minus(&body.multiplied_by(&factor))
+                    })
+                    .collect();
+                // 开收盘差远大于振幅时估计值可能为负，按 0 处理
+                Ok(mean(values)?.max(&T::zero()))
+            }
+            VolatilityEstimator::RogersSatchell => mean(bars.iter().map(rogers_satchell).collect()),
+            VolatilityEstimator::YangZhang => {
+                if bars.len() < 2 {
+                    return Ok(T::zero());
+                }
+                let overnight: Vec<T> = bars
+                    .iter()
+                    .map(|bar| bar.open_close.clone().unwrap_or_else(T::zero))
+                    .collect();
+                let open_close: Vec<T> = bars.iter().map(|bar| bar.close_open.clone()).collect();
+                let n = bars.len() as f64;
+                let k = num(0.34 / (1.34 + (n + 1.0) / (n - 1.0)));
+                let intraday = mean(bars.iter().map(rogers_satchell).collect())?;
+                Ok(sample_variance(&overnight)?
+                    .plus(&k.multiplied_by(&sample_variance(&open_close)?))
+                    .plus(&T::one().minus(&k).multiplied_by(&intraday)))
+            }
+        }
+    }
+}
+
+/// 单根 bar 的对数价格比
+pub(crate) struct LogRatios<T> {
+    /// ln(high / open)
+    pub(crate) high_open: T,
+    /// ln(low / open)
+    pub(crate) low_open: T,
+    /// ln(close / open)
+    pub(crate) close_open: T,
+    /// ln(open / 前一根 close)，没有前一根 bar 时为 None
+    pub(crate) open_close: Option<T>,
+}
+
+impl<T: TrNum> LogRatios<T> {
+    /// 读取 index 处的对数价格比，with_previous 为 true 时同时读取前一根 bar 的收盘价
+    pub(crate) fn from_series<S>(
+        series: &S,
+        index: usize,
+        with_previous: bool,
+    ) -> Result<Self, IndicatorError>
+    where
+        T: Clone + 'static,
+        S: BarSeries<T> + 'static,
+    {
+        let ln = |value: T, base: &T| -> Result<T, IndicatorError> {
+            Ok(value.divided_by(base)?.log()?)
+        };
+
+        let open = bar_price(series, index, BarPrice::Open)?;
+        let high = bar_price(series, index, BarPrice::High)?;
+        let low = bar_price(series, index, BarPrice::Low)?;
+        let close = bar_price(series, index, BarPrice::Close)?;
+        let open_close = if with_previous {
+            let previous_close = bar_price(series, index - 1, BarPrice::Close)?;
+            Some(ln(open.clone(), &previous_close)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            high_open: ln(high, &open)?,
+            low_open: ln(low, &open)?,
+            close_open: ln(close, &open)?,
+            open_close,
+        })
+    }
+
+    /// ln(close / 前一根 close) = ln(open / 前一根 close) + ln(close / open)
+    fn close_close(&self) -> Option<T> {
+        self.open_close
+            .as_ref()
+            .map(|open_close| open_close.plus(&self.close_open))
+    }
+}
+
+/// 样本方差（除以 n - 1），少于 2 个值时为 0
+fn sample_variance<T>(values: &[T]) -> Result<T, IndicatorError>
+where
+    T: TrNum + Clone + 'static,
+{
+    if values.len() < 2 {
+        return Ok(T::zero());
+    }
+    let count = T::from_usize(values.len()).unwrap_or_else(T::one);
+    let mean = values
+        .iter()
+        .fold(T::zero(), |sum, value| sum.plus(value))
+        .divided_by(&count)?;
+    let squares = values.iter().fold(T::zero(), |sum, value| {
+        let deviation = value.minus(&mean);
+        sum.plus(&deviation.multiplied_by(&deviation))
+    });
+    Ok(squares.divided_by(&count.minus(&T::one()))?)
+}
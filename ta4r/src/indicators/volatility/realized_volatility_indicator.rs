@@ -0,0 +1,185 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::indicators::volatility::{LogRatios, VolatilityEstimator};
+use crate::num::{NumFactory, TrNum};
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// RealizedVolatilityCalculator：最近 n 根 bar 的单根 bar 波动率
+#[derive(Clone)]
+pub struct RealizedVolatilityCalculator {
+    estimator: VolatilityEstimator,
+    bar_count: usize,
+}
+
+impl<T, S> IndicatorCalculator<T, S> for RealizedVolatilityCalculator
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        base.bar_series().with_ref(|s| {
+            let with_previous = self.estimator.uses_previous_close();
+            // 需要前一根收盘价的估计方法从第二根 bar 开始
+            let first = s.get_begin_index().unwrap_or(0) + usize::from(with_previous);
+            let start = index.saturating_sub(self.bar_count - 1).max(first);
+            if start > index {
+                return Ok(T::zero());
+            }
+            let bars = (start..=index)
+                .map(|i| LogRatios::from_series(s, i, with_previous))
+                .collect::<Result<Vec<_>, _>>()?;
+            let factory = s.num_factory();
+            let variance = self
+                .estimator
+                .variance(&bars, |value| factory.num_of_f64(value))?;
+            Ok(variance.sqrt()?)
+        })?
+    }
+}
+
+/// 已实现波动率
+///
+/// - 按 VolatilityEstimator 选择的方法，由最近 n 根 bar 的 OHLC 估计单根 bar 的波动率（对数收益率的标准差）
+/// - CloseToClose 与 YangZhang 需要前一根收盘价，n 根 bar 对应 n 个收益率，第一根 bar 不参与计算
+/// - 结果未年化，年化使用 AnnualizedVolatilityIndicator
+pub struct RealizedVolatilityIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, RealizedVolatilityCalculator>,
+}
+
+impl<T, S> Clone for RealizedVolatilityIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+        }
+    }
+}
+
+impl<T, S> RealizedVolatilityIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，YangZhang 至少需要 2 根 bar
+    pub fn new(
+        series_ref: BarSeriesRef<S>,
+        estimator: VolatilityEstimator,
+        bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        let minimum = match estimator {
+            VolatilityEstimator::YangZhang => 2,
+            _ => 1,
+        };
+        if bar_count < minimum {
+            return Err(IndicatorError::CalculationError {
+                message: format!(
+                    "Bar count must be at least {} for {:?} volatility",
+                    minimum, estimator
+                ),
+            });
+        }
+        let calculator = RealizedVolatilityCalculator {
+            estimator,
+            bar_count,
+        };
+        Ok(Self {
+            cached: CachedIndicator::new_from_series(series_ref, calculator),
+        })
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(
+        series: Arc<RwLock<S>>,
+        estimator: VolatilityEstimator,
+        bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), estimator, bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(
+        series: Arc<RefCell<S>>,
+        estimator: VolatilityEstimator,
+        bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), estimator, bar_count)
+    }
+
+    pub fn estimator(&self) -> VolatilityEstimator {
+        self.cached.calculator().estimator
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.cached.calculator().bar_count
+    }
+}
+
+impl<T, S> Indicator for RealizedVolatilityIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        if self.estimator().uses_previous_close() {
+            self.bar_count()
+        } else {
+            self.bar_count() - 1
+        }
+    }
+}
@@ -0,0 +1,113 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::macd_indicator::MacdIndicator;
+use crate::indicators::types::IndicatorError;
+use crate::indicators::volume::accumulation_distribution_indicator::AccumulationDistributionIndicator;
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// 蔡金震荡指标（Chaikin Oscillator）
+///
+/// CO = EMA(ADL, short) - EMA(ADL, long)，即累积/派发线的 MACD，默认周期 (3, 10)
+pub struct ChaikinOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    macd: MacdIndicator<T, S, AccumulationDistributionIndicator<T, S>>,
+}
+
+impl<T, S> Clone for ChaikinOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            macd: self.macd.clone(),
+        }
+    }
+}
+
+impl<T, S> ChaikinOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，使用标准周期 (3, 10)
+    pub fn new(series_ref: BarSeriesRef<S>) -> Result<Self, IndicatorError> {
+        Self::with_bar_counts(series_ref, 3, 10)
+    }
+
+    /// 自定义短周期与长周期，长周期必须大于短周期
+    pub fn with_bar_counts(
+        series_ref: BarSeriesRef<S>,
+        short_bar_count: usize,
+        long_bar_count: usize,
+    ) -> Result<Self, IndicatorError> {
+        let adl = Arc::new(AccumulationDistributionIndicator::new(series_ref));
+        Ok(Self {
+            macd: MacdIndicator::new(adl, short_bar_count, long_bar_count)?,
+        })
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series))
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series))
+    }
+}
+
+impl<T, S> Indicator for ChaikinOscillatorIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.macd.get_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.macd.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.macd.count_of_unstable_bars()
+    }
+}
@@ -0,0 +1,195 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::indicators::volume::volume_bar;
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// EaseOfMovementCalculator：单根 bar 的 EMV 在窗口内的简单平均
+#[derive(Clone)]
+pub struct EaseOfMovementCalculator<T> {
+    bar_count: usize,
+    volume_divisor: T,
+}
+
+impl<T> EaseOfMovementCalculator<T>
+where
+    T: TrNum + Clone + 'static,
+{
+    /// 单根 bar 的 EMV：中点位移 / ((volume / volume_divisor) / (high - low))
+    fn raw_value<S>(&self, base: &BaseIndicator<T, S>, index: usize) -> Result<T, IndicatorError>
+    where
+        S: BarSeries<T> + 'static,
+    {
+        if index == 0 {
+            return Ok(T::zero());
+        }
+        let current = volume_bar(base, index)?;
+        let previous = volume_bar(base, index - 1)?;
+        let range = current.high.minus(&current.low);
+        // 没有成交或没有价格区间时无法计算 box ratio，返回 0
+        if current.volume.is_zero() || range.is_zero() {
+            return Ok(T::zero());
+        }
+
+        let two = T::from_usize(2).unwrap_or_else(T::one);
+        let midpoint = current.high.plus(&current.low).divided_by(&two)?;
+        let previous_midpoint = previous.high.plus(&previous.low).divided_by(&two)?;
+        let distance = midpoint.minus(&previous_midpoint);
+        let box_ratio = current
+            .volume
+            .divided_by(&self.volume_divisor)?
+            .divided_by(&range)?;
+        Ok(distance.divided_by(&box_ratio)?)
+    }
+}
+
+impl<T, S> IndicatorCalculator<T, S> for EaseOfMovementCalculator<T>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        let start = index.saturating_sub(self.bar_count - 1);
+        let mut sum = T::zero();
+        for i in start..=index {
+            sum = sum.plus(&self.raw_value(base, i)?);
+        }
+        let count = T::from_usize(index - start + 1).unwrap_or_else(T::one);
+        Ok(sum.divided_by(&count)?)
+    }
+}
+
+/// 简易波动指标（Ease of Movement）
+///
+/// EMV = SMA((中点 - 前中点) / ((volume / volume_divisor) / (high - low)), n)，
+/// 中点为 (high + low) / 2，volume_divisor 默认 100000000
+pub struct EaseOfMovementIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, EaseOfMovementCalculator<T>>,
+}
+
+impl<T, S> Clone for EaseOfMovementIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+        }
+    }
+}
+
+impl<T, S> EaseOfMovementIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，使用默认的成交量缩放系数 100000000
+    pub fn new(series_ref: BarSeriesRef<S>, bar_count: usize) -> Result<Self, IndicatorError> {
+        let volume_divisor = T::from_usize(100_000_000).unwrap_or_else(T::one);
+        Self::with_volume_divisor(series_ref, bar_count, volume_divisor)
+    }
+
+    /// 使用自定义的成交量缩放系数构造
+    pub fn with_volume_divisor(
+        series_ref: BarSeriesRef<S>,
+        bar_count: usize,
+        volume_divisor: T,
+    ) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+        if volume_divisor.is_zero() {
+            return Err(IndicatorError::CalculationError {
+                message: "Volume divisor must not be zero".to_string(),
+            });
+        }
+        let calculator = EaseOfMovementCalculator {
+            bar_count,
+            volume_divisor,
+        };
+        Ok(Self {
+            cached: CachedIndicator::new_from_series(series_ref, calculator),
+        })
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), bar_count)
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.cached.calculator().bar_count
+    }
+}
+
+impl<T, S> Indicator for EaseOfMovementIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    /// 第一根 bar 没有位移，窗口需要 n 根有效的 EMV
+    fn count_of_unstable_bars(&self) -> usize {
+        self.bar_count()
+    }
+}
@@ -0,0 +1,166 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::cached_indicator::CachedIndicator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::indicators::volume::volume_bar;
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+/// MfiCalculator：窗口内正资金流与负资金流之比换算为 [0, 100]
+#[derive(Clone)]
+pub struct MfiCalculator {
+    bar_count: usize,
+}
+
+impl<T, S> IndicatorCalculator<T, S> for MfiCalculator
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self::Output, IndicatorError> {
+        // 第一根 bar 没有前一个典型价格，不计入资金流
+        let start = index.saturating_sub(self.bar_count - 1).max(1);
+        let mut positive_flow = T::zero();
+        let mut negative_flow = T::zero();
+        if start <= index {
+            let mut previous_typical = volume_bar(base, start - 1)?.typical_price()?;
+            for i in start..=index {
+                let bar = volume_bar(base, i)?;
+                let typical = bar.typical_price()?;
+                if typical.is_greater_than(&previous_typical) {
+                    positive_flow = positive_flow.plus(&bar.money_flow()?);
+                } else if typical.is_less_than(&previous_typical) {
+                    negative_flow = negative_flow.plus(&bar.money_flow()?);
+                }
+                previous_typical = typical;
+            }
+        }
+
+        let hundred = T::from_usize(100).unwrap_or_else(T::one);
+        // 与 RSI 一致：没有负资金流时，无正资金流为 0，否则为 100
+        if negative_flow.is_zero() {
+            return if positive_flow.is_zero() {
+                Ok(T::zero())
+            } else {
+                Ok(hundred)
+            };
+        }
+
+        let money_ratio = positive_flow.divided_by(&negative_flow)?;
+        Ok(hundred.minus(&hundred.divided_by(&T::one().plus(&money_ratio))?))
+    }
+}
+
+/// 资金流量指数（Money Flow Index）
+///
+/// MFI = 100 - 100 / (1 + 正资金流 / 负资金流)，资金流按典型价格涨跌归类，默认周期 14。
+/// 资金流取调用方提供的成交额（get_amount），未提供时以典型价格 * 成交量估算
+pub struct MfiIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    cached: CachedIndicator<T, S, MfiCalculator>,
+}
+
+impl<T, S> Clone for MfiIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            cached: self.cached.clone(),
+        }
+    }
+}
+
+impl<T, S> MfiIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，直接传入 BarSeriesRef
+    pub fn new(series_ref: BarSeriesRef<S>, bar_count: usize) -> Result<Self, IndicatorError> {
+        if bar_count == 0 {
+            return Err(IndicatorError::CalculationError {
+                message: "Bar count must be greater than 0".to_string(),
+            });
+        }
+        let calculator = MfiCalculator { bar_count };
+        Ok(Self {
+            cached: CachedIndicator::new_from_series(series_ref, calculator),
+        })
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Shared(series), bar_count)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, bar_count: usize) -> Result<Self, IndicatorError> {
+        Self::new(BarSeriesRef::Mut(series), bar_count)
+    }
+
+    pub fn bar_count(&self) -> usize {
+        self.cached.calculator().bar_count
+    }
+}
+
+impl<T, S> Indicator for MfiIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.cached.get_cached_value(index)
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.cached.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.bar_count()
+    }
+}
@@ -0,0 +1,143 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+pub mod accumulation_distribution_indicator;
+pub mod chaikin_money_flow_indicator;
+pub mod chaikin_oscillator_indicator;
+pub mod ease_of_movement_indicator;
+pub mod force_index_indicator;
+pub mod mfi_indicator;
+pub mod nvi_indicator;
+pub mod on_balance_volume_indicator;
+pub mod pvi_indicator;
+
+use crate::bar::types::{Bar, BarSeries};
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::{BarPrice, BaseIndicator, bar_at, bar_price};
+use crate::indicators::types::IndicatorError;
+use crate::num::TrNum;
+
+/// 量价指标需要的单根 bar 数据
+#[derive(Clone, Debug)]
+pub(crate) struct VolumeBar<T> {
+    pub(crate) high: T,
+    pub(crate) low: T,
+    pub(crate) close: T,
+    pub(crate) volume: T,
+    pub(crate) amount: T,
+}
+
+impl<T> VolumeBar<T>
+where
+    T: TrNum + Clone + 'static,
+{
+    /// 典型价格 (high + low + close) / 3
+    pub(crate) fn typical_price(&self) -> Result<T, IndicatorError> {
+        let three = T::from_usize(3).unwrap_or_else(T::one);
+        Ok(self
+            .high
+            .plus(&self.low)
+            .plus(&self.close)
+            .divided_by(&three)?)
+    }
+
+    /// 成交额是否由调用方提供
+    ///
+    /// 成交额为 0，或等于 TimeBarBuilder 自动推导的 close * volume 时，视为未提供
+    pub(crate) fn has_amount(&self) -> bool {
+        !self.amount.is_zero() && self.amount != self.close.multiplied_by(&self.volume)
+    }
+
+    /// 原始资金流：bar 带有成交额时直接使用，否则以典型价格 * 成交量估算
+    pub(crate) fn money_flow(&self) -> Result<T, IndicatorError> {
+        if self.has_amount() {
+            Ok(self.amount.clone())
+        } else {
+            Ok(self.typical_price()?.multiplied_by(&self.volume))
+        }
+    }
+
+    /// 收盘位置值 CLV = ((close - low) - (high - close)) / (high - low)，high == low 时为 0
+    pub(crate) fn close_location_value(&self) -> Result<T, IndicatorError> {
+        let range = self.high.minus(&self.low);
+        if range.is_zero() {
+            return Ok(T::zero());
+        }
+        let close_to_low = self.close.minus(&self.low);
+        let high_to_close = self.high.minus(&self.close);
+        Ok(close_to_low.minus(&high_to_close).divided_by(&range)?)
+    }
+
+    /// 资金流量 MFV = CLV * volume
+    pub(crate) fn money_flow_volume(&self) -> Result<T, IndicatorError> {
+        Ok(self.close_location_value()?.multiplied_by(&self.volume))
+    }
+}
+
+/// 读取 index 处 bar 的价格、成交量（get_volume）与成交额（get_amount）
+pub(crate) fn volume_bar<T, S>(
+    base: &BaseIndicator<T, S>,
+    index: usize,
+) -> Result<VolumeBar<T>, IndicatorError>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    base.bar_series().with_ref(|s| {
+        let bar = bar_at(s, index)?;
+        Ok(VolumeBar {
+            high: bar_price(s, index, BarPrice::High)?,
+            low: bar_price(s, index, BarPrice::Low)?,
+            close: bar_price(s, index, BarPrice::Close)?,
+            volume: bar.get_volume(),
+            amount: bar.get_amount(),
+        })
+    })?
+}
+
+/// NVI / PVI 的递推：成交量满足 should_update 时按收盘价变动率调整，否则沿用上一个值
+///
+/// 指数从 1000 开始
+pub(crate) fn next_volume_index<T, S>(
+    base: &BaseIndicator<T, S>,
+    index: usize,
+    previous: Option<&T>,
+    should_update: fn(&T, &T) -> bool,
+) -> Result<T, IndicatorError>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    let Some(prev) = previous.filter(|_| index > 0) else {
+        return Ok(T::from_usize(1000).unwrap_or_else(T::one));
+    };
+    let current = volume_bar(base, index)?;
+    let last = volume_bar(base, index - 1)?;
+    if !should_update(&current.volume, &last.volume) || last.close.is_zero() {
+        return Ok(prev.clone());
+    }
+    let change = current.close.minus(&last.close).divided_by(&last.close)?;
+    Ok(prev.plus(&prev.multiplied_by(&change)))
+}
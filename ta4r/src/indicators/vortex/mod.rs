@@ -0,0 +1,97 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+pub mod minus_vi_indicator;
+pub mod plus_vi_indicator;
+
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::{BarPrice, BaseIndicator, bar_price};
+use crate::indicators::helpers::tr_indicator::TRCalculator;
+use crate::indicators::types::{IndicatorCalculator, IndicatorError};
+use crate::num::TrNum;
+
+/// 单根 bar 的涡旋运动量
+pub(crate) struct VortexMovement<T> {
+    /// +VM = |high - 前一根 low|
+    pub(crate) plus: T,
+    /// -VM = |low - 前一根 high|
+    pub(crate) minus: T,
+    pub(crate) true_range: T,
+}
+
+/// 读取 index 处的 +VM、-VM 与真实波幅，第一根 bar 没有前值，运动量为 0
+pub(crate) fn vortex_movement<T, S>(
+    base: &BaseIndicator<T, S>,
+    index: usize,
+) -> Result<VortexMovement<T>, IndicatorError>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    let true_range = TRCalculator.calculate(base, index)?;
+    if index == 0 {
+        return Ok(VortexMovement {
+            plus: T::zero(),
+            minus: T::zero(),
+            true_range,
+        });
+    }
+    base.bar_series().with_ref(|s| {
+        let high = |i: usize| bar_price(s, i, BarPrice::High);
+        let low = |i: usize| bar_price(s, i, BarPrice::Low);
+
+        Ok(VortexMovement {
+            plus: high(index)?.minus(&low(index - 1)?).abs(),
+            minus: low(index)?.minus(&high(index - 1)?).abs(),
+            true_range,
+        })
+    })?
+}
+
+/// 涡旋指标：最近 n 根 bar 选定的运动量之和 / 真实波幅之和，真实波幅之和为 0 时返回 0
+pub(crate) fn vortex_indicator<T, S>(
+    base: &BaseIndicator<T, S>,
+    index: usize,
+    bar_count: usize,
+    movement: fn(VortexMovement<T>) -> T,
+) -> Result<T, IndicatorError>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    let start = index.saturating_sub(bar_count - 1);
+    let mut movement_sum = T::zero();
+    let mut true_range_sum = T::zero();
+    for i in start..=index {
+        let current = vortex_movement(base, i)?;
+        true_range_sum = true_range_sum.plus(&current.true_range);
+        movement_sum = movement_sum.plus(&movement(current));
+    }
+    if true_range_sum.is_zero() {
+        return Ok(T::zero());
+    }
+    Ok(movement_sum.divided_by(&true_range_sum)?)
+}
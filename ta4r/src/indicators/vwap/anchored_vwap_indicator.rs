@@ -0,0 +1,191 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::BaseIndicator;
+use crate::indicators::recursive_cached_indicator::RecursiveCachedIndicator;
+use crate::indicators::types::{IndicatorError, RecursiveIndicatorCalculator};
+use crate::indicators::vwap::{VwapAnchor, VwapMoments, VwapSource, bar_begin_time};
+use crate::num::TrNum;
+use parking_lot::RwLock;
+use std::cell::RefCell;
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+/// 锚定 VWAP 的累计状态
+#[derive(Clone, Debug)]
+pub struct AnchoredVwapState<T> {
+    /// 当前累计开始的 bar，锚点之前为 None
+    pub anchor_index: Option<usize>,
+    pub moments: VwapMoments<T>,
+    time: OffsetDateTime,
+}
+
+/// AnchoredVwapCalculator：遇到锚点时重置，之后逐根累加
+#[derive(Clone)]
+pub struct AnchoredVwapCalculator {
+    anchor: VwapAnchor,
+}
+
+impl<T, S> RecursiveIndicatorCalculator<T, S> for AnchoredVwapCalculator
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Output = AnchoredVwapState<T>;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, S>,
+        index: usize,
+        previous: Option<&Self::Output>,
+    ) -> Result<Self::Output, IndicatorError> {
+        let time = bar_begin_time(base, index)?;
+        let previous_time = previous.map(|p| p.time);
+
+        let (anchor_index, moments) = if self.anchor.starts_at(index, previous_time, time) {
+            (Some(index), VwapMoments::from_bar(base, index)?)
+        } else {
+            match previous {
+                Some(AnchoredVwapState {
+                    anchor_index: Some(anchor_index),
+                    moments,
+                    ..
+                }) => (
+                    Some(*anchor_index),
+                    moments.plus(&VwapMoments::from_bar(base, index)?),
+                ),
+                _ => (None, VwapMoments::zero()),
+            }
+        };
+
+        Ok(AnchoredVwapState {
+            anchor_index,
+            moments,
+            time,
+        })
+    }
+}
+
+/// 锚定成交量加权平均价（Anchored VWAP）
+///
+/// 从锚点所在的 bar 开始累计 Σ(price * volume) / Σvolume，
+/// 锚点可以是交易时段、自然日/周/月、指定的 bar 或时间；锚点之前的 bar 返回 NotAvailable
+pub struct AnchoredVwapIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    inner: RecursiveCachedIndicator<T, S, AnchoredVwapCalculator>,
+}
+
+impl<T, S> Clone for AnchoredVwapIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T, S> AnchoredVwapIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    /// 通用构造，直接传入 BarSeriesRef
+    pub fn new(series_ref: BarSeriesRef<S>, anchor: VwapAnchor) -> Self {
+        Self {
+            inner: RecursiveCachedIndicator::new(series_ref, AnchoredVwapCalculator { anchor }),
+        }
+    }
+
+    /// 快捷方式：从 Arc<RwLock<S>> 构造
+    pub fn from_shared(series: Arc<RwLock<S>>, anchor: VwapAnchor) -> Self {
+        Self::new(BarSeriesRef::Shared(series), anchor)
+    }
+
+    /// 快捷方式：从 Arc<RefCell<S>> 构造
+    pub fn from_mut(series: Arc<RefCell<S>>, anchor: VwapAnchor) -> Self {
+        Self::new(BarSeriesRef::Mut(series), anchor)
+    }
+
+    pub fn anchor(&self) -> VwapAnchor {
+        self.inner.calculator().anchor
+    }
+
+    /// index 处的值从哪根 bar 开始累计，锚点之前为 None
+    pub fn anchor_index(&self, index: usize) -> Result<Option<usize>, IndicatorError> {
+        Ok(self.inner.get_value(index)?.anchor_index)
+    }
+}
+
+impl<T, S> VwapSource for AnchoredVwapIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    fn moments(&self, index: usize) -> Result<VwapMoments<T>, IndicatorError> {
+        let state = self.inner.get_value(index)?;
+        if state.anchor_index.is_none() {
+            let available_from = match self.anchor() {
+                VwapAnchor::Index(anchor_index) => anchor_index,
+                _ => index + 1,
+            };
+            return Err(IndicatorError::NotAvailable {
+                index,
+                available_from,
+            });
+        }
+        Ok(state.moments)
+    }
+}
+
+impl<T, S> Indicator for AnchoredVwapIndicator<T, S>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        self.moments(index)?.vwap()
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.inner.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        0
+    }
+}
@@ -0,0 +1,218 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+pub mod anchored_vwap_indicator;
+pub mod vwap_band_indicator;
+pub mod vwap_indicator;
+
+use crate::bar::types::{Bar, BarSeries};
+use crate::indicators::Indicator;
+use crate::indicators::abstract_indicator::{BaseIndicator, bar_at};
+use crate::indicators::types::IndicatorError;
+use crate::indicators::volume::volume_bar;
+use crate::num::TrNum;
+use time::{OffsetDateTime, Time};
+
+/// VWAP 的累计量：Σ(price * volume)、Σvolume 与 Σ(price² * volume)
+///
+/// price * volume 取调用方提供的成交额（get_amount），未提供时以典型价格 * 成交量估算
+#[derive(Clone, Debug, PartialEq)]
+pub struct VwapMoments<T> {
+    pub price_volume: T,
+    pub volume: T,
+    pub squared_price_volume: T,
+    /// 累计起点 bar 的典型价格，Σvolume 为 0 时作为 VWAP
+    pub first_price: Option<T>,
+}
+
+impl<T> VwapMoments<T>
+where
+    T: TrNum + Clone + 'static,
+{
+    pub fn zero() -> Self {
+        Self {
+            price_volume: T::zero(),
+            volume: T::zero(),
+            squared_price_volume: T::zero(),
+            first_price: None,
+        }
+    }
+
+    /// 单根 bar 的累计量，bar 的成交均价为 成交额 / 成交量
+    pub(crate) fn from_bar<S>(
+        base: &BaseIndicator<T, S>,
+        index: usize,
+    ) -> Result<Self, IndicatorError>
+    where
+        S: BarSeries<T> + 'static,
+    {
+        let bar = volume_bar(base, index)?;
+        let price_volume = bar.money_flow()?;
+        let first_price = bar.typical_price()?;
+        // 没有成交的 bar 不参与加权
+        let squared_price_volume = if bar.volume.is_zero() {
+            T::zero()
+        } else {
+            price_volume
+                .multiplied_by(&price_volume)
+                .divided_by(&bar.volume)?
+        };
+        Ok(Self {
+            price_volume,
+            volume: bar.volume,
+            squared_price_volume,
+            first_price: Some(first_price),
+        })
+    }
+
+    pub fn plus(&self, other: &Self) -> Self {
+        Self {
+            price_volume: self.price_volume.plus(&other.price_volume),
+            volume: self.volume.plus(&other.volume),
+            squared_price_volume: self.squared_price_volume.plus(&other.squared_price_volume),
+            first_price: self
+                .first_price
+                .clone()
+                .or_else(|| other.first_price.clone()),
+        }
+    }
+
+    /// VWAP = Σ(price * volume) / Σvolume
+    ///
+    /// Σvolume 为 0 时沿用累计起点 bar 的典型价格，不返回错误
+    pub fn vwap(&self) -> Result<T, IndicatorError> {
+        if self.volume.is_zero() {
+            return self
+                .first_price
+                .clone()
+                .ok_or_else(|| IndicatorError::CalculationError {
+                    message: "VWAP requires at least one bar".to_string(),
+                });
+        }
+        Ok(self.price_volume.divided_by(&self.volume)?)
+    }
+
+    /// 成交量加权标准差 sqrt(Σ(price² * volume) / Σvolume - VWAP²)，Σvolume 为 0 时为 0
+    pub fn standard_deviation(&self) -> Result<T, IndicatorError> {
+        if self.volume.is_zero() {
+            return Ok(T::zero());
+        }
+        let vwap = self.vwap()?;
+        let variance = self
+            .squared_price_volume
+            .divided_by(&self.volume)?
+            .minus(&vwap.multiplied_by(&vwap));
+        // 舍入误差可能产生极小的负方差
+        if variance.is_less_than(&T::zero()) {
+            return Ok(T::zero());
+        }
+        Ok(variance.sqrt()?)
+    }
+}
+
+/// 可提供 VWAP 累计量的指标，供标准差通道使用
+pub trait VwapSource: Indicator<Output = <Self as Indicator>::Num> {
+    fn moments(&self, index: usize) -> Result<VwapMoments<Self::Num>, IndicatorError>;
+
+    /// index 处的成交量加权标准差
+    fn standard_deviation(&self, index: usize) -> Result<Self::Num, IndicatorError> {
+        self.moments(index)?.standard_deviation()
+    }
+}
+
+/// 按日历周期对 bar 分组，用于锚定 VWAP 与枢轴点
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnchorPeriod {
+    Day,
+    /// ISO 周，周一开始
+    Week,
+    Month,
+}
+
+impl AnchorPeriod {
+    /// 两个时间是否属于同一个自然日、周或月
+    pub(crate) fn same_period(&self, a: OffsetDateTime, b: OffsetDateTime) -> bool {
+        let (a, b) = (a.date(), b.date());
+        match self {
+            AnchorPeriod::Day => a == b,
+            AnchorPeriod::Week => {
+                let (a_year, a_week, _) = a.to_iso_week_date();
+                let (b_year, b_week, _) = b.to_iso_week_date();
+                (a_year, a_week) == (b_year, b_week)
+            }
+            AnchorPeriod::Month => (a.year(), a.month()) == (b.year(), b.month()),
+        }
+    }
+}
+
+/// 锚定 VWAP 重新开始累计的位置
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VwapAnchor {
+    /// 每个交易时段开始时重置，参数为时段在一天中的开始时刻（与 bar 时间同一时区）
+    Session(Time),
+    /// 每个自然日、周或月开始时重置
+    Period(AnchorPeriod),
+    /// 从指定的 bar 开始累计，之前的 bar 没有值
+    Index(usize),
+    /// 从开始时间不早于该时刻的第一根 bar 开始累计，之前的 bar 没有值
+    Time(OffsetDateTime),
+}
+
+impl VwapAnchor {
+    /// index 处的 bar 是否开始新的累计；previous_time 为前一根 bar 的开始时间
+    pub(crate) fn starts_at(
+        &self,
+        index: usize,
+        previous_time: Option<OffsetDateTime>,
+        time: OffsetDateTime,
+    ) -> bool {
+        match *self {
+            VwapAnchor::Session(session_start) => {
+                let session_day = |t: OffsetDateTime| (t - (session_start - Time::MIDNIGHT)).date();
+                previous_time.is_none_or(|previous| session_day(previous) != session_day(time))
+            }
+            VwapAnchor::Period(period) => {
+                previous_time.is_none_or(|previous| !period.same_period(previous, time))
+            }
+            VwapAnchor::Index(anchor_index) => index == anchor_index,
+            VwapAnchor::Time(anchor_time) => {
+                time >= anchor_time && previous_time.is_none_or(|previous| previous < anchor_time)
+            }
+        }
+    }
+}
+
+/// 读取 index 处 bar 的开始时间
+pub(crate) fn bar_begin_time<T, S>(
+    base: &BaseIndicator<T, S>,
+    index: usize,
+) -> Result<OffsetDateTime, IndicatorError>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+{
+    base.bar_series()
+        .with_ref(|s| bar_at(s, index).map(|bar| bar.get_begin_time()))?
+}
@@ -0,0 +1,125 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use crate::bar::builder::types::BarSeriesRef;
+use crate::bar::types::BarSeries;
+use crate::indicators::Indicator;
+use crate::indicators::types::IndicatorError;
+use crate::indicators::vwap::VwapSource;
+use crate::num::TrNum;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// VWAP 标准差通道
+///
+/// 上轨 = VWAP + k * σ，下轨 = VWAP - k * σ，σ 为同一窗口内的成交量加权标准差
+pub struct VwapBandIndicator<T, S, V>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    V: VwapSource<Num = T, Series = S>,
+{
+    vwap: Arc<V>,
+    k: T,
+    upper: bool,
+    _phantom: PhantomData<S>,
+}
+
+impl<T, S, V> Clone for VwapBandIndicator<T, S, V>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    V: VwapSource<Num = T, Series = S>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            vwap: Arc::clone(&self.vwap),
+            k: self.k.clone(),
+            upper: self.upper,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, S, V> VwapBandIndicator<T, S, V>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    V: VwapSource<Num = T, Series = S>,
+{
+    /// 上轨：VWAP + k * σ
+    pub fn upper(vwap: Arc<V>, k: T) -> Self {
+        Self {
+            vwap,
+            k,
+            upper: true,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// 下轨：VWAP - k * σ
+    pub fn lower(vwap: Arc<V>, k: T) -> Self {
+        Self {
+            vwap,
+            k,
+            upper: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn k(&self) -> &T {
+        &self.k
+    }
+}
+
+impl<T, S, V> Indicator for VwapBandIndicator<T, S, V>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    V: VwapSource<Num = T, Series = S>,
+{
+    type Num = T;
+    type Output = T;
+    type Series = S;
+
+    fn get_value(&self, index: usize) -> Result<T, IndicatorError> {
+        let moments = self.vwap.moments(index)?;
+        let offset = self.k.multiplied_by(&moments.standard_deviation()?);
+        let vwap = moments.vwap()?;
+        if self.upper {
+            Ok(vwap.plus(&offset))
+        } else {
+            Ok(vwap.minus(&offset))
+        }
+    }
+
+    fn bar_series(&self) -> BarSeriesRef<Self::Series> {
+        self.vwap.bar_series()
+    }
+
+    fn count_of_unstable_bars(&self) -> usize {
+        self.vwap.count_of_unstable_bars()
+    }
+}
@@ -28,6 +28,7 @@ use std::sync::Arc;
 
 pub mod aggregator;
 pub mod analysis;
+pub mod backtest;
 pub mod bar;
 pub mod base_trading_record;
 pub mod indicators;
@@ -14,7 +14,7 @@ use std::sync::Arc;
 /// 满足条件：当 up crosses-down low 指标时
 pub struct CrossedDownIndicatorRule<T, CM, HM, S, IU, IL, R>
 where
-    T: TrNum + Clone + From<bool> + 'static,
+    T: TrNum + Clone + 'static,
     CM: CostModel<T> + Clone,
     HM: CostModel<T> + Clone,
     S: BarSeries<T> + 'static,
@@ -29,7 +29,7 @@ where
 
 impl<T, CM, HM, S, IU, IL, R> CrossedDownIndicatorRule<T, CM, HM, S, IU, IL, R>
 where
-    T: TrNum + Clone + From<bool> + 'static,
+    T: TrNum + Clone + 'static,
     CM: CostModel<T> + Clone,
     HM: CostModel<T> + Clone,
     S: BarSeries<T> + 'static,
@@ -88,7 +88,7 @@ where
     IU: Indicator<Num = T, Output = T, Series = S>,
     R: TradingRecord<T, CM, HM, S>,
     S: 'static + BarSeries<T>,
-    T: 'static + Clone + From<bool> + TrNum,
+    T: 'static + Clone + TrNum,
 {
     fn clone(&self) -> Self {
         Self {
@@ -101,7 +101,7 @@ where
 
 impl<T, CM, HM, S, IU, IL, R> Rule for CrossedDownIndicatorRule<T, CM, HM, S, IU, IL, R>
 where
-    T: TrNum + Clone + From<bool> + 'static,
+    T: TrNum + Clone + 'static,
     CM: CostModel<T> + Clone,
     HM: CostModel<T> + Clone,
     S: BarSeries<T> + 'static,
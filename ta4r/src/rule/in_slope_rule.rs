@@ -4,12 +4,41 @@ use crate::bar::types::BarSeries;
 use crate::indicators::Indicator;
 use crate::indicators::helpers::previous_value_indicator::PreviousValueIndicator;
 use crate::indicators::numeric::binary_operation::BinaryOperation;
+use crate::indicators::regression::linear_regression_slope_indicator::LinearRegressionSlopeIndicator;
+use crate::indicators::types::IndicatorError;
 use crate::num::TrNum;
 use crate::rule::Rule;
 use crate::rule::base_rule::BaseRule;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+/// 斜率的计算方式
+enum SlopeSource<T, S, IR>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    IR: Indicator<Num = T, Output = T, Series = S> + 'static,
+{
+    /// 当前值 - 第 n 个之前的值（与 ta4j 一致）
+    Difference(PreviousValueIndicator<T, S, IR>),
+    /// 最近 bar_count 个值的最小二乘回归斜率
+    Regression(LinearRegressionSlopeIndicator<T, S, IR>),
+}
+
+impl<T, S, IR> Clone for SlopeSource<T, S, IR>
+where
+    T: TrNum + Clone + 'static,
+    S: BarSeries<T> + 'static,
+    IR: Indicator<Num = T, Output = T, Series = S> + 'static,
+{
+    fn clone(&self) -> Self {
+        match self {
+            SlopeSource::Difference(prev) => SlopeSource::Difference(prev.clone()),
+            SlopeSource::Regression(slope) => SlopeSource::Regression(slope.clone()),
+        }
+    }
+}
+
 /// InSlopeRule: 判断指标在指定的斜率区间内
 pub struct InSlopeRule<T, CM, HM, S, IR, R>
 where
@@ -21,7 +50,7 @@ where
     R: TradingRecord<T, CM, HM, S>,
 {
     ref_ind: Arc<IR>,
-    prev: PreviousValueIndicator<T, S, IR>,
+    slope: SlopeSource<T, S, IR>,
     min_slope: T,
     max_slope: T,
     base_rule: BaseRule,
@@ -57,13 +86,31 @@ where
         let prev = PreviousValueIndicator::with_n(ref_ind.clone(), nth_previous);
         Self {
             ref_ind,
-            prev,
+            slope: SlopeSource::Difference(prev),
             min_slope,
             max_slope,
             base_rule: BaseRule::new("InSlopeRule"),
             _phantom: PhantomData,
         }
     }
+
+    /// 使用最近 bar_count 个值的回归斜率代替两点差值
+    pub fn with_regression(
+        ref_ind: Arc<IR>,
+        bar_count: usize,
+        min_slope: T,
+        max_slope: T,
+    ) -> Result<Self, IndicatorError> {
+        let slope = LinearRegressionSlopeIndicator::new(ref_ind.clone(), bar_count)?;
+        Ok(Self {
+            ref_ind,
+            slope: SlopeSource::Regression(slope),
+            min_slope,
+            max_slope,
+            base_rule: BaseRule::new("InSlopeRule"),
+            _phantom: PhantomData,
+        })
+    }
 }
 
 impl<T, CM, HM, S, IR, R> Clone for InSlopeRule<T, CM, HM, S, IR, R>
@@ -78,7 +125,7 @@ where
     fn clone(&self) -> Self {
         Self {
             ref_ind: self.ref_ind.clone(),
-            prev: self.prev.clone(),
+            slope: self.slope.clone(),
             min_slope: self.min_slope.clone(),
             max_slope: self.max_slope.clone(),
             base_rule: self.base_rule.clone(),
@@ -113,9 +160,15 @@ where
             return false;
         }
 
-        // 计算 ref - prev
-        let diff = BinaryOperation::difference(self.ref_ind.clone(), Arc::new(self.prev.clone()));
-        let val = match diff.get_value(index) {
+        let slope = match &self.slope {
+            // 计算 ref - prev
+            SlopeSource::Difference(prev) => {
+                BinaryOperation::difference(self.ref_ind.clone(), Arc::new(prev.clone()))
+                    .get_value(index)
+            }
+            SlopeSource::Regression(slope) => slope.get_value(index),
+        };
+        let val = match slope {
             Ok(v) => v,
             Err(_) => return false,
         };
@@ -1,8 +1,14 @@
 use crate::IndicatorFactory;
-use ta4r::bar::types::BarSeries;
+use std::cell::RefCell;
+use std::sync::Arc;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::bar::builder::mocks::mock_bar_series_builder::MockBarSeriesBuilder;
+use ta4r::bar::types::{BarBuilder, BarSeries};
 use ta4r::indicators::Indicator;
+use ta4r::indicators::helpers::close_price_indicator::ClosePriceIndicator;
 use ta4r::indicators::types::IndicatorError;
 use ta4r::num::TrNum;
+use time::{Duration, OffsetDateTime};
 
 /// 运行时选择的数值类型
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -71,3 +77,171 @@ pub fn assert_num_eq<T: TrNum>(expected: f64, actual: T) {
         actual_f64
     );
 }
+
+/// ta4j 参考值通常保留 4 位小数，对应 TestUtils.GENERAL_OFFSET
+pub const GENERAL_OFFSET: f64 = 0.0001;
+
+// 帮助函数：按指定误差断言数字相等
+pub fn assert_num_eq_with_offset<T: TrNum>(expected: f64, actual: T, offset: f64) {
+    let actual_f64 = actual.to_f64().unwrap_or(f64::NAN);
+    assert!(
+        (expected - actual_f64).abs() <= offset,
+        "expected: {} (±{}), actual: {:?} (as f64: {})",
+        expected,
+        offset,
+        actual,
+        actual_f64
+    );
+}
+
+// 帮助函数：从索引 0 开始逐个校验指标值
+pub fn assert_values<I, T>(expected: &[f64], indicator: &I)
+where
+    T: TrNum + 'static,
+    I: Indicator<Num = T, Output = T>,
+{
+    assert_values_from(0, expected, indicator);
+}
+
+// 帮助函数：从 offset 开始逐个校验指标值
+pub fn assert_values_from<I, T>(offset: usize, expected: &[f64], indicator: &I)
+where
+    T: TrNum + 'static,
+    I: Indicator<Num = T, Output = T>,
+{
+    for (i, value) in expected.iter().enumerate() {
+        let actual = indicator.get_value(offset + i).unwrap();
+        assert_num_eq_with_offset(*value, actual, GENERAL_OFFSET);
+    }
+}
+
+// 帮助函数：按给定索引逐个校验指标值
+pub fn assert_values_at<I, T>(indexes: &[usize], expected: &[f64], indicator: &I)
+where
+    T: TrNum + 'static,
+    I: Indicator<Num = T, Output = T>,
+{
+    for (index, value) in indexes.iter().zip(expected) {
+        assert_num_eq_with_offset(*value, indicator.get_value(*index).unwrap(), GENERAL_OFFSET);
+    }
+}
+
+// 帮助函数：按收盘价数据构造测试序列上的收盘价指标
+pub fn close_price<T: TrNum + 'static>(
+    factory: Arc<T::Factory>,
+    data: &[f64],
+) -> Arc<ClosePriceIndicator<T, BaseBarSeries<T>>> {
+    let series = MockBarSeriesBuilder::<T>::default()
+        .with_num_factory(factory)
+        .with_data(data.to_vec())
+        .build();
+    Arc::new(ClosePriceIndicator::from_mut(Arc::new(RefCell::new(
+        series,
+    ))))
+}
+
+/// ta4j 均线、PPO 与动量指标测试共用的 30 个收盘价
+pub const TA4J_CLOSE_PRICES: [f64; 30] = [
+    22.27, 22.19, 22.08, 22.17, 22.18, 22.13, 22.23, 22.43, 22.24, 22.29, 22.15, 22.39, 22.38,
+    22.61, 23.36, 24.05, 23.75, 23.83, 23.95, 23.63, 23.82, 23.87, 23.65, 23.19, 23.10, 23.33,
+    22.68, 23.10, 21.40, 20.17,
+];
+
+/// 一根测试 bar 的 (open, high, low, close, volume)
+pub type Ohlcv = (f64, f64, f64, f64, f64);
+
+// 帮助函数：按 OHLCV 数据构造测试序列
+pub fn ohlcv_series<T: TrNum + 'static>(
+    factory: Arc<T::Factory>,
+    bars: &[Ohlcv],
+) -> BaseBarSeries<T> {
+    build_ohlcv_series(factory, bars, None)
+}
+
+// 帮助函数：按固定周期构造共享的测试序列，第一根 bar 在 first_end_time 结束
+pub fn timed_ohlcv_series<T: TrNum + 'static>(
+    factory: Arc<T::Factory>,
+    bars: &[Ohlcv],
+    first_end_time: OffsetDateTime,
+    period: Duration,
+) -> Arc<RefCell<BaseBarSeries<T>>> {
+    Arc::new(RefCell::new(build_ohlcv_series(
+        factory,
+        bars,
+        Some((first_end_time, period)),
+    )))
+}
+
+fn build_ohlcv_series<T: TrNum + 'static>(
+    factory: Arc<T::Factory>,
+    bars: &[Ohlcv],
+    timing: Option<(OffsetDateTime, Duration)>,
+) -> BaseBarSeries<T> {
+    let mut series = MockBarSeriesBuilder::<T>::default()
+        .with_num_factory(factory)
+        .build();
+    let num = |value: f64| T::from_f64(value).unwrap_or_else(T::zero);
+    for (i, &(open, high, low, close, volume)) in bars.iter().enumerate() {
+        let mut builder = series.bar_builder();
+        if let Some((first_end_time, period)) = timing {
+            builder
+                .time_period(period)
+                .end_time(first_end_time + period * i as u32);
+        }
+        builder
+            .open_price(num(open))
+            .high_price(num(high))
+            .low_price(num(low))
+            .close_price(num(close))
+            .volume(num(volume))
+            .add()
+            .expect("Failed to add bar");
+    }
+    series
+}
+
+/// ta4j 多个指标测试共用的 15 根 bar，成交量为 0
+pub const TA4J_BARS: [Ohlcv; 15] = [
+    (44.98, 119.50, 116.00, 119.13, 0.0),
+    (45.05, 119.94, 116.00, 116.75, 0.0),
+    (45.11, 118.44, 111.63, 113.50, 0.0),
+    (45.19, 114.19, 110.06, 111.56, 0.0),
+    (45.12, 112.81, 109.63, 112.25, 0.0),
+    (45.15, 113.44, 109.13, 110.00, 0.0),
+    (45.13, 115.81, 110.38, 113.50, 0.0),
+    (45.12, 117.50, 114.06, 117.38, 0.0),
+    (45.15, 118.44, 114.81, 116.38, 0.0),
+    (45.22, 120.88, 116.69, 120.88, 0.0),
+    (45.26, 121.94, 119.31, 121.38, 0.0),
+    (45.29, 121.38, 118.94, 120.50, 0.0),
+    (45.35, 121.31, 117.44, 121.06, 0.0),
+    (45.28, 121.69, 119.03, 120.00, 0.0),
+    (45.19, 121.25, 117.19, 118.38, 0.0),
+];
+
+// 帮助函数：按 OHLCV 数据构造可供指标共享的测试序列
+pub fn ohlcv_series_mut<T: TrNum + 'static>(
+    factory: Arc<T::Factory>,
+    bars: &[Ohlcv],
+) -> Arc<RefCell<BaseBarSeries<T>>> {
+    Arc::new(RefCell::new(ohlcv_series(factory, bars)))
+}
+
+/// 接在 TA4J_BARS 之后的两根回落 bar，用于触发趋势反转
+pub const TA4J_PULLBACK_BARS: [Ohlcv; 2] = [
+    (45.10, 119.00, 114.00, 114.50, 0.0),
+    (45.05, 116.00, 112.00, 112.50, 0.0),
+];
+
+// 帮助函数：按 TA4J_BARS 构造共享的测试序列
+pub fn ta4j_series<T: TrNum + 'static>(factory: Arc<T::Factory>) -> Arc<RefCell<BaseBarSeries<T>>> {
+    ta4j_series_with(factory, &[])
+}
+
+// 帮助函数：在 TA4J_BARS 之后追加 extra 构造共享的测试序列
+pub fn ta4j_series_with<T: TrNum + 'static>(
+    factory: Arc<T::Factory>,
+    extra: &[Ohlcv],
+) -> Arc<RefCell<BaseBarSeries<T>>> {
+    ohlcv_series_mut(factory, &[TA4J_BARS.as_slice(), extra].concat())
+}
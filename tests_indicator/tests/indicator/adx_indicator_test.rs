@@ -0,0 +1,116 @@
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::adx_indicator::AdxIndicator;
+use ta4r::indicators::dx_indicator::DxIndicator;
+use ta4r::indicators::helpers::constant_indicator::ConstantIndicator;
+use ta4r::indicators::minus_dm_indicator::MinusDmIndicator;
+use ta4r::indicators::plus_dm_indicator::PlusDmIndicator;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::num::{NumFactory, TrNum};
+use ta4r::rule::Rule;
+use ta4r::rule::over_indicator_rule::OverIndicatorRule;
+use tests_indicator::types::{assert_values, ta4j_series};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_directional_movement_double(#[case] factory: DoubleNumFactory) {
+    test_directional_movement::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_directional_movement_decimal(#[case] factory: DecimalNumFactory) {
+    test_directional_movement::<DecimalNum>(Arc::new(factory));
+}
+fn test_directional_movement<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series::<T>(factory);
+
+    let plus_dm = PlusDmIndicator::from_mut(series.clone());
+    assert_values(
+        &[
+            0.0, 0.44, 0.0, 0.0, 0.0, 0.63, 2.37, 1.69, 0.94, 2.44, 1.06, 0.0, 0.0, 0.38, 0.0,
+        ],
+        &plus_dm,
+    );
+    let minus_dm = MinusDmIndicator::from_mut(series.clone());
+    assert_values(
+        &[
+            0.0, 0.0, 4.37, 1.57, 0.43, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.37, 1.5, 0.0, 1.84,
+        ],
+        &minus_dm,
+    );
+
+    let dx = DxIndicator::from_mut(series, 3).unwrap();
+    assert_eq!(3, dx.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.0, 4.0219, 2.0799, 1.4451, 1.0685, 5.7610, 20.1893, 26.7725, 26.5191, 36.2, 37.1624,
+            28.0186, 17.6731, 16.7391, 10.2615,
+        ],
+        dx.plus_di().as_ref(),
+    );
+    assert_values(
+        &[
+            0.0, 0.0, 30.9856, 33.1308, 28.0203, 18.3157, 10.7719, 7.5574, 5.3743, 3.4962, 2.6763,
+            5.7489, 17.9377, 12.9919, 25.5022,
+        ],
+        dx.minus_di().as_ref(),
+    );
+    // index 0 两个方向都没有动向
+    assert_values(
+        &[
+            0.0, 100.0, 87.4196, 91.6409, 92.6537, 52.1447, 30.4168, 55.9720, 66.2985, 82.3850,
+            86.5642, 65.9499, 0.7431, 12.6038, 42.6151,
+        ],
+        &dx,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_adx_double(#[case] factory: DoubleNumFactory) {
+    test_adx::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_adx_decimal(#[case] factory: DecimalNumFactory) {
+    test_adx::<DecimalNum>(Arc::new(factory));
+}
+fn test_adx<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series::<T>(factory.clone());
+    assert!(AdxIndicator::from_mut(series.clone(), 0).is_err());
+
+    let adx = Arc::new(AdxIndicator::from_mut(series, 3).unwrap());
+    assert_eq!(6, adx.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.0, 33.3333, 51.3621, 64.7884, 74.0768, 66.7661, 54.6497, 55.0904, 58.8265, 66.6793,
+            73.3076, 70.8550, 47.4844, 35.8575, 38.1101,
+        ],
+        adx.as_ref(),
+    );
+
+    // ADX 过滤：趋势强度高于 60
+    let threshold = Arc::new(ConstantIndicator::new(
+        adx.bar_series(),
+        factory.num_of_f64(60.0),
+    ));
+    let rule: OverIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        OverIndicatorRule::new(adx, threshold);
+    let trending: Vec<usize> = (0..15).filter(|i| rule.is_satisfied(*i)).collect();
+    assert_eq!(vec![3, 4, 5, 9, 10, 11], trending);
+}
@@ -0,0 +1,165 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::aroon::aroon_down_indicator::AroonDownIndicator;
+use ta4r::indicators::aroon::aroon_oscillator_indicator::AroonOscillatorIndicator;
+use ta4r::indicators::aroon::aroon_up_indicator::AroonUpIndicator;
+use ta4r::indicators::helpers::high_price_indicator::HighPriceIndicator;
+use ta4r::indicators::helpers::highest_value_indicator::HighestValueIndicator;
+use ta4r::indicators::helpers::low_price_indicator::LowPriceIndicator;
+use ta4r::indicators::helpers::lowest_value_indicator::LowestValueIndicator;
+use ta4r::indicators::vortex::minus_vi_indicator::MinusViIndicator;
+use ta4r::indicators::vortex::plus_vi_indicator::PlusViIndicator;
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use tests_indicator::types::{
+    GENERAL_OFFSET, TA4J_PULLBACK_BARS, assert_num_eq_with_offset, assert_values, ta4j_series_with,
+};
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_highest_lowest_index_double(#[case] factory: DoubleNumFactory) {
+    test_highest_lowest_index::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_highest_lowest_index_decimal(#[case] factory: DecimalNumFactory) {
+    test_highest_lowest_index::<DecimalNum>(Arc::new(factory));
+}
+fn test_highest_lowest_index<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series_with::<T>(factory, &TA4J_PULLBACK_BARS);
+
+    let high = Arc::new(HighPriceIndicator::from_mut(series.clone()));
+    let highest = HighestValueIndicator::new(high, 3);
+    let indexes: Vec<usize> = (0..17).map(|i| highest.highest_index(i).unwrap()).collect();
+    assert_eq!(
+        vec![0, 1, 1, 1, 2, 3, 6, 7, 8, 9, 10, 10, 10, 13, 13, 13, 14],
+        indexes
+    );
+    assert_eq!(2, highest.bars_since_highest(12).unwrap());
+    let extreme = highest.get_extreme(12).unwrap();
+    assert_eq!(10, extreme.index);
+    assert_num_eq_with_offset(121.94, extreme.value, GENERAL_OFFSET);
+    assert_num_eq_with_offset(121.94, highest.get_value(12).unwrap(), GENERAL_OFFSET);
+
+    let low = Arc::new(LowPriceIndicator::from_mut(series));
+    let lowest = LowestValueIndicator::new(low, 4);
+    assert_eq!(5, lowest.lowest_index(7).unwrap());
+    assert_eq!(2, lowest.bars_since_lowest(7).unwrap());
+    assert_num_eq_with_offset(109.13, lowest.get_value(7).unwrap(), GENERAL_OFFSET);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_aroon_double(#[case] factory: DoubleNumFactory) {
+    test_aroon::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_aroon_decimal(#[case] factory: DecimalNumFactory) {
+    test_aroon::<DecimalNum>(Arc::new(factory));
+}
+fn test_aroon<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series_with::<T>(factory, &TA4J_PULLBACK_BARS);
+
+    assert!(AroonUpIndicator::from_mut(series.clone(), 0).is_err());
+    let up = AroonUpIndicator::from_mut(series.clone(), 5).unwrap();
+    assert_eq!(5, up.count_of_unstable_bars());
+    assert_values(
+        &[
+            100.0, 100.0, 80.0, 60.0, 40.0, 20.0, 0.0, 0.0, 100.0, 100.0, 100.0, 80.0, 60.0, 40.0,
+            20.0, 0.0, 40.0,
+        ],
+        &up,
+    );
+
+    let down = AroonDownIndicator::from_mut(series.clone(), 5).unwrap();
+    assert_values(
+        &[
+            100.0, 100.0, 100.0, 100.0, 100.0, 100.0, 80.0, 60.0, 40.0, 20.0, 0.0, 0.0, 0.0, 0.0,
+            0.0, 100.0, 100.0,
+        ],
+        &down,
+    );
+
+    let oscillator = AroonOscillatorIndicator::from_mut(series, 5).unwrap();
+    assert_eq!(5, oscillator.aroon_up().bar_count());
+    assert_values(
+        &[
+            0.0, 0.0, -20.0, -40.0, -60.0, -80.0, -80.0, -60.0, 60.0, 80.0, 100.0, 80.0, 60.0,
+            40.0, 20.0, -100.0, -60.0,
+        ],
+        &oscillator,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_vortex_double(#[case] factory: DoubleNumFactory) {
+    test_vortex::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_vortex_decimal(#[case] factory: DecimalNumFactory) {
+    test_vortex::<DecimalNum>(Arc::new(factory));
+}
+fn test_vortex<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series_with::<T>(factory, &TA4J_PULLBACK_BARS);
+
+    assert!(PlusViIndicator::from_mut(series.clone(), 0).is_err());
+    let plus_vi = PlusViIndicator::from_mut(series.clone(), 5).unwrap();
+    assert_eq!(5, plus_vi.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.0, 0.52957, 0.44772, 0.4864, 0.54221, 0.69289, 0.75248, 1.06953, 1.18204, 1.26112,
+            1.43413, 1.44709, 1.17985, 1.24286, 1.03193, 0.70549, 0.64574,
+        ],
+        &plus_vi,
+    );
+
+    let minus_vi = MinusViIndicator::from_mut(series, 5).unwrap();
+    assert_values(
+        &[
+            0.0, 0.47043, 0.82877, 1.09848, 1.14796, 1.2709, 1.1547, 1.0, 0.75203, 0.58112,
+            0.52601, 0.62558, 0.75864, 0.77888, 0.97637, 1.16306, 1.27463,
+        ],
+        &minus_vi,
+    );
+}
@@ -0,0 +1,153 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::bar::builder::types::BarSeriesRef;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::chandelier::chandelier_exit_long_indicator::ChandelierExitLongIndicator;
+use ta4r::indicators::chandelier::chandelier_exit_short_indicator::ChandelierExitShortIndicator;
+use ta4r::indicators::helpers::close_price_indicator::ClosePriceIndicator;
+use ta4r::indicators::supertrend_indicator::SupertrendIndicator;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::num::{NumFactory, TrNum};
+use ta4r::rule::Rule;
+use ta4r::rule::crossed_down_indicator_rule::CrossedDownIndicatorRule;
+use ta4r::rule::crossed_up_indicator_rule::CrossedUpIndicatorRule;
+use tests_indicator::types::{
+    GENERAL_OFFSET, TA4J_PULLBACK_BARS, assert_num_eq_with_offset, assert_values, ta4j_series_with,
+};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_supertrend_double(#[case] factory: DoubleNumFactory) {
+    test_supertrend::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_supertrend_decimal(#[case] factory: DecimalNumFactory) {
+    test_supertrend::<DecimalNum>(Arc::new(factory));
+}
+fn test_supertrend<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let multiplier = factory.num_of_f64(1.5);
+    let series = ta4j_series_with::<T>(factory, &TA4J_PULLBACK_BARS);
+
+    assert!(SupertrendIndicator::from_mut(series.clone(), 0, multiplier.clone()).is_err());
+    assert!(SupertrendIndicator::from_mut(series.clone(), 3, T::zero()).is_err());
+
+    let supertrend =
+        Arc::new(SupertrendIndicator::from_mut(series.clone(), 3, multiplier).unwrap());
+    assert_eq!(3, supertrend.count_of_unstable_bars());
+    // 3 转为下降趋势，7 向上反转，15 再次向下反转
+    assert_values(
+        &[
+            112.5, 112.5, 112.5, 118.89111, 117.32074, 117.32074, 117.32074, 109.07793, 110.34195,
+            112.3463, 115.01753, 115.20169, 115.20169, 115.53631, 115.53631, 122.4972, 119.99813,
+        ],
+        supertrend.as_ref(),
+    );
+    let flips: Vec<usize> = (0..17)
+        .filter(|i| supertrend.is_trend_changed(*i).unwrap())
+        .collect();
+    assert_eq!(vec![3, 7, 15], flips);
+    assert!(supertrend.is_uptrend(10).unwrap());
+    assert!(!supertrend.is_uptrend(16).unwrap());
+    let state = supertrend.state(4).unwrap();
+    assert_num_eq_with_offset(117.32074, state.upper_band, GENERAL_OFFSET);
+
+    // 收盘价下穿 Supertrend：多头离场
+    let close = Arc::new(ClosePriceIndicator::from_mut(series));
+    let rule: CrossedDownIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        CrossedDownIndicatorRule::new(close, supertrend);
+    let signals: Vec<usize> = (0..17).filter(|i| rule.is_satisfied(*i)).collect();
+    assert_eq!(vec![3, 15], signals);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_chandelier_exit_double(#[case] factory: DoubleNumFactory) {
+    test_chandelier_exit::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_chandelier_exit_decimal(#[case] factory: DecimalNumFactory) {
+    test_chandelier_exit::<DecimalNum>(Arc::new(factory));
+}
+fn test_chandelier_exit<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series_with::<T>(factory, &TA4J_PULLBACK_BARS);
+
+    assert!(ChandelierExitLongIndicator::from_mut(series.clone(), 0, T::one()).is_err());
+    let long =
+        Arc::new(ChandelierExitLongIndicator::from_mut(series.clone(), 3, T::one()).unwrap());
+    assert_eq!(3, long.count_of_unstable_bars());
+    assert_values(
+        &[
+            116.0, 116.29333, 115.23889, 115.42926, 114.37284, 110.04189, 111.10793, 113.03195,
+            114.2513, 116.58753, 118.20169, 118.63446, 118.44631, 118.4742, 118.1928, 117.69187,
+            117.25125,
+        ],
+        long.as_ref(),
+    );
+
+    let short =
+        Arc::new(ChandelierExitShortIndicator::from_mut(series.clone(), 3, T::one()).unwrap());
+    assert_values(
+        &[
+            119.5, 119.64667, 116.33111, 114.57074, 113.69716, 113.27811, 113.83207, 113.59805,
+            114.5687, 118.35247, 118.54831, 119.99554, 120.93369, 120.6558, 120.6872, 117.99813,
+            115.99875,
+        ],
+        short.as_ref(),
+    );
+
+    let close = Arc::new(ClosePriceIndicator::from_mut(series.clone()));
+    let long_exit: CrossedDownIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        CrossedDownIndicatorRule::new(close.clone(), long);
+    let signals: Vec<usize> = (0..17).filter(|i| long_exit.is_satisfied(*i)).collect();
+    assert_eq!(vec![2, 15], signals);
+
+    let short_exit: CrossedUpIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        CrossedUpIndicatorRule::new(close, short);
+    let signals: Vec<usize> = (0..17).filter(|i| short_exit.is_satisfied(*i)).collect();
+    assert_eq!(vec![7], signals);
+
+    let defaults = ChandelierExitLongIndicator::with_defaults(BarSeriesRef::Mut(series)).unwrap();
+    assert_eq!(22, defaults.bar_count());
+    assert_num_eq_with_offset(3.0, defaults.k().clone(), GENERAL_OFFSET);
+}
@@ -1 +1,2 @@
+pub mod moving_average_indicator_test;
 pub mod sma_indicator_test;
@@ -0,0 +1,213 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::averages::dema_indicator::DemaIndicator;
+use ta4r::indicators::averages::hma_indicator::HmaIndicator;
+use ta4r::indicators::averages::kama_indicator::KamaIndicator;
+use ta4r::indicators::averages::mcginley_dynamic_indicator::McGinleyDynamicIndicator;
+use ta4r::indicators::averages::t3_indicator::T3Indicator;
+use ta4r::indicators::averages::tema_indicator::TemaIndicator;
+use ta4r::indicators::averages::vidya_indicator::VidyaIndicator;
+use ta4r::indicators::averages::wma_indicator::WmaIndicator;
+use ta4r::indicators::averages::zlema_indicator::ZlemaIndicator;
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use tests_indicator::types::{
+    GENERAL_OFFSET, TA4J_CLOSE_PRICES, assert_num_eq_with_offset, assert_values, assert_values_at,
+    close_price,
+};
+
+/// 检查的索引，覆盖预热阶段与稳定阶段
+const INDEXES: [usize; 8] = [0, 1, 5, 10, 15, 20, 25, 29];
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_ema_based_averages_double(#[case] factory: DoubleNumFactory) {
+    test_ema_based_averages::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_ema_based_averages_decimal(#[case] factory: DecimalNumFactory) {
+    test_ema_based_averages::<DecimalNum>(Arc::new(factory));
+}
+fn test_ema_based_averages<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &TA4J_CLOSE_PRICES);
+
+    assert!(DemaIndicator::new(close.clone(), 0).is_err());
+    let dema = DemaIndicator::new(close.clone(), 10).unwrap();
+    assert_eq!(10, dema.count_of_unstable_bars());
+    assert_values_at(
+        &INDEXES,
+        &[
+            22.27, 22.24355, 22.15292, 22.22917, 23.20621, 23.90541, 23.51531, 21.75549,
+        ],
+        &dema,
+    );
+    // 组合均线的不稳定 bar 数为输入的不稳定 bar 数加 n
+    let nested = DemaIndicator::new(Arc::new(DemaIndicator::new(close.clone(), 5).unwrap()), 10);
+    assert_eq!(15, nested.unwrap().count_of_unstable_bars());
+
+    assert!(TemaIndicator::new(close.clone(), 0).is_err());
+    let tema = TemaIndicator::new(close.clone(), 10).unwrap();
+    assert_eq!(10, tema.count_of_unstable_bars());
+    assert_values_at(
+        &INDEXES,
+        &[
+            22.27, 22.23382, 22.13403, 22.23255, 23.49107, 24.02263, 23.35205, 21.14466,
+        ],
+        &tema,
+    );
+
+    assert!(T3Indicator::with_default_volume_factor(close.clone(), 0).is_err());
+    let t3 = T3Indicator::with_default_volume_factor(close.clone(), 5).unwrap();
+    assert_eq!(5, t3.count_of_unstable_bars());
+    assert_num_eq_with_offset(0.7, t3.volume_factor().clone(), GENERAL_OFFSET);
+    assert_values_at(
+        &INDEXES,
+        &[
+            22.27, 22.26065, 22.16533, 22.25215, 22.80191, 23.88395, 23.55601, 22.38027,
+        ],
+        &t3,
+    );
+
+    assert!(ZlemaIndicator::new(close.clone(), 0).is_err());
+    let zlema = ZlemaIndicator::new(close, 10).unwrap();
+    assert_eq!(10, zlema.count_of_unstable_bars());
+    assert_values_at(
+        &INDEXES,
+        &[
+            22.27, 22.25545, 22.16925, 22.27791, 23.35225, 23.88652, 23.2732, 21.41518,
+        ],
+        &zlema,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_wma_double(#[case] factory: DoubleNumFactory) {
+    test_wma::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_wma_decimal(#[case] factory: DecimalNumFactory) {
+    test_wma::<DecimalNum>(Arc::new(factory));
+}
+fn test_wma<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &[1., 2., 3., 4., 5., 6.]);
+
+    // index < bar_count - 1 时按已有的 index + 1 个值加权
+    let wma = WmaIndicator::new(close, 3);
+    assert_values(&[1.0, 1.6667, 2.3333, 3.3333, 4.3333, 5.3333], &wma);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_hma_double(#[case] factory: DoubleNumFactory) {
+    test_hma::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_hma_decimal(#[case] factory: DecimalNumFactory) {
+    test_hma::<DecimalNum>(Arc::new(factory));
+}
+fn test_hma<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &TA4J_CLOSE_PRICES);
+
+    assert!(HmaIndicator::new(close.clone(), 1).is_err());
+    let hma = HmaIndicator::new(close, 9).unwrap();
+    assert_eq!(9, hma.count_of_unstable_bars());
+    assert_values_at(
+        &INDEXES,
+        &[
+            22.27, 22.23444, 22.14723, 22.292, 23.40056, 23.96411, 23.12481, 21.36496,
+        ],
+        &hma,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_adaptive_averages_double(#[case] factory: DoubleNumFactory) {
+    test_adaptive_averages::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_adaptive_averages_decimal(#[case] factory: DecimalNumFactory) {
+    test_adaptive_averages::<DecimalNum>(Arc::new(factory));
+}
+fn test_adaptive_averages<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &TA4J_CLOSE_PRICES);
+
+    assert!(KamaIndicator::new(close.clone(), 0, 2, 30).is_err());
+    let kama = KamaIndicator::with_defaults(close.clone()).unwrap();
+    assert_eq!(10, kama.count_of_unstable_bars());
+    assert_values_at(
+        &INDEXES,
+        &[
+            22.27, 22.19, 22.13, 22.28743, 22.90906, 23.43377, 23.48654, 22.42271,
+        ],
+        &kama,
+    );
+
+    assert!(VidyaIndicator::new(close.clone(), 9, 0).is_err());
+    let vidya = VidyaIndicator::with_default_cmo(close.clone(), 9).unwrap();
+    assert_eq!(9, vidya.count_of_unstable_bars());
+    assert_values_at(
+        &INDEXES,
+        &[
+            22.27, 22.254, 22.2066, 22.21807, 22.64254, 23.17148, 23.26225, 22.58922,
+        ],
+        &vidya,
+    );
+
+    assert!(McGinleyDynamicIndicator::new(close.clone(), 0).is_err());
+    let mcginley = McGinleyDynamicIndicator::new(close, 10).unwrap();
+    assert_eq!(10, mcginley.count_of_unstable_bars());
+    assert_values_at(
+        &INDEXES,
+        &[
+            22.27, 22.26188, 22.21987, 22.23604, 22.50946, 22.9601, 23.12425, 22.40975,
+        ],
+        &mcginley,
+    );
+}
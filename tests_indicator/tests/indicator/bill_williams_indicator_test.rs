@@ -0,0 +1,215 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::bill_williams::AlligatorLine;
+use ta4r::indicators::bill_williams::accelerator_oscillator_indicator::AcceleratorOscillatorIndicator;
+use ta4r::indicators::bill_williams::alligator_indicator::AlligatorLineIndicator;
+use ta4r::indicators::bill_williams::awesome_oscillator_indicator::AwesomeOscillatorIndicator;
+use ta4r::indicators::bill_williams::gator_oscillator_indicator::GatorOscillatorIndicator;
+use ta4r::indicators::helpers::median_price_indicator::MedianPriceIndicator;
+use ta4r::indicators::types::IndicatorError;
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use tests_indicator::types::{GENERAL_OFFSET, Ohlcv, assert_num_eq_with_offset, ohlcv_series_mut};
+
+/// (open, high, low, close, volume)
+const BARS: [Ohlcv; 45] = [
+    (99.70, 102.00, 98.50, 100.00, 0.0),
+    (101.93, 104.00, 100.31, 102.61, 0.0),
+    (104.04, 105.63, 102.38, 104.54, 0.0),
+    (105.90, 107.21, 104.63, 105.93, 0.0),
+    (107.43, 108.90, 106.61, 107.38, 0.0),
+    (108.54, 110.48, 107.82, 108.93, 0.0),
+    (109.18, 111.46, 108.12, 109.88, 0.0),
+    (109.32, 111.50, 107.79, 109.75, 0.0),
+    (108.97, 110.70, 107.28, 108.94, 0.0),
+    (108.17, 109.52, 106.77, 108.17, 0.0),
+    (106.99, 108.37, 106.06, 107.46, 0.0),
+    (105.50, 107.31, 104.80, 106.20, 0.0),
+    (103.83, 106.05, 102.90, 104.17, 0.0),
+    (102.08, 104.34, 100.67, 102.01, 0.0),
+    (100.39, 102.26, 98.70, 100.45, 0.0),
+    (98.88, 100.30, 97.35, 99.42, 0.0),
+    (97.65, 98.97, 96.59, 98.32, 0.0),
+    (96.79, 98.45, 96.07, 97.05, 0.0),
+    (96.38, 98.51, 95.56, 96.28, 0.0),
+    (96.46, 98.75, 95.18, 96.59, 0.0),
+    (97.03, 99.03, 95.37, 97.63, 0.0),
+    (98.08, 99.60, 96.46, 98.71, 0.0),
+    (99.56, 100.86, 98.36, 99.73, 0.0),
+    (101.38, 102.92, 100.61, 101.28, 0.0),
+    (103.46, 105.48, 102.72, 103.68, 0.0),
+    (105.68, 107.98, 104.55, 106.34, 0.0),
+    (107.92, 110.04, 106.34, 108.50, 0.0),
+    (110.05, 111.70, 108.37, 110.14, 0.0),
+    (111.96, 113.27, 110.62, 111.87, 0.0),
+    (113.53, 114.96, 112.67, 113.84, 0.0),
+    (114.70, 116.58, 114.00, 115.39, 0.0),
+    (115.41, 117.66, 114.41, 115.91, 0.0),
+    (115.61, 117.83, 114.14, 115.64, 0.0),
+    (115.33, 117.12, 113.63, 115.28, 0.0),
+    (114.59, 115.96, 113.12, 114.97, 0.0),
+    (113.45, 114.80, 112.46, 114.15, 0.0),
+    (112.00, 113.73, 111.29, 112.42, 0.0),
+    (110.34, 112.52, 109.46, 110.31, 0.0),
+    (108.60, 110.88, 107.25, 108.59, 0.0),
+    (106.89, 108.83, 105.21, 107.36, 0.0),
+    (105.35, 106.81, 103.78, 106.04, 0.0),
+    (104.07, 105.38, 102.95, 104.42, 0.0),
+    (103.16, 104.76, 102.42, 103.09, 0.0),
+    (102.69, 104.77, 101.91, 102.75, 0.0),
+    (102.70, 105.00, 101.49, 103.24, 0.0),
+];
+
+fn assert_at<I, T>(expected: &[(usize, f64)], indicator: &I)
+where
+    T: TrNum + 'static,
+    I: Indicator<Num = T, Output = T>,
+{
+    for (index, value) in expected {
+        assert_num_eq_with_offset(*value, indicator.get_value(*index).unwrap(), GENERAL_OFFSET);
+    }
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_alligator_double(#[case] factory: DoubleNumFactory) {
+    test_alligator::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_alligator_decimal(#[case] factory: DecimalNumFactory) {
+    test_alligator::<DecimalNum>(Arc::new(factory));
+}
+fn test_alligator<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ohlcv_series_mut::<T>(factory, &BARS);
+
+    let median = MedianPriceIndicator::from_mut(series.clone());
+    assert_at(&[(0, 100.25), (44, 103.245)], &median);
+
+    // 下颚：SMMA(13) 向前平移 8 根 bar，前 8 根 bar 没有可用的值
+    let jaw =
+        Arc::new(AlligatorLineIndicator::from_mut(series.clone(), AlligatorLine::Jaw).unwrap());
+    assert_eq!((13, 8), (jaw.bar_count(), jaw.shift()));
+    assert_eq!(21, jaw.count_of_unstable_bars());
+    assert!(matches!(
+        jaw.get_value(7),
+        Err(IndicatorError::NotAvailable {
+            index: 7,
+            available_from: 8
+        })
+    ));
+    assert_at(
+        &[(8, 100.25), (20, 104.445736), (44, 108.740067)],
+        jaw.as_ref(),
+    );
+    assert_num_eq_with_offset(107.152796, jaw.leading_value(44).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(108.740067, jaw.leading_value(36).unwrap(), GENERAL_OFFSET);
+
+    let teeth =
+        Arc::new(AlligatorLineIndicator::from_mut(series.clone(), AlligatorLine::Teeth).unwrap());
+    assert_at(
+        &[(8, 101.551768), (20, 103.943347), (44, 110.28214)],
+        teeth.as_ref(),
+    );
+
+    let lips =
+        Arc::new(AlligatorLineIndicator::from_mut(series.clone(), AlligatorLine::Lips).unwrap());
+    assert_at(
+        &[(8, 104.49713), (20, 101.313091), (44, 108.618243)],
+        lips.as_ref(),
+    );
+
+    // 鳄鱼震荡：上方柱 |下颚 - 牙齿|，下方柱 -|牙齿 - 嘴唇|
+    let upper = GatorOscillatorIndicator::upper_from_lines(jaw.clone(), teeth.clone());
+    let lower = GatorOscillatorIndicator::lower_from_lines(teeth, lips);
+    assert_at(&[(8, 1.301768), (20, 0.502389), (44, 1.542073)], &upper);
+    assert_at(&[(8, -2.945362), (20, -2.630256), (44, -1.663897)], &lower);
+    assert_eq!(21, upper.count_of_unstable_bars());
+    assert!(upper.get_value(7).is_err());
+
+    let default_lower = GatorOscillatorIndicator::lower(jaw.bar_series()).unwrap();
+    assert!(!default_lower.is_upper());
+    assert_at(&[(20, -2.630256)], &default_lower);
+
+    assert!(AlligatorLineIndicator::with_periods(jaw.bar_series(), 0, 3).is_err());
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_awesome_accelerator_double(#[case] factory: DoubleNumFactory) {
+    test_awesome_accelerator::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_awesome_accelerator_decimal(#[case] factory: DecimalNumFactory) {
+    test_awesome_accelerator::<DecimalNum>(Arc::new(factory));
+}
+fn test_awesome_accelerator<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ohlcv_series_mut::<T>(factory, &BARS);
+
+    // AO = SMA(中间价, 5) - SMA(中间价, 34)
+    let awesome = Arc::new(AwesomeOscillatorIndicator::from_mut(series.clone()).unwrap());
+    assert_eq!(34, awesome.count_of_unstable_bars());
+    assert_at(
+        &[
+            (10, 2.118818),
+            (33, 9.769412),
+            (40, 2.444676),
+            (44, -2.026235),
+        ],
+        awesome.as_ref(),
+    );
+
+    // AC = AO - SMA(AO, 5)
+    let accelerator = AcceleratorOscillatorIndicator::from_mut(series.clone()).unwrap();
+    assert_eq!(38, accelerator.count_of_unstable_bars());
+    assert_at(
+        &[
+            (10, -0.172962),
+            (33, 1.595721),
+            (40, -2.842482),
+            (44, -1.942829),
+        ],
+        &accelerator,
+    );
+    let shared = AcceleratorOscillatorIndicator::from_awesome(awesome.clone(), 5).unwrap();
+    assert_at(&[(44, -1.942829)], &shared);
+
+    assert!(AwesomeOscillatorIndicator::with_periods(awesome.bar_series(), 34, 5).is_err());
+    assert!(AcceleratorOscillatorIndicator::from_awesome(awesome, 0).is_err());
+}
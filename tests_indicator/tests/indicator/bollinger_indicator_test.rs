@@ -0,0 +1,190 @@
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::bollinger::bollinger_band_width_indicator::BollingerBandWidthIndicator;
+use ta4r::indicators::bollinger::bollinger_bands_lower_indicator::BollingerBandsLowerIndicator;
+use ta4r::indicators::bollinger::bollinger_bands_middle_indicator::{
+    BollingerBandsMiddleIndicator, MiddleBandType,
+};
+use ta4r::indicators::bollinger::bollinger_bands_upper_indicator::BollingerBandsUpperIndicator;
+use ta4r::indicators::bollinger::percent_b_indicator::PercentBIndicator;
+use ta4r::indicators::statistics::standard_deviation_indicator::StandardDeviationIndicator;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::num::{NumFactory, TrNum};
+use ta4r::rule::Rule;
+use ta4r::rule::in_pipe_rule::InPipeRule;
+use tests_indicator::types::{assert_values, close_price};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+/// ta4j BollingerBandsUpperIndicatorTest 使用的数据
+const CLOSES: [f64; 13] = [1., 2., 3., 4., 3., 4., 5., 4., 3., 3., 4., 3., 2.];
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_bands_double(#[case] factory: DoubleNumFactory) {
+    test_bands::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_bands_decimal(#[case] factory: DecimalNumFactory) {
+    test_bands::<DecimalNum>(Arc::new(factory));
+}
+fn test_bands<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &CLOSES);
+    let middle = Arc::new(BollingerBandsMiddleIndicator::sma(close.clone(), 3));
+    let deviation = Arc::new(StandardDeviationIndicator::new(close, 3).unwrap());
+    let upper = Arc::new(BollingerBandsUpperIndicator::with_default_k(
+        middle.clone(),
+        deviation.clone(),
+    ));
+    let lower = Arc::new(BollingerBandsLowerIndicator::with_default_k(
+        middle.clone(),
+        deviation.clone(),
+    ));
+
+    assert_eq!(MiddleBandType::Sma, middle.band_type());
+    assert_eq!(3, upper.count_of_unstable_bars());
+    assert_values(
+        &[
+            1.0, 1.5, 2.0, 3.0, 3.3333, 3.6667, 4.0, 4.3333, 4.0, 3.3333, 3.3333, 3.3333, 3.0,
+        ],
+        middle.as_ref(),
+    );
+    assert_values(
+        &[
+            0.0, 0.5, 0.8165, 0.8165, 0.4714, 0.4714, 0.8165, 0.4714, 0.8165, 0.4714, 0.4714,
+            0.4714, 0.8165,
+        ],
+        deviation.as_ref(),
+    );
+    assert_values(
+        &[
+            1.0, 2.5, 3.6330, 4.6330, 4.2761, 4.6095, 5.6330, 5.2761, 5.6330, 4.2761, 4.2761,
+            4.2761, 4.6330,
+        ],
+        upper.as_ref(),
+    );
+    assert_values(
+        &[
+            1.0, 0.5, 0.3670, 1.3670, 2.3905, 2.7239, 2.3670, 3.3905, 2.3670, 2.3905, 2.3905,
+            2.3905, 1.3670,
+        ],
+        lower.as_ref(),
+    );
+
+    let width = BollingerBandWidthIndicator::new(upper, middle, lower);
+    assert_values(
+        &[
+            0.0, 133.3333, 163.2993, 108.8662, 56.5685, 51.4259, 81.6497, 43.5143, 81.6497,
+            56.5685, 56.5685, 56.5685, 108.8662,
+        ],
+        &width,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_ema_middle_band_double(#[case] factory: DoubleNumFactory) {
+    test_ema_middle_band::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_ema_middle_band_decimal(#[case] factory: DecimalNumFactory) {
+    test_ema_middle_band::<DecimalNum>(Arc::new(factory));
+}
+fn test_ema_middle_band<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &CLOSES);
+    let middle = Arc::new(BollingerBandsMiddleIndicator::ema(close.clone(), 3));
+    let deviation = Arc::new(StandardDeviationIndicator::new(close, 3).unwrap());
+    let upper = BollingerBandsUpperIndicator::with_default_k(middle.clone(), deviation);
+
+    assert_values(
+        &[
+            1.0, 1.5, 2.25, 3.125, 3.0625, 3.5312, 4.2656, 4.1328, 3.5664, 3.2832, 3.6416, 3.3208,
+            2.6604,
+        ],
+        middle.as_ref(),
+    );
+    assert_values(
+        &[
+            1.0, 2.5, 3.8830, 4.7580, 4.0053, 4.4741, 5.8986, 5.0756, 5.1994, 4.2260, 4.5844,
+            4.2636, 4.2934,
+        ],
+        &upper,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_percent_b_double(#[case] factory: DoubleNumFactory) {
+    test_percent_b::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_percent_b_decimal(#[case] factory: DecimalNumFactory) {
+    test_percent_b::<DecimalNum>(Arc::new(factory));
+}
+fn test_percent_b<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory.clone(), &CLOSES);
+    assert!(PercentBIndicator::new(close.clone(), 0, factory.two().as_ref().clone()).is_err());
+
+    let percent_b = PercentBIndicator::new(close, 3, factory.two().as_ref().clone()).unwrap();
+    assert_eq!(3, percent_b.count_of_unstable_bars());
+    // index 0 带宽为 0，视为位于中轨
+    assert_values(
+        &[
+            0.5, 0.75, 0.8062, 0.8062, 0.3232, 0.6768, 0.8062, 0.3232, 0.1938, 0.3232, 0.8536,
+            0.3232, 0.1938,
+        ],
+        &percent_b,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_in_pipe_rule_against_bands_double(#[case] factory: DoubleNumFactory) {
+    test_in_pipe_rule_against_bands::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_in_pipe_rule_against_bands_decimal(#[case] factory: DecimalNumFactory) {
+    test_in_pipe_rule_against_bands::<DecimalNum>(Arc::new(factory));
+}
+fn test_in_pipe_rule_against_bands<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory.clone(), &CLOSES);
+    let middle = Arc::new(BollingerBandsMiddleIndicator::sma(close.clone(), 3));
+    let deviation = Arc::new(StandardDeviationIndicator::new(close.clone(), 3).unwrap());
+    let upper = Arc::new(BollingerBandsUpperIndicator::new(
+        middle.clone(),
+        deviation.clone(),
+        factory.one().as_ref().clone(),
+    ));
+    let lower = Arc::new(BollingerBandsLowerIndicator::new(
+        middle,
+        deviation,
+        factory.one().as_ref().clone(),
+    ));
+
+    let rule: InPipeRule<_, _, _, _, _, _, _, Record<T>> = InPipeRule::new(close, upper, lower);
+    let inside: Vec<usize> = (2..13).filter(|i| rule.is_satisfied(*i)).collect();
+    assert_eq!(vec![4, 5, 7, 9, 11], inside);
+}
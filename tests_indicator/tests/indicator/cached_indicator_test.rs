@@ -1,12 +1,22 @@
 use rstest::rstest;
+use std::cell::RefCell;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use ta4r::bar::base_bar_series::BaseBarSeries;
 use ta4r::bar::base_bar_series_builder::BaseBarSeriesBuilder;
 use ta4r::bar::builder::mocks::mock_bar_series_builder::MockBarSeriesBuilder;
-use ta4r::bar::types::{Bar, BarSeries, BarSeriesBuilder};
+use ta4r::bar::builder::types::BarSeriesRef;
+use ta4r::bar::types::{BarBuilder, BarSeries, BarSeriesBuilder};
 use ta4r::indicators::Indicator;
+use ta4r::indicators::abstract_indicator::BaseIndicator;
+use ta4r::indicators::averages::ema_indicator::EmaIndicator;
 use ta4r::indicators::averages::sma_indicator::SmaIndicator;
+use ta4r::indicators::averages::zlema_indicator::ZlemaIndicator;
+use ta4r::indicators::cached_indicator::CachedIndicator;
 use ta4r::indicators::helpers::close_price_indicator::ClosePriceIndicator;
 use ta4r::indicators::helpers::constant_indicator::ConstantIndicator;
+use ta4r::indicators::recursive_cached_indicator::RecursiveCachedIndicator;
+use ta4r::indicators::types::{IndicatorCalculator, IndicatorError, RecursiveIndicatorCalculator};
 use ta4r::num::decimal_num::DecimalNum;
 use ta4r::num::decimal_num_factory::DecimalNumFactory;
 use ta4r::num::double_num::DoubleNum;
@@ -41,8 +51,10 @@ where
         .with_data(data)
         .build();
 
-    let close_price = ClosePriceIndicator::new(&series);
-    let sma = SmaIndicator::new(&close_price, 3);
+    let close_price = Arc::new(ClosePriceIndicator::from_mut(Arc::new(RefCell::new(
+        series,
+    ))));
+    let sma = SmaIndicator::new(close_price, 3);
 
     let first = sma.get_value(4).unwrap();
     let second = sma.get_value(4).unwrap();
@@ -79,14 +91,14 @@ where
         .build()
         .expect("Failed to build BaseBarSeries");
 
-    let constant = ConstantIndicator::new(&base_series, constant_val.clone());
+    let constant = Arc::new(ConstantIndicator::new(
+        BarSeriesRef::Mut(Arc::new(RefCell::new(base_series))),
+        constant_val.clone(),
+    ));
 
     assert_eq!(constant_val, constant.get_value(0).unwrap());
     assert_eq!(constant_val, constant.get_value(100).unwrap());
 
-    let series_ref = constant.get_bar_series();
-    assert!(std::ptr::eq(series_ref, series_ref)); // 总是 true，或者不写断言
-
     eprintln!(
         "First constant_val value:  {:#?}",
         constant.get_value(0).unwrap()
@@ -96,16 +108,13 @@ where
         constant.get_value(100).unwrap()
     );
 
-    let sma = SmaIndicator::new(&constant, 10);
+    let sma = SmaIndicator::new(constant, 10);
 
     assert_eq!(constant_val, sma.get_value(0).unwrap());
     assert_eq!(constant_val, sma.get_value(100).unwrap());
 
     eprintln!("First sma value:  {:#?}", sma.get_value(0).unwrap());
     eprintln!("Second sma value: {:#?}", sma.get_value(100).unwrap());
-
-    let series_ref = sma.get_bar_series();
-    assert!(std::ptr::eq(series_ref, series_ref)); // 总是 true，或者不写断言
 }
 
 /// cargo test test_get_value_with_cache_length_increase_double -- --nocapture --test-threads=1
@@ -131,8 +140,10 @@ where
         .with_data(data)
         .build();
 
-    let close_price = ClosePriceIndicator::new(&series);
-    let sma = SmaIndicator::new(&close_price, 100);
+    let close_price = Arc::new(ClosePriceIndicator::from_mut(Arc::new(RefCell::new(
+        series,
+    ))));
+    let sma = SmaIndicator::new(close_price, 100);
 
     assert_num_eq(10.0, sma.get_value(105).unwrap());
 
@@ -166,8 +177,10 @@ where
         .set_maximum_bar_count(12)
         .expect("set_maximum_bar_count error");
 
-    let close_price = ClosePriceIndicator::new(&bar_series);
-    let sma = SmaIndicator::new(&close_price, 10);
+    let close_price = Arc::new(ClosePriceIndicator::from_mut(Arc::new(RefCell::new(
+        bar_series,
+    ))));
+    let sma = SmaIndicator::new(close_price, 10);
 
     // rust语言层面就避免了修改情况
     // bar_series
@@ -179,6 +192,113 @@ where
     assert_num_eq(1.0, sma.get_value(19).unwrap());
 }
 
+/// 统计计算次数的收盘价计算器，用于判断是否命中缓存
+#[derive(Clone)]
+struct CountingCloseCalculator {
+    calls: Arc<AtomicUsize>,
+}
+
+impl<T> IndicatorCalculator<T, BaseBarSeries<T>> for CountingCloseCalculator
+where
+    T: TrNum + 'static,
+{
+    type Output = T;
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, BaseBarSeries<T>>,
+        index: usize,
+    ) -> Result<T, IndicatorError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        base.get_value(index)
+    }
+}
+
+/// cargo test test_get_value_with_bounded_cache_eviction_double -- --nocapture --test-threads=1
+#[test]
+fn test_get_value_with_bounded_cache_eviction_double() {
+    let factory = Arc::new(DoubleNumFactory::default());
+    test_get_value_with_bounded_cache_eviction::<DoubleNum>(factory);
+}
+/// cargo test test_get_value_with_bounded_cache_eviction_decimal -- --nocapture --test-threads=1
+#[test]
+fn test_get_value_with_bounded_cache_eviction_decimal() {
+    let factory = Arc::new(DecimalNumFactory::default());
+    test_get_value_with_bounded_cache_eviction::<DecimalNum>(factory);
+}
+
+fn test_get_value_with_bounded_cache_eviction<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    // 收盘价 = 索引 + 1
+    let mut bar_series = MockBarSeriesBuilder::<T>::default()
+        .with_num_factory(factory)
+        .with_data(vec![1., 2., 3.])
+        .build();
+    bar_series
+        .set_maximum_bar_count(5)
+        .expect("set_maximum_bar_count error");
+    let series = Arc::new(RefCell::new(bar_series));
+    let add_bar = |close: f64| {
+        series
+            .borrow_mut()
+            .bar_builder()
+            .close_price(T::from_f64(close).unwrap())
+            .add()
+            .expect("Failed to add bar");
+    };
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let cached = CachedIndicator::new_from_series(
+        BarSeriesRef::Mut(series.clone()),
+        CountingCloseCalculator {
+            calls: calls.clone(),
+        },
+    );
+
+    // 逐根追加，缓存窗口随序列一起滑动
+    for end in 3..12usize {
+        add_bar(end as f64 + 1.0);
+        let removed = series.borrow().get_removed_bars_count();
+        assert_eq!(end.saturating_sub(4), removed);
+
+        for index in removed..=end {
+            assert_num_eq(index as f64 + 1.0, cached.get_value(index).unwrap());
+        }
+
+        // 窗口内除最新 bar 外都应命中缓存
+        let before = calls.load(Ordering::SeqCst);
+        for index in removed..=end {
+            assert_num_eq(index as f64 + 1.0, cached.get_value(index).unwrap());
+        }
+        assert_eq!(before + 1, calls.load(Ordering::SeqCst));
+    }
+
+    // 已移除的索引按最早的 bar 计算，且不影响已缓存的结果
+    assert_num_eq(8.0, cached.get_value(0).unwrap());
+    for index in 7..11 {
+        assert_num_eq(index as f64 + 1.0, cached.get_value(index).unwrap());
+    }
+    add_bar(13.0);
+    for index in 8..=12 {
+        assert_num_eq(index as f64 + 1.0, cached.get_value(index).unwrap());
+    }
+
+    // 一次跳过超过 maximum_bar_count 根，整个缓存重建
+    for close in 14..=20 {
+        add_bar(close as f64);
+    }
+    for index in (15..=19).rev() {
+        assert_num_eq(index as f64 + 1.0, cached.get_value(index).unwrap());
+    }
+    let before = calls.load(Ordering::SeqCst);
+    for index in 15..19 {
+        assert_num_eq(index as f64 + 1.0, cached.get_value(index).unwrap());
+    }
+    assert_eq!(before, calls.load(Ordering::SeqCst));
+}
+
 // #[rstest]
 // #[case(NumKind::Double)]
 // #[case(NumKind::Decimal)]
@@ -253,8 +373,10 @@ fn test_get_value_on_results_calculated_from_removed_bars_should_return_first_re
 
     assert_eq!(2, bar_series.get_removed_bars_count());
 
-    let close_price = ClosePriceIndicator::new(&bar_series);
-    let sma = SmaIndicator::new(&close_price, 2);
+    let close_price = Arc::new(ClosePriceIndicator::from_mut(Arc::new(RefCell::new(
+        bar_series,
+    ))));
+    let sma = SmaIndicator::new(close_price, 2);
 
     for i in 0..5 {
         assert_num_eq(1.0, sma.get_value(i).unwrap());
@@ -283,25 +405,68 @@ fn test_recursive_cached_indicator_on_moving_bar_series_should_not_cause_stack_o
 ) where
     T: TrNum + 'static,
 {
+    let mut series = MockBarSeriesBuilder::<T>::default()
+        .with_num_factory(factory)
+        .with_default_data()
+        .build();
 
-    // let mut series = MockBarSeriesBuilder::new()
-    //     .with_num_factory(factory)
-    //     .with_default_data()
-    //     .build();
-    //
-    // series.set_maximum_bar_count(5);
-    //
-    // assert_eq!(5, series.get_bar_count());
-    //
-    // let close_price = ClosePriceIndicator::new(&series);
-    // // todo
-    // let zlema = ZLEMAIndicator::new(Arc::new(close_price), 1);
-    //
-    // let result = std::panic::catch_unwind(|| {
-    //     assert_num_eq(4996.0, zlema.get_value(8).unwrap());
-    // });
-    //
-    // assert!(result.is_ok());
+    series
+        .set_maximum_bar_count(5)
+        .expect("set_maximum_bar_count error");
+
+    assert_eq!(5, series.get_bar_count());
+
+    let close_price = Arc::new(ClosePriceIndicator::from_mut(Arc::new(RefCell::new(
+        series,
+    ))));
+    let zlema = ZlemaIndicator::new(close_price, 1).unwrap();
+
+    // 已移除的索引按第一根剩余 bar 计算，缓存从剩余 bar 开始迭代补齐
+    assert_num_eq(4996.0, zlema.get_value(8).unwrap());
+}
+
+/// cargo test test_get_value_of_earlier_index_after_cache_grows_double -- --nocapture --test-threads=1
+#[test]
+fn test_get_value_of_earlier_index_after_cache_grows_double() {
+    let factory = Arc::new(DoubleNumFactory);
+    test_get_value_of_earlier_index_after_cache_grows::<DoubleNum>(factory);
+}
+/// cargo test test_get_value_of_earlier_index_after_cache_grows_decimal -- --nocapture --test-threads=1
+#[test]
+fn test_get_value_of_earlier_index_after_cache_grows_decimal() {
+    let factory = Arc::new(DecimalNumFactory::default());
+    test_get_value_of_earlier_index_after_cache_grows::<DecimalNum>(factory);
+}
+
+fn test_get_value_of_earlier_index_after_cache_grows<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let data = vec![1., 2., 3., 4., 5., 6., 7., 8.];
+    let series = MockBarSeriesBuilder::<T>::default()
+        .with_num_factory(factory)
+        .with_data(data)
+        .build();
+
+    let close_price = Arc::new(ClosePriceIndicator::from_mut(Arc::new(RefCell::new(
+        series,
+    ))));
+    let sma = SmaIndicator::new(close_price.clone(), 1);
+    let ema = EmaIndicator::new(close_price, 3);
+
+    for i in 0..8 {
+        sma.get_value(i).unwrap();
+        ema.get_value(i).unwrap();
+    }
+
+    // 缓存增长之后，较早的索引仍然取回各自的值
+    for i in 0..8 {
+        assert_num_eq((i + 1) as f64, sma.get_value(i).unwrap());
+    }
+    // EMA 递归依赖自身前值：1, 1.5, 2.25, 3.125, 4.0625
+    assert_num_eq(1.0, ema.get_value(0).unwrap());
+    assert_num_eq(2.25, ema.get_value(2).unwrap());
+    assert_num_eq(4.0625, ema.get_value(4).unwrap());
 }
 
 /// cargo test test_leave_last_bar_uncached_double -- --nocapture --test-threads=1
@@ -323,27 +488,138 @@ where
     let trade_volume = factory.clone().num_of_i64(10);
     let trade_price = factory.clone().num_of_i64(5);
 
-    let mut series = MockBarSeriesBuilder::<T>::default()
-        .with_num_factory(factory)
-        .with_default_data()
-        .build();
+    let series = Arc::new(RefCell::new(
+        MockBarSeriesBuilder::<T>::default()
+            .with_num_factory(factory)
+            .with_default_data()
+            .build(),
+    ));
+    let end_index = series.borrow().get_end_index().unwrap();
 
-    series.add_trade_to_last_bar(trade_volume, trade_price); // rust版本语言禁止后面修改
+    let close_price = Arc::new(ClosePriceIndicator::from_mut(series.clone()));
+    let sma = SmaIndicator::new(close_price, 5);
 
-    let close_price = ClosePriceIndicator::new(&series);
-    let sma = SmaIndicator::new(&close_price, 5);
+    assert_num_eq(4998.0, sma.get_value(end_index).unwrap());
 
-    // assert_num_eq(
-    //     4998.0,
-    //     sma.get_value(series.get_end_index().unwrap()).unwrap(),
-    // );
-    // series.add_trade_to_last_bar(trade_volume,trade_price); // java版本的是这里设置的
+    series
+        .borrow_mut()
+        .add_trade_to_last_bar(trade_volume, trade_price);
 
     // (4996 + 4997 + 4998 + 4999 + 5) / 5
-    assert_num_eq(
-        3999.0,
-        sma.get_value(series.get_end_index().unwrap()).unwrap(),
+    assert_num_eq(3999.0, sma.get_value(end_index).unwrap());
+}
+
+/// cargo test test_recursive_cached_indicator_leaves_last_bar_uncached_double -- --nocapture --test-threads=1
+#[test]
+fn test_recursive_cached_indicator_leaves_last_bar_uncached_double() {
+    let factory = Arc::new(DoubleNumFactory);
+    test_recursive_cached_indicator_leaves_last_bar_uncached::<DoubleNum>(factory);
+}
+/// cargo test test_recursive_cached_indicator_leaves_last_bar_uncached_decimal -- --nocapture --test-threads=1
+#[test]
+fn test_recursive_cached_indicator_leaves_last_bar_uncached_decimal() {
+    let factory = Arc::new(DecimalNumFactory::default());
+    test_recursive_cached_indicator_leaves_last_bar_uncached::<DecimalNum>(factory);
+}
+fn test_recursive_cached_indicator_leaves_last_bar_uncached<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = Arc::new(RefCell::new(
+        MockBarSeriesBuilder::<T>::default()
+            .with_num_factory(factory.clone())
+            .with_data(vec![1., 2., 3., 4., 5.])
+            .build(),
+    ));
+    let close_price = Arc::new(ClosePriceIndicator::from_mut(series.clone()));
+    let ema = EmaIndicator::new(close_price, 3);
+
+    // EMA(3)：1, 1.5, 2.25, 3.125, 4.0625
+    assert_num_eq(4.0625, ema.get_value(4).unwrap());
+
+    // 最新 bar 的收盘价变化后重新计算，之前的 bar 仍取缓存
+    series
+        .borrow_mut()
+        .add_trade_to_last_bar(factory.num_of_i64(10), factory.num_of_i64(7));
+    assert_num_eq(5.0625, ema.get_value(4).unwrap());
+    assert_num_eq(3.125, ema.get_value(3).unwrap());
+}
+
+/// 累计收盘价之和与 bar 数的递归计算器，输出为元组
+#[derive(Clone)]
+struct RunningSumCalculator {
+    calls: Arc<AtomicUsize>,
+}
+
+impl<T> RecursiveIndicatorCalculator<T, BaseBarSeries<T>> for RunningSumCalculator
+where
+    T: TrNum + 'static,
+{
+    type Output = (T, usize);
+
+    fn calculate(
+        &self,
+        base: &BaseIndicator<T, BaseBarSeries<T>>,
+        index: usize,
+        previous: Option<&(T, usize)>,
+    ) -> Result<(T, usize), IndicatorError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let close = base.get_value(index)?;
+        Ok(match previous {
+            Some((sum, count)) => (sum.clone() + close, count + 1),
+            None => (close, 1),
+        })
+    }
+}
+
+/// cargo test test_recursive_cached_indicator_with_struct_output_double -- --nocapture --test-threads=1
+#[test]
+fn test_recursive_cached_indicator_with_struct_output_double() {
+    let factory = Arc::new(DoubleNumFactory);
+    test_recursive_cached_indicator_with_struct_output::<DoubleNum>(factory);
+}
+/// cargo test test_recursive_cached_indicator_with_struct_output_decimal -- --nocapture --test-threads=1
+#[test]
+fn test_recursive_cached_indicator_with_struct_output_decimal() {
+    let factory = Arc::new(DecimalNumFactory::default());
+    test_recursive_cached_indicator_with_struct_output::<DecimalNum>(factory);
+}
+fn test_recursive_cached_indicator_with_struct_output<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let mut bar_series = MockBarSeriesBuilder::<T>::default()
+        .with_num_factory(factory)
+        .with_data((1..=10).map(f64::from).collect())
+        .build();
+    bar_series
+        .set_maximum_bar_count(6)
+        .expect("set_maximum_bar_count error");
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let running = RecursiveCachedIndicator::from_mut(
+        Arc::new(RefCell::new(bar_series)),
+        RunningSumCalculator {
+            calls: calls.clone(),
+        },
     );
+
+    // 剩余 bar 为 5..=10，从第一根剩余 bar 开始累计
+    let (sum, count) = running.get_value(9).unwrap();
+    assert_num_eq(45.0, sum);
+    assert_eq!(6, count);
+    assert_eq!(6, calls.load(Ordering::SeqCst));
+
+    // 已移除的索引按第一根剩余 bar 取值
+    let (sum, count) = running.get_value(0).unwrap();
+    assert_num_eq(5.0, sum);
+    assert_eq!(1, count);
+
+    // 最新 bar 之前的结果命中缓存，最新 bar 每次重新计算
+    for index in 4..=9 {
+        running.get_value(index).unwrap();
+    }
+    assert_eq!(7, calls.load(Ordering::SeqCst));
 }
 
 // fn simulate_online_data_flow<T>(mut series: SeriesType<T>, factory: Arc<T::Factory>)
@@ -356,8 +632,8 @@ where
 //         series.add_bar(new_bar);
 //
 //         // 构造指标（或复用，保证借用规则）
-//         let close_price = ClosePriceIndicator::new(&series);
-//         let sma = SmaIndicator::new(&close_price, 5);
+//         let close_price = Arc::new(ClosePriceIndicator::from_mut(Arc::new(RefCell::new(series))));
+//         let sma = SmaIndicator::new(close_price, 5);
 //
 //         // 调用指标计算最新值，验证缓存增量计算
 //         let idx = series.get_end_index().unwrap();
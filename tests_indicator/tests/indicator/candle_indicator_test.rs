@@ -0,0 +1,225 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::bar::builder::types::BarSeriesRef;
+use ta4r::bar::types::BarSeries;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::candles::bearish_engulfing_indicator::BearishEngulfingIndicator;
+use ta4r::indicators::candles::bearish_harami_indicator::BearishHaramiIndicator;
+use ta4r::indicators::candles::bullish_engulfing_indicator::BullishEngulfingIndicator;
+use ta4r::indicators::candles::bullish_harami_indicator::BullishHaramiIndicator;
+use ta4r::indicators::candles::doji_indicator::DojiIndicator;
+use ta4r::indicators::candles::evening_star_indicator::EveningStarIndicator;
+use ta4r::indicators::candles::hammer_indicator::HammerIndicator;
+use ta4r::indicators::candles::hanging_man_indicator::HangingManIndicator;
+use ta4r::indicators::candles::inside_bar_indicator::InsideBarIndicator;
+use ta4r::indicators::candles::morning_star_indicator::MorningStarIndicator;
+use ta4r::indicators::candles::outside_bar_indicator::OutsideBarIndicator;
+use ta4r::indicators::candles::three_black_crows_indicator::ThreeBlackCrowsIndicator;
+use ta4r::indicators::candles::three_white_soldiers_indicator::ThreeWhiteSoldiersIndicator;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::num::{NumFactory, TrNum};
+use ta4r::rule::Rule;
+use ta4r::rule::boolean_indicator_rule::BooleanIndicatorRule;
+use tests_indicator::types::{Ohlcv, ohlcv_series_mut};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+/// (open, high, low, close, volume)：十字星、锤子线与上吊线
+const SINGLE_BARS: [Ohlcv; 7] = [
+    (10.0, 10.2, 9.8, 10.0, 0.0),
+    (10.0, 10.1, 9.5, 9.6, 0.0),
+    (9.6, 9.7, 9.1, 9.2, 0.0),
+    (9.2, 9.22, 8.5, 9.15, 0.0),
+    (9.2, 9.8, 9.2, 9.7, 0.0),
+    (9.7, 10.3, 9.6, 10.2, 0.0),
+    (10.2, 10.25, 9.6, 10.1, 0.0),
+];
+
+/// 吞没、孕线与内包/外包线
+const TWO_BAR_PATTERNS: [Ohlcv; 7] = [
+    (10.0, 10.5, 9.5, 10.2, 0.0),
+    (10.3, 10.4, 9.8, 9.9, 0.0),
+    (9.8, 10.6, 9.7, 10.5, 0.0),
+    (10.4, 10.5, 10.0, 10.1, 0.0),
+    (10.2, 10.3, 9.5, 9.6, 0.0),
+    (9.7, 10.0, 9.6, 9.9, 0.0),
+    (10.0, 10.1, 9.0, 9.1, 0.0),
+];
+
+/// 启明星、红三兵、黄昏星与三只乌鸦
+const THREE_BAR_PATTERNS: [Ohlcv; 9] = [
+    (10.0, 10.1, 8.9, 9.0, 0.0),
+    (8.8, 8.9, 8.6, 8.75, 0.0),
+    (8.9, 9.8, 8.85, 9.7, 0.0),
+    (9.7, 10.3, 9.65, 10.2, 0.0),
+    (10.1, 10.75, 10.05, 10.7, 0.0),
+    (10.8, 10.95, 10.75, 10.85, 0.0),
+    (10.6, 10.65, 10.0, 10.05, 0.0),
+    (10.1, 10.15, 9.5, 9.55, 0.0),
+    (9.6, 9.62, 9.0, 9.05, 0.0),
+];
+
+/// 形态成立的 bar 索引
+fn matches<I, T>(indicator: &I) -> Vec<usize>
+where
+    T: TrNum + 'static,
+    I: Indicator<Num = T, Output = bool>,
+{
+    let count = indicator.bar_series().with_ref_or(0, |s| s.get_bar_count());
+    (0..count)
+        .filter(|i| indicator.get_value(*i).unwrap())
+        .collect()
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_single_bar_patterns_double(#[case] factory: DoubleNumFactory) {
+    test_single_bar_patterns::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_single_bar_patterns_decimal(#[case] factory: DecimalNumFactory) {
+    test_single_bar_patterns::<DecimalNum>(Arc::new(factory));
+}
+fn test_single_bar_patterns<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let two = factory.num_of_f64(2.0);
+    let series = ohlcv_series_mut::<T>(factory, &SINGLE_BARS);
+
+    let doji = Arc::new(DojiIndicator::from_mut(series.clone()).unwrap());
+    assert_eq!(vec![0, 3], matches(doji.as_ref()));
+    // 实体阈值为 0 时只有一字线
+    let strict_doji =
+        DojiIndicator::with_thresholds(BarSeriesRef::Mut(series.clone()), T::zero()).unwrap();
+    assert_eq!(vec![0], matches(&strict_doji));
+    assert!(
+        DojiIndicator::with_thresholds(BarSeriesRef::Mut(series.clone()), T::zero().minus(&two))
+            .is_err()
+    );
+
+    let hammer = HammerIndicator::from_mut(series.clone()).unwrap();
+    assert_eq!(vec![3], matches(&hammer));
+    let hanging_man = HangingManIndicator::from_mut(series.clone()).unwrap();
+    assert_eq!(vec![6], matches(&hanging_man));
+    assert_eq!(&two, hanging_man.shadow_factor());
+    // 要求下影线至少为实体的 20 倍时，上吊线不再成立
+    let strict_hanging_man = HangingManIndicator::with_thresholds(
+        BarSeriesRef::Mut(series),
+        hanging_man.body_factor().clone(),
+        T::from_usize(20).unwrap(),
+    )
+    .unwrap();
+    assert!(matches(&strict_hanging_man).is_empty());
+
+    let rule: BooleanIndicatorRule<_, _, _, _, _, Record<T>> = BooleanIndicatorRule::new(doji);
+    let signals: Vec<usize> = (0..7).filter(|i| rule.is_satisfied(*i)).collect();
+    assert_eq!(vec![0, 3], signals);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_two_bar_patterns_double(#[case] factory: DoubleNumFactory) {
+    test_two_bar_patterns::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_two_bar_patterns_decimal(#[case] factory: DecimalNumFactory) {
+    test_two_bar_patterns::<DecimalNum>(Arc::new(factory));
+}
+fn test_two_bar_patterns<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ohlcv_series_mut::<T>(factory, &TWO_BAR_PATTERNS);
+
+    assert_eq!(
+        vec![2],
+        matches(&BullishEngulfingIndicator::from_mut(series.clone()))
+    );
+    assert_eq!(
+        vec![1, 6],
+        matches(&BearishEngulfingIndicator::from_mut(series.clone()))
+    );
+    assert_eq!(
+        vec![5],
+        matches(&BullishHaramiIndicator::from_mut(series.clone()))
+    );
+    assert_eq!(
+        vec![3],
+        matches(&BearishHaramiIndicator::from_mut(series.clone()))
+    );
+    assert_eq!(
+        vec![1, 3, 5],
+        matches(&InsideBarIndicator::from_mut(series.clone()))
+    );
+    assert_eq!(vec![2, 6], matches(&OutsideBarIndicator::from_mut(series)));
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_three_bar_patterns_double(#[case] factory: DoubleNumFactory) {
+    test_three_bar_patterns::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_three_bar_patterns_decimal(#[case] factory: DecimalNumFactory) {
+    test_three_bar_patterns::<DecimalNum>(Arc::new(factory));
+}
+fn test_three_bar_patterns<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ohlcv_series_mut::<T>(factory, &THREE_BAR_PATTERNS);
+
+    let morning_star = MorningStarIndicator::from_mut(series.clone()).unwrap();
+    assert_eq!(vec![2], matches(&morning_star));
+    let evening_star = EveningStarIndicator::from_mut(series.clone()).unwrap();
+    assert_eq!(vec![6], matches(&evening_star));
+
+    let soldiers = ThreeWhiteSoldiersIndicator::from_mut(series.clone()).unwrap();
+    assert_eq!(vec![4], matches(&soldiers));
+    let crows = ThreeBlackCrowsIndicator::from_mut(series.clone()).unwrap();
+    assert_eq!(vec![8], matches(&crows));
+
+    // 要求实体至少占振幅的 90% 时，红三兵不再成立
+    let strict_soldiers = ThreeWhiteSoldiersIndicator::with_thresholds(
+        BarSeriesRef::Mut(series),
+        T::from_f64(0.9).unwrap(),
+        soldiers.shadow_factor().clone(),
+    )
+    .unwrap();
+    assert!(matches(&strict_soldiers).is_empty());
+}
@@ -0,0 +1,192 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::donchian::donchian_channel_lower_indicator::DonchianChannelLowerIndicator;
+use ta4r::indicators::donchian::donchian_channel_middle_indicator::DonchianChannelMiddleIndicator;
+use ta4r::indicators::donchian::donchian_channel_upper_indicator::DonchianChannelUpperIndicator;
+use ta4r::indicators::helpers::close_price_indicator::ClosePriceIndicator;
+use ta4r::indicators::keltner::keltner_channel_lower_indicator::KeltnerChannelLowerIndicator;
+use ta4r::indicators::keltner::keltner_channel_middle_indicator::KeltnerChannelMiddleIndicator;
+use ta4r::indicators::keltner::keltner_channel_upper_indicator::KeltnerChannelUpperIndicator;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::num::{NumFactory, TrNum};
+use ta4r::rule::Rule;
+use ta4r::rule::crossed_up_indicator_rule::CrossedUpIndicatorRule;
+use ta4r::rule::in_pipe_rule::InPipeRule;
+use ta4r::rule::over_indicator_rule::OverIndicatorRule;
+use tests_indicator::types::{assert_values, ta4j_series};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_keltner_channel_double(#[case] factory: DoubleNumFactory) {
+    test_keltner_channel::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_keltner_channel_decimal(#[case] factory: DecimalNumFactory) {
+    test_keltner_channel::<DecimalNum>(Arc::new(factory));
+}
+fn test_keltner_channel<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series::<T>(factory.clone());
+    assert!(KeltnerChannelMiddleIndicator::from_mut(series.clone(), 0).is_err());
+
+    let middle = Arc::new(KeltnerChannelMiddleIndicator::from_mut(series.clone(), 3).unwrap());
+    assert_values(
+        &[
+            118.21, 117.8867, 116.205, 114.0708, 112.8171, 111.8369, 112.5334, 114.4234, 115.4834,
+            117.4833, 119.18, 119.7267, 119.8317, 120.0358, 119.4879,
+        ],
+        middle.as_ref(),
+    );
+
+    let upper = Arc::new(
+        KeltnerChannelUpperIndicator::new(middle.clone(), factory.num_of_f64(2.0), 3).unwrap(),
+    );
+    assert_eq!(3, upper.count_of_unstable_bars());
+    assert_values(
+        &[
+            125.21, 125.18, 125.6072, 123.0923, 120.9514, 120.1331, 121.9376, 123.3595, 123.8608,
+            126.0683, 126.6566, 126.3378, 126.8191, 126.4674, 126.4823,
+        ],
+        upper.as_ref(),
+    );
+    // 下轨共享上轨的 ATR
+    let lower = KeltnerChannelLowerIndicator::from_atr(
+        middle.clone(),
+        upper.atr(),
+        factory.num_of_f64(2.0),
+    );
+    assert_values(
+        &[
+            111.21, 110.5933, 106.8028, 105.0494, 104.6828, 103.5407, 103.1293, 105.4873, 107.106,
+            108.8984, 111.7034, 113.1156, 112.8443, 113.6042, 112.4935,
+        ],
+        &lower,
+    );
+
+    // 0.5 倍 ATR 的窄通道
+    let upper = Arc::new(
+        KeltnerChannelUpperIndicator::new(middle.clone(), factory.num_of_f64(0.5), 3).unwrap(),
+    );
+    let lower = Arc::new(KeltnerChannelLowerIndicator::from_atr(
+        middle,
+        upper.atr(),
+        factory.num_of_f64(0.5),
+    ));
+    let close = Arc::new(ClosePriceIndicator::from_mut(series));
+    let in_pipe: InPipeRule<_, _, _, _, _, _, _, Record<T>> =
+        InPipeRule::new(close.clone(), upper.clone(), lower);
+    let inside: Vec<usize> = (0..15).filter(|i| in_pipe.is_satisfied(*i)).collect();
+    assert_eq!(vec![0, 1, 4, 5, 6, 8, 11, 12, 13, 14], inside);
+
+    let over: OverIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        OverIndicatorRule::new(close.clone(), upper.clone());
+    let above: Vec<usize> = (0..15).filter(|i| over.is_satisfied(*i)).collect();
+    assert_eq!(vec![7, 9, 10], above);
+
+    let breakout: CrossedUpIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        CrossedUpIndicatorRule::new(close, upper);
+    let signals: Vec<usize> = (0..15).filter(|i| breakout.is_satisfied(*i)).collect();
+    assert_eq!(vec![7, 9], signals);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_donchian_channel_double(#[case] factory: DoubleNumFactory) {
+    test_donchian_channel::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_donchian_channel_decimal(#[case] factory: DecimalNumFactory) {
+    test_donchian_channel::<DecimalNum>(Arc::new(factory));
+}
+fn test_donchian_channel<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series::<T>(factory);
+    assert!(DonchianChannelUpperIndicator::from_mut(series.clone(), 0).is_err());
+    assert!(DonchianChannelLowerIndicator::from_mut(series.clone(), 0).is_err());
+
+    let upper = Arc::new(DonchianChannelUpperIndicator::from_mut(series.clone(), 3).unwrap());
+    let lower = Arc::new(DonchianChannelLowerIndicator::from_mut(series.clone(), 3).unwrap());
+    assert_eq!(2, upper.count_of_unstable_bars());
+    assert_values(
+        &[
+            119.5, 119.94, 119.94, 119.94, 118.44, 114.19, 115.81, 117.5, 118.44, 120.88, 121.94,
+            121.94, 121.94, 121.69, 121.69,
+        ],
+        upper.as_ref(),
+    );
+    assert_values(
+        &[
+            116.0, 116.0, 111.63, 110.06, 109.63, 109.13, 109.13, 109.13, 110.38, 114.06, 114.81,
+            116.69, 117.44, 117.44, 117.19,
+        ],
+        lower.as_ref(),
+    );
+
+    let middle = Arc::new(DonchianChannelMiddleIndicator::from_channels(
+        upper.clone(),
+        lower.clone(),
+    ));
+    assert_values(
+        &[
+            117.75, 117.97, 115.785, 115.0, 114.035, 111.66, 112.47, 113.315, 114.41, 117.47,
+            118.375, 119.315, 119.69, 119.565, 119.44,
+        ],
+        middle.as_ref(),
+    );
+
+    // 通道包含当前 bar，收盘价始终位于上下轨之间
+    let close = Arc::new(ClosePriceIndicator::from_mut(series));
+    let in_pipe: InPipeRule<_, _, _, _, _, _, _, Record<T>> =
+        InPipeRule::new(close.clone(), upper, lower);
+    assert!((0..15).all(|i| in_pipe.is_satisfied(i)));
+
+    let over: OverIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        OverIndicatorRule::new(close.clone(), middle.clone());
+    let above: Vec<usize> = (0..15).filter(|i| over.is_satisfied(*i)).collect();
+    assert_eq!(vec![0, 6, 7, 8, 9, 10, 11, 12, 13], above);
+
+    let cross: CrossedUpIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        CrossedUpIndicatorRule::new(close, middle);
+    let signals: Vec<usize> = (0..15).filter(|i| cross.is_satisfied(*i)).collect();
+    assert_eq!(vec![6], signals);
+}
@@ -0,0 +1,192 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::bar::builder::types::BarSeriesRef;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::helpers::close_price_indicator::ClosePriceIndicator;
+use ta4r::indicators::ichimoku::ichimoku_chikou_span_indicator::IchimokuChikouSpanIndicator;
+use ta4r::indicators::ichimoku::ichimoku_line_indicator::IchimokuLineIndicator;
+use ta4r::indicators::ichimoku::ichimoku_senkou_span_a_indicator::IchimokuSenkouSpanAIndicator;
+use ta4r::indicators::ichimoku::ichimoku_senkou_span_b_indicator::IchimokuSenkouSpanBIndicator;
+use ta4r::indicators::types::IndicatorError;
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::rule::Rule;
+use ta4r::rule::over_indicator_rule::OverIndicatorRule;
+use tests_indicator::types::{
+    GENERAL_OFFSET, assert_num_eq_with_offset, assert_values_from, ta4j_series,
+};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+/// 平移不足的 index 没有可用的值
+fn assert_not_available<I, T>(displacement: usize, indicator: &I)
+where
+    T: TrNum + 'static,
+    I: Indicator<Num = T, Output = T>,
+{
+    for index in 0..displacement {
+        assert!(matches!(
+            indicator.get_value(index),
+            Err(IndicatorError::NotAvailable { available_from, .. }) if available_from == displacement
+        ));
+    }
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_ichimoku_double(#[case] factory: DoubleNumFactory) {
+    test_ichimoku::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_ichimoku_decimal(#[case] factory: DecimalNumFactory) {
+    test_ichimoku::<DecimalNum>(Arc::new(factory));
+}
+fn test_ichimoku<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series::<T>(factory);
+    let series_ref = BarSeriesRef::Mut(series.clone());
+    assert!(IchimokuLineIndicator::new(series_ref.clone(), 0).is_err());
+
+    let tenkan_sen = Arc::new(IchimokuLineIndicator::new(series_ref.clone(), 3).unwrap());
+    let kijun_sen = Arc::new(IchimokuLineIndicator::new(series_ref.clone(), 5).unwrap());
+    assert_values_from(
+        0,
+        &[
+            117.75, 117.97, 115.785, 115.0, 114.035, 111.66, 112.47, 113.315, 114.41, 117.47,
+            118.375, 119.315, 119.69, 119.565, 119.44,
+        ],
+        tenkan_sen.as_ref(),
+    );
+    assert_values_from(
+        0,
+        &[
+            117.75, 117.97, 115.785, 115.0, 114.785, 114.535, 113.785, 113.315, 113.785, 115.005,
+            116.16, 118.0, 118.375, 119.315, 119.565,
+        ],
+        kijun_sen.as_ref(),
+    );
+
+    let span_a = Arc::new(IchimokuSenkouSpanAIndicator::from_lines(
+        tenkan_sen, kijun_sen, 4,
+    ));
+    assert_eq!(8, span_a.count_of_unstable_bars());
+    assert_not_available(4, span_a.as_ref());
+    assert_values_from(
+        4,
+        &[
+            117.75, 117.97, 115.785, 115.0, 114.41, 113.0975, 113.1275, 113.315, 114.0975,
+            116.2375, 117.2675,
+        ],
+        span_a.as_ref(),
+    );
+    // 最新 bar 上计算出的云层值只能通过 leading_value 读取
+    assert_num_eq_with_offset(119.5025, span_a.leading_value(14).unwrap(), GENERAL_OFFSET);
+
+    let span_b =
+        Arc::new(IchimokuSenkouSpanBIndicator::with_periods(series_ref.clone(), 7, 4).unwrap());
+    assert_eq!(10, span_b.count_of_unstable_bars());
+    assert_not_available(4, span_b.as_ref());
+    assert_values_from(
+        4,
+        &[
+            117.75, 117.97, 115.785, 115.0, 114.785, 114.535, 114.535, 114.535, 113.785, 115.005,
+            115.535,
+        ],
+        span_b.as_ref(),
+    );
+    assert_num_eq_with_offset(118.375, span_b.leading_value(14).unwrap(), GENERAL_OFFSET);
+
+    let chikou_span = IchimokuChikouSpanIndicator::with_displacement(series_ref, 4);
+    assert_eq!(4, chikou_span.count_of_unstable_bars());
+    assert_not_available(4, &chikou_span);
+    assert_values_from(4, &[112.25, 110.00, 113.50], &chikou_span);
+
+    // 收盘价位于云层之上；平移不足的 bar 不满足条件
+    let close = Arc::new(ClosePriceIndicator::from_mut(series));
+    let over_a: OverIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        OverIndicatorRule::new(close.clone(), span_a);
+    let over_b: OverIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        OverIndicatorRule::new(close, span_b);
+    let above_cloud: Vec<usize> = (0..15)
+        .filter(|i| over_a.is_satisfied(*i) && over_b.is_satisfied(*i))
+        .collect();
+    assert_eq!(vec![7, 8, 9, 10, 11, 12, 13, 14], above_cloud);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_ichimoku_defaults_double(#[case] factory: DoubleNumFactory) {
+    test_ichimoku_defaults::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_ichimoku_defaults_decimal(#[case] factory: DecimalNumFactory) {
+    test_ichimoku_defaults::<DecimalNum>(Arc::new(factory));
+}
+fn test_ichimoku_defaults<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series::<T>(factory);
+    let series_ref = BarSeriesRef::Mut(series.clone());
+
+    assert_eq!(
+        9,
+        IchimokuLineIndicator::tenkan_sen(series_ref.clone()).bar_count()
+    );
+    assert_eq!(26, IchimokuLineIndicator::kijun_sen(series_ref).bar_count());
+
+    let span_a = IchimokuSenkouSpanAIndicator::from_mut(series.clone()).unwrap();
+    assert_eq!(26, span_a.displacement());
+    assert_eq!(51, span_a.count_of_unstable_bars());
+    // 序列只有 15 根 bar，不足 26 根时云层不可用
+    assert!(matches!(
+        span_a.get_value(14),
+        Err(IndicatorError::NotAvailable {
+            index: 14,
+            available_from: 26
+        })
+    ));
+
+    let span_b = IchimokuSenkouSpanBIndicator::from_mut(series.clone()).unwrap();
+    assert_eq!(77, span_b.count_of_unstable_bars());
+    assert!(span_b.get_value(14).is_err());
+
+    let chikou_span = IchimokuChikouSpanIndicator::from_mut(series);
+    assert_eq!(26, chikou_span.displacement());
+    assert!(chikou_span.get_value(14).is_err());
+}
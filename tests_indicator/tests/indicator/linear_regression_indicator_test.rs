@@ -0,0 +1,214 @@
+use rstest::rstest;
+use std::cell::RefCell;
+use std::sync::Arc;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::bar::builder::mocks::mock_bar_series_builder::MockBarSeriesBuilder;
+use ta4r::bar::types::{BarBuilder, BarSeries};
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::helpers::close_price_indicator::ClosePriceIndicator;
+use ta4r::indicators::regression::linear_regression_channel_lower_indicator::LinearRegressionChannelLowerIndicator;
+use ta4r::indicators::regression::linear_regression_channel_upper_indicator::LinearRegressionChannelUpperIndicator;
+use ta4r::indicators::regression::linear_regression_forecast_indicator::LinearRegressionForecastIndicator;
+use ta4r::indicators::regression::linear_regression_indicator::LinearRegressionIndicator;
+use ta4r::indicators::regression::linear_regression_intercept_indicator::LinearRegressionInterceptIndicator;
+use ta4r::indicators::regression::linear_regression_slope_indicator::LinearRegressionSlopeIndicator;
+use ta4r::indicators::regression::r_squared_indicator::RSquaredIndicator;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::num::{NumFactory, TrNum};
+use ta4r::rule::Rule;
+use ta4r::rule::in_slope_rule::InSlopeRule;
+use tests_indicator::types::{
+    GENERAL_OFFSET, assert_num_eq_with_offset, assert_values, close_price,
+};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+const CLOSES: [f64; 12] = [1., 2., 4., 3., 5., 7., 6., 8., 9., 12., 10., 9.];
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_slope_intercept_and_r_squared_double(#[case] factory: DoubleNumFactory) {
+    test_slope_intercept_and_r_squared::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_slope_intercept_and_r_squared_decimal(#[case] factory: DecimalNumFactory) {
+    test_slope_intercept_and_r_squared::<DecimalNum>(Arc::new(factory));
+}
+fn test_slope_intercept_and_r_squared<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &CLOSES);
+    assert!(LinearRegressionIndicator::new(close.clone(), 1).is_err());
+
+    let regression = Arc::new(LinearRegressionIndicator::new(close, 4).unwrap());
+    assert_eq!(3, regression.count_of_unstable_bars());
+    // 先访问末尾（slow path），再顺序访问（fast path）
+    assert_num_eq_with_offset(
+        -0.2,
+        regression.get_value(11).unwrap().slope,
+        GENERAL_OFFSET,
+    );
+
+    let slope = LinearRegressionSlopeIndicator::from_regression(regression.clone());
+    assert_eq!(3, slope.count_of_unstable_bars());
+    assert_values(
+        &[0.0, 1.0, 1.5, 0.8, 0.8, 1.1, 1.1, 0.8, 0.8, 1.9, 0.9, -0.2],
+        &slope,
+    );
+
+    let intercept = LinearRegressionInterceptIndicator::from_regression(regression.clone());
+    assert_values(
+        &[
+            1.0, 1.0, 0.8333, 1.3, 2.3, 3.1, 3.6, 5.3, 6.3, 5.9, 8.4, 10.3,
+        ],
+        &intercept,
+    );
+
+    let r_squared = RSquaredIndicator::from_regression(regression);
+    // index 0 只有一个观测，R² 无法定义
+    assert_values(
+        &[
+            0.0, 1.0, 0.9643, 0.64, 0.64, 0.6914, 0.6914, 0.64, 0.64, 0.9627, 0.4629, 0.0333,
+        ],
+        &r_squared,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_forecast_and_channel_double(#[case] factory: DoubleNumFactory) {
+    test_forecast_and_channel::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_forecast_and_channel_decimal(#[case] factory: DecimalNumFactory) {
+    test_forecast_and_channel::<DecimalNum>(Arc::new(factory));
+}
+fn test_forecast_and_channel<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory.clone(), &CLOSES);
+
+    let lsma = LinearRegressionForecastIndicator::new(close.clone(), 4).unwrap();
+    assert_eq!(0, lsma.offset());
+    assert_values(
+        &[
+            1.0, 2.0, 3.8333, 3.7, 4.7, 6.4, 6.9, 7.7, 8.7, 11.6, 11.1, 9.7,
+        ],
+        &lsma,
+    );
+
+    let forecast = LinearRegressionForecastIndicator::with_offset(close.clone(), 4, 2).unwrap();
+    assert_values(
+        &[
+            1.0, 4.0, 6.8333, 5.3, 6.3, 8.6, 9.1, 9.3, 10.3, 15.4, 12.9, 9.3,
+        ],
+        &forecast,
+    );
+
+    let two = factory.two().as_ref().clone();
+    let upper = LinearRegressionChannelUpperIndicator::new(close.clone(), 4, two.clone()).unwrap();
+    assert_values(
+        &[
+            1.0, 2.0, 4.3047, 5.0416, 6.0416, 8.0432, 8.5432, 9.0416, 10.0416, 12.4367, 13.2679,
+            12.1083,
+        ],
+        &upper,
+    );
+    let lower = LinearRegressionChannelLowerIndicator::new(close, 4, two).unwrap();
+    assert_values(
+        &[
+            1.0, 2.0, 3.3619, 2.3584, 3.3584, 4.7568, 5.2568, 6.3584, 7.3584, 10.7633, 8.9321,
+            7.2917,
+        ],
+        &lower,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_in_slope_rule_with_regression_double(#[case] factory: DoubleNumFactory) {
+    test_in_slope_rule_with_regression::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_in_slope_rule_with_regression_decimal(#[case] factory: DecimalNumFactory) {
+    test_in_slope_rule_with_regression::<DecimalNum>(Arc::new(factory));
+}
+fn test_in_slope_rule_with_regression<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory.clone(), &CLOSES);
+    let rule: InSlopeRule<_, _, _, _, _, Record<T>> =
+        InSlopeRule::with_regression(close, 4, factory.num_of_f64(0.5), factory.num_of_f64(1.5))
+            .unwrap();
+    let satisfied: Vec<usize> = (3..12).filter(|i| rule.is_satisfied(*i)).collect();
+    assert_eq!(vec![3, 4, 5, 6, 7, 8, 10], satisfied);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_live_last_bar_double(#[case] factory: DoubleNumFactory) {
+    test_live_last_bar::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_live_last_bar_decimal(#[case] factory: DecimalNumFactory) {
+    test_live_last_bar::<DecimalNum>(Arc::new(factory));
+}
+fn test_live_last_bar<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = Arc::new(RefCell::new(
+        MockBarSeriesBuilder::<T>::default()
+            .with_num_factory(factory)
+            .with_data(vec![1., 2., 4., 3., 5., 7., 6., 8., 9., 12., 10., 9.])
+            .build(),
+    ));
+    let close = Arc::new(ClosePriceIndicator::from_mut(series.clone()));
+    let regression = LinearRegressionIndicator::new(close.clone(), 4).unwrap();
+
+    // 读取最新 bar，之后它继续成交，再追加新 bar
+    for index in 0..12 {
+        regression.get_value(index).unwrap();
+    }
+    series.borrow_mut().add_price(T::from_f64(14.0).unwrap());
+    series
+        .borrow_mut()
+        .bar_builder()
+        .close_price(T::from_f64(11.0).unwrap())
+        .add()
+        .expect("Failed to add bar");
+
+    // 先读新 bar：不能沿用被修改前的最新 bar 滑动窗口和
+    let fresh = LinearRegressionIndicator::new(close, 4).unwrap();
+    for index in [12, 11, 10] {
+        let expected = fresh.get_value(index).unwrap();
+        let actual = regression.get_value(index).unwrap();
+        assert_num_eq_with_offset(
+            expected.slope.to_f64().unwrap(),
+            actual.slope,
+            GENERAL_OFFSET,
+        );
+        assert_num_eq_with_offset(
+            expected.intercept.to_f64().unwrap(),
+            actual.intercept,
+            GENERAL_OFFSET,
+        );
+        assert_num_eq_with_offset(
+            expected.r_squared.to_f64().unwrap(),
+            actual.r_squared,
+            GENERAL_OFFSET,
+        );
+    }
+}
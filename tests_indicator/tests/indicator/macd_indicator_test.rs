@@ -0,0 +1,125 @@
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::macd_histogram_indicator::MacdHistogramIndicator;
+use ta4r::indicators::macd_indicator::MacdIndicator;
+use ta4r::indicators::macd_signal_indicator::MacdSignalIndicator;
+use ta4r::indicators::ppo_indicator::PpoIndicator;
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::rule::Rule;
+use ta4r::rule::crossed_up_indicator_rule::CrossedUpIndicatorRule;
+use tests_indicator::types::{
+    GENERAL_OFFSET, TA4J_CLOSE_PRICES, assert_num_eq, assert_num_eq_with_offset, close_price,
+};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+/// ta4j MACDIndicatorTest 使用的数据
+const CLOSES: [f64; 11] = [
+    37.08, 36.7, 36.11, 35.85, 35.71, 36.04, 36.41, 37.67, 38.01, 37.79, 36.83,
+];
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_macd_double(#[case] factory: DoubleNumFactory) {
+    test_macd::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_macd_decimal(#[case] factory: DecimalNumFactory) {
+    test_macd::<DecimalNum>(Arc::new(factory));
+}
+fn test_macd<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &CLOSES);
+
+    assert!(MacdIndicator::new(close.clone(), 10, 5).is_err());
+
+    let macd = MacdIndicator::new(close, 5, 10).unwrap();
+    assert_eq!(10, macd.count_of_unstable_bars());
+
+    let expected = [
+        0.0, -0.05757, -0.17488, -0.26766, -0.32326, -0.28399, -0.18930, 0.06472, 0.25087, 0.30387,
+        0.16891,
+    ];
+    for (index, value) in expected.iter().enumerate() {
+        assert_num_eq_with_offset(*value, macd.get_value(index).unwrap(), GENERAL_OFFSET);
+    }
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_signal_and_histogram_double(#[case] factory: DoubleNumFactory) {
+    test_signal_and_histogram::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_signal_and_histogram_decimal(#[case] factory: DecimalNumFactory) {
+    test_signal_and_histogram::<DecimalNum>(Arc::new(factory));
+}
+fn test_signal_and_histogram<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &CLOSES);
+    let macd = Arc::new(MacdIndicator::new(close, 5, 10).unwrap());
+
+    let signal = Arc::new(MacdSignalIndicator::new(macd.clone(), 3));
+    assert_eq!(13, signal.count_of_unstable_bars());
+    assert_num_eq_with_offset(-0.10184, signal.get_value(2).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(-0.22916, signal.get_value(6).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(0.18151, signal.get_value(10).unwrap(), GENERAL_OFFSET);
+
+    let histogram = MacdHistogramIndicator::from_signal(macd.clone(), signal.clone());
+    assert_eq!(13, histogram.count_of_unstable_bars());
+    assert_num_eq_with_offset(-0.07305, histogram.get_value(2).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(0.03985, histogram.get_value(6).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(-0.01259, histogram.get_value(10).unwrap(), GENERAL_OFFSET);
+
+    // MACD 上穿信号线
+    let rule: CrossedUpIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        CrossedUpIndicatorRule::new(macd, signal);
+    let crossed: Vec<usize> = (0..11).filter(|i| rule.is_satisfied(*i)).collect();
+    assert_eq!(vec![6], crossed);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_ppo_double(#[case] factory: DoubleNumFactory) {
+    test_ppo::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_ppo_decimal(#[case] factory: DecimalNumFactory) {
+    test_ppo::<DecimalNum>(Arc::new(factory));
+}
+fn test_ppo<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &TA4J_CLOSE_PRICES);
+
+    let ppo = PpoIndicator::with_default_periods(close).unwrap();
+    assert_eq!(26, ppo.count_of_unstable_bars());
+    assert_num_eq(0.0, ppo.get_value(0).unwrap());
+
+    let expected = [
+        (21, 1.8904),
+        (22, 1.8284),
+        (23, 1.6020),
+        (28, 0.1195),
+        (29, -0.8639),
+    ];
+    for (index, value) in expected {
+        assert_num_eq_with_offset(value, ppo.get_value(index).unwrap(), GENERAL_OFFSET);
+    }
+}
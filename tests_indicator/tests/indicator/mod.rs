@@ -1,2 +1,22 @@
+mod adx_indicator_test;
+mod aroon_vortex_indicator_test;
+mod atr_stop_indicator_test;
 pub mod averages;
+mod bill_williams_indicator_test;
+mod bollinger_indicator_test;
 mod cached_indicator_test;
+mod candle_indicator_test;
+mod channel_indicator_test;
+mod ichimoku_indicator_test;
+mod linear_regression_indicator_test;
+mod macd_indicator_test;
+mod momentum_oscillator_indicator_test;
+mod parabolic_sar_indicator_test;
+mod pivot_point_indicator_test;
+mod rsi_indicator_test;
+mod statistics_indicator_test;
+mod stochastic_oscillator_indicator_test;
+mod swing_indicator_test;
+mod volatility_indicator_test;
+mod volume_indicator_test;
+mod vwap_indicator_test;
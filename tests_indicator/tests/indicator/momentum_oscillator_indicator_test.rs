@@ -0,0 +1,223 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::cci_indicator::CciIndicator;
+use ta4r::indicators::cmo_indicator::CmoIndicator;
+use ta4r::indicators::dpo_indicator::DpoIndicator;
+use ta4r::indicators::kst_indicator::KstIndicator;
+use ta4r::indicators::kst_signal_indicator::KstSignalIndicator;
+use ta4r::indicators::momentum_indicator::MomentumIndicator;
+use ta4r::indicators::roc_indicator::RocIndicator;
+use ta4r::indicators::trix_indicator::TrixIndicator;
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::rule::Rule;
+use ta4r::rule::crossed_up_indicator_rule::CrossedUpIndicatorRule;
+use tests_indicator::types::{
+    GENERAL_OFFSET, TA4J_CLOSE_PRICES, assert_num_eq_with_offset, assert_values_at, close_price,
+    ta4j_series,
+};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+/// 检查的索引，覆盖预热阶段与稳定阶段
+const INDEXES: [usize; 8] = [0, 1, 5, 10, 15, 20, 25, 29];
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_rate_of_change_double(#[case] factory: DoubleNumFactory) {
+    test_rate_of_change::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_rate_of_change_decimal(#[case] factory: DecimalNumFactory) {
+    test_rate_of_change::<DecimalNum>(Arc::new(factory));
+}
+fn test_rate_of_change<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &TA4J_CLOSE_PRICES);
+
+    assert!(RocIndicator::new(close.clone(), 0).is_err());
+    let roc = RocIndicator::new(close.clone(), 9).unwrap();
+    assert_eq!(9, roc.count_of_unstable_bars());
+    assert_values_at(
+        &INDEXES,
+        &[
+            0.0, -0.35923, -0.62865, -0.18026, 8.18713, 6.38678, -1.76842, -15.32326,
+        ],
+        &roc,
+    );
+
+    assert!(MomentumIndicator::new(close.clone(), 0).is_err());
+    let momentum = MomentumIndicator::new(close.clone(), 5).unwrap();
+    assert_eq!(5, momentum.count_of_unstable_bars());
+    assert_values_at(
+        &INDEXES,
+        &[0.0, -0.08, -0.14, 0.02, 1.9, -0.23, -0.49, -2.93],
+        &momentum,
+    );
+
+    assert!(TrixIndicator::new(close.clone(), 0).is_err());
+    let trix = TrixIndicator::new(close, 5).unwrap();
+    assert_eq!(16, trix.count_of_unstable_bars());
+    assert_values_at(
+        &INDEXES,
+        &[
+            0.0, -0.0133, -0.0588, 0.01842, 0.53681, 0.58691, -0.04577, -0.95649,
+        ],
+        &trix,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_cmo_and_dpo_double(#[case] factory: DoubleNumFactory) {
+    test_cmo_and_dpo::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_cmo_and_dpo_decimal(#[case] factory: DecimalNumFactory) {
+    test_cmo_and_dpo::<DecimalNum>(Arc::new(factory));
+}
+fn test_cmo_and_dpo<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &TA4J_CLOSE_PRICES);
+
+    assert!(CmoIndicator::new(close.clone(), 0).is_err());
+    let cmo = CmoIndicator::new(close.clone(), 9).unwrap();
+    assert_eq!(9, cmo.count_of_unstable_bars());
+    // index 0 没有涨跌，返回 0
+    assert_values_at(
+        &INDEXES,
+        &[
+            0.0, -100.0, -41.17647, -4.25532, 72.8, 53.15985, -23.86364, -72.27723,
+        ],
+        &cmo,
+    );
+
+    assert!(DpoIndicator::new(close.clone(), 0).is_err());
+    let dpo = DpoIndicator::new(close, 10).unwrap();
+    assert_eq!(6, dpo.time_shift());
+    assert_eq!(15, dpo.count_of_unstable_bars());
+    assert_values_at(
+        &INDEXES,
+        &[0.0, -0.08, -0.14, -0.028, 1.829, 1.399, 0.12, -3.54],
+        &dpo,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_kst_double(#[case] factory: DoubleNumFactory) {
+    test_kst::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_kst_decimal(#[case] factory: DecimalNumFactory) {
+    test_kst::<DecimalNum>(Arc::new(factory));
+}
+fn test_kst<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &TA4J_CLOSE_PRICES);
+
+    assert!(KstIndicator::new(close.clone(), [2, 3, 0, 5], [2, 2, 2, 3]).is_err());
+    assert_eq!(
+        44,
+        KstIndicator::with_defaults(close.clone())
+            .unwrap()
+            .count_of_unstable_bars()
+    );
+
+    let kst = Arc::new(KstIndicator::new(close, [2, 3, 4, 5], [2, 2, 2, 3]).unwrap());
+    assert_eq!(7, kst.count_of_unstable_bars());
+    assert_values_at(
+        &INDEXES,
+        &[
+            0.0, -1.79614, -2.66992, 0.25459, 56.53924, 4.75238, -23.33157, -90.16418,
+        ],
+        kst.as_ref(),
+    );
+
+    assert!(KstSignalIndicator::new(kst.clone(), 0).is_err());
+    let signal = Arc::new(KstSignalIndicator::new(kst.clone(), 3).unwrap());
+    assert_eq!(9, signal.count_of_unstable_bars());
+    assert_values_at(
+        &INDEXES,
+        &[
+            0.0, -0.89807, -4.15996, 4.22743, 31.2558, 20.38845, -20.30769, -52.07956,
+        ],
+        signal.as_ref(),
+    );
+
+    // KST 上穿信号线
+    let rule: CrossedUpIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        CrossedUpIndicatorRule::new(kst, signal);
+    let crossed: Vec<usize> = (0..30).filter(|i| rule.is_satisfied(*i)).collect();
+    assert_eq!(vec![4, 12, 27], crossed);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_cci_double(#[case] factory: DoubleNumFactory) {
+    test_cci::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_cci_decimal(#[case] factory: DecimalNumFactory) {
+    test_cci::<DecimalNum>(Arc::new(factory));
+}
+fn test_cci<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series::<T>(factory);
+
+    assert!(CciIndicator::from_mut(series.clone(), 0).is_err());
+    let cci = CciIndicator::from_mut(series, 5).unwrap();
+    assert_eq!(4, cci.count_of_unstable_bars());
+
+    let expected = [
+        0.0, -66.66667, -100.0, -103.6984, -85.16308, -73.57212, 46.28781, 147.83821, 86.84757,
+        107.90664, 103.41713, 57.84011, 29.75226, 14.38053, -150.85818,
+    ];
+    for (index, value) in expected.iter().enumerate() {
+        assert_num_eq_with_offset(*value, cci.get_value(index).unwrap(), GENERAL_OFFSET);
+    }
+}
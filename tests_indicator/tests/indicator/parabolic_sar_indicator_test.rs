@@ -0,0 +1,144 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::bar::builder::types::BarSeriesRef;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::helpers::close_price_indicator::ClosePriceIndicator;
+use ta4r::indicators::parabolic_sar_indicator::ParabolicSarIndicator;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::num::{NumFactory, TrNum};
+use ta4r::rule::Rule;
+use ta4r::rule::crossed_down_indicator_rule::CrossedDownIndicatorRule;
+use tests_indicator::types::{
+    GENERAL_OFFSET, TA4J_PULLBACK_BARS, assert_num_eq_with_offset, assert_values, ta4j_series_with,
+};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_parabolic_sar_double(#[case] factory: DoubleNumFactory) {
+    test_parabolic_sar::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_parabolic_sar_decimal(#[case] factory: DecimalNumFactory) {
+    test_parabolic_sar::<DecimalNum>(Arc::new(factory));
+}
+fn test_parabolic_sar<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series_with::<T>(factory, &TA4J_PULLBACK_BARS);
+
+    let sar = Arc::new(ParabolicSarIndicator::from_mut(series.clone()).unwrap());
+    assert_eq!(2, sar.count_of_unstable_bars());
+    // 1 转为下降趋势，7 向上反转，15 再次向下反转
+    assert_values(
+        &[
+            116.0, 119.5, 119.94, 119.94, 119.3472, 118.5698, 117.6258, 109.13, 109.2974, 109.6631,
+            110.3361, 111.2644, 112.1185, 112.9042, 113.6271, 121.94, 121.7812,
+        ],
+        sar.as_ref(),
+    );
+    let trend: Vec<bool> = (0..17).map(|i| sar.is_uptrend(i).unwrap()).collect();
+    assert_eq!(
+        vec![
+            true, false, false, false, false, false, false, true, true, true, true, true, true,
+            true, true, false, false
+        ],
+        trend
+    );
+    let state = sar.state(10).unwrap();
+    assert_num_eq_with_offset(121.94, state.extreme_point, GENERAL_OFFSET);
+    assert_num_eq_with_offset(0.08, state.acceleration, GENERAL_OFFSET);
+
+    // 收盘价下穿 SAR：做多离场 / 跟踪止损信号
+    let close = Arc::new(ClosePriceIndicator::from_mut(series));
+    let rule: CrossedDownIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        CrossedDownIndicatorRule::new(close, sar);
+    let signals: Vec<usize> = (0..17).filter(|i| rule.is_satisfied(*i)).collect();
+    assert_eq!(vec![1, 15], signals);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_parabolic_sar_custom_factors_double(#[case] factory: DoubleNumFactory) {
+    test_parabolic_sar_custom_factors::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_parabolic_sar_custom_factors_decimal(#[case] factory: DecimalNumFactory) {
+    test_parabolic_sar_custom_factors::<DecimalNum>(Arc::new(factory));
+}
+fn test_parabolic_sar_custom_factors<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series_with::<T>(factory.clone(), &TA4J_PULLBACK_BARS);
+    let series_ref = BarSeriesRef::Mut(series);
+
+    assert!(
+        ParabolicSarIndicator::with_factors(
+            series_ref.clone(),
+            factory.num_of_f64(0.0),
+            factory.num_of_f64(0.01),
+            factory.num_of_f64(0.1),
+        )
+        .is_err()
+    );
+    assert!(
+        ParabolicSarIndicator::with_factors(
+            series_ref.clone(),
+            factory.num_of_f64(0.2),
+            factory.num_of_f64(0.01),
+            factory.num_of_f64(0.1),
+        )
+        .is_err()
+    );
+
+    let sar = ParabolicSarIndicator::with_factors(
+        series_ref,
+        factory.num_of_f64(0.01),
+        factory.num_of_f64(0.01),
+        factory.num_of_f64(0.1),
+    )
+    .unwrap();
+    assert_values(
+        &[
+            116.0, 119.5, 119.94, 119.94, 119.6436, 119.2431, 118.7374, 118.257, 109.13, 109.2231,
+            109.4562, 109.8308, 110.194, 110.5464, 110.8882, 111.2198, 111.5414,
+        ],
+        &sar,
+    );
+}
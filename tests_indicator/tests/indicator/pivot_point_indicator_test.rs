@@ -0,0 +1,271 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::helpers::close_price_indicator::ClosePriceIndicator;
+use ta4r::indicators::pivot_points::pivot_level_indicator::PivotLevelIndicator;
+use ta4r::indicators::pivot_points::pivot_point_indicator::PivotPointIndicator;
+use ta4r::indicators::pivot_points::{PivotLevel, PivotMethod};
+use ta4r::indicators::types::IndicatorError;
+use ta4r::indicators::vwap::AnchorPeriod;
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::rule::Rule;
+use ta4r::rule::over_indicator_rule::OverIndicatorRule;
+use ta4r::rule::under_indicator_rule::UnderIndicatorRule;
+use tests_indicator::types::{
+    GENERAL_OFFSET, Ohlcv, assert_num_eq_with_offset, timed_ohlcv_series,
+};
+use time::Duration;
+use time_macros::datetime;
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+/// 6 小时 bar，每天 4 根：2025-04-05（周六）到 2025-04-07（周一）
+const BARS: [Ohlcv; 12] = [
+    (100.0, 102.0, 99.0, 101.0, 0.0),
+    (101.0, 104.0, 100.0, 103.0, 0.0),
+    (103.0, 105.0, 102.0, 104.0, 0.0),
+    (104.0, 106.0, 103.0, 105.0, 0.0),
+    (105.0, 107.0, 101.0, 102.0, 0.0),
+    (102.0, 103.0, 98.0, 99.0, 0.0),
+    (99.0, 101.0, 97.0, 100.0, 0.0),
+    (100.0, 102.0, 99.0, 101.5, 0.0),
+    (101.5, 104.0, 101.0, 103.5, 0.0),
+    (103.5, 108.0, 103.0, 107.0, 0.0),
+    (107.0, 109.0, 105.0, 106.0, 0.0),
+    (106.0, 107.0, 104.0, 104.5, 0.0),
+];
+
+/// 依次为 P、R1、R2、R3、S1、S2、S3
+fn assert_levels<T>(
+    expected: &[f64],
+    pivot: &Arc<PivotPointIndicator<T, BaseBarSeries<T>>>,
+    index: usize,
+) where
+    T: TrNum + 'static,
+{
+    let levels = [
+        PivotLevel::Resistance1,
+        PivotLevel::Resistance2,
+        PivotLevel::Resistance3,
+        PivotLevel::Support1,
+        PivotLevel::Support2,
+        PivotLevel::Support3,
+    ];
+    assert_num_eq_with_offset(expected[0], pivot.get_value(index).unwrap(), GENERAL_OFFSET);
+    for (value, level) in expected[1..].iter().zip(levels) {
+        let indicator = PivotLevelIndicator::new(pivot.clone(), level).unwrap();
+        assert_num_eq_with_offset(*value, indicator.get_value(index).unwrap(), GENERAL_OFFSET);
+    }
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_daily_pivot_points_double(#[case] factory: DoubleNumFactory) {
+    test_daily_pivot_points::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_daily_pivot_points_decimal(#[case] factory: DecimalNumFactory) {
+    test_daily_pivot_points::<DecimalNum>(Arc::new(factory));
+}
+fn test_daily_pivot_points<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = timed_ohlcv_series::<T>(
+        factory,
+        &BARS,
+        datetime!(2025-04-05 06:00 UTC),
+        Duration::hours(6),
+    );
+    let pivot = |method| {
+        Arc::new(PivotPointIndicator::from_mut(
+            series.clone(),
+            AnchorPeriod::Day,
+            method,
+        ))
+    };
+
+    let standard = pivot(PivotMethod::Standard);
+    // 第一天没有前一天的数据，第二天开始才有值
+    assert!(matches!(
+        standard.get_value(2),
+        Err(IndicatorError::NotAvailable {
+            index: 2,
+            available_from: 4
+        })
+    ));
+    // 同一天的每一根 bar 使用相同的价位
+    for index in 4..8 {
+        assert_levels(
+            &[
+                103.33333, 107.66667, 110.33333, 114.66667, 100.66667, 96.33333, 93.66667,
+            ],
+            &standard,
+            index,
+        );
+    }
+    assert_levels(
+        &[
+            101.83333, 106.66667, 111.83333, 116.66667, 96.66667, 91.83333, 86.66667,
+        ],
+        &standard,
+        9,
+    );
+
+    let fibonacci = pivot(PivotMethod::Fibonacci);
+    assert_levels(
+        &[
+            103.33333, 106.00733, 107.65933, 110.33333, 100.65933, 99.00733, 96.33333,
+        ],
+        &fibonacci,
+        5,
+    );
+    assert_levels(
+        &[
+            101.83333, 105.65333, 108.01333, 111.83333, 98.01333, 95.65333, 91.83333,
+        ],
+        &fibonacci,
+        11,
+    );
+
+    let camarilla = pivot(PivotMethod::Camarilla);
+    assert_levels(
+        &[
+            103.33333, 105.64167, 106.28333, 106.925, 104.35833, 103.71667, 103.075,
+        ],
+        &camarilla,
+        4,
+    );
+    assert_levels(
+        &[
+            101.83333, 102.41667, 103.33333, 104.25, 100.58333, 99.66667, 98.75,
+        ],
+        &camarilla,
+        8,
+    );
+
+    let woodie = pivot(PivotMethod::Woodie);
+    assert_levels(
+        &[103.75, 108.5, 110.75, 115.5, 101.5, 96.75, 94.5],
+        &woodie,
+        6,
+    );
+    assert_levels(
+        &[101.75, 106.5, 111.75, 116.5, 96.5, 91.75, 86.5],
+        &woodie,
+        10,
+    );
+
+    // DeMark 只有 R1 / S1
+    let demark = pivot(PivotMethod::DeMark);
+    assert!(PivotLevelIndicator::new(demark.clone(), PivotLevel::Resistance2).is_err());
+    let demark_r1 = PivotLevelIndicator::new(demark.clone(), PivotLevel::Resistance1).unwrap();
+    let demark_s1 = PivotLevelIndicator::new(demark.clone(), PivotLevel::Support1).unwrap();
+    assert_num_eq_with_offset(104.0, demark.get_value(7).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(109.0, demark_r1.get_value(7).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(102.0, demark_s1.get_value(7).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(100.625, demark.get_value(8).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(104.25, demark_r1.get_value(8).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(94.25, demark_s1.get_value(8).unwrap(), GENERAL_OFFSET);
+
+    // 收盘价突破 R1 / 跌破 S1
+    let close = Arc::new(ClosePriceIndicator::from_mut(series.clone()));
+    let r1 = Arc::new(PivotLevelIndicator::new(standard.clone(), PivotLevel::Resistance1).unwrap());
+    let s1 = Arc::new(PivotLevelIndicator::new(standard, PivotLevel::Support1).unwrap());
+    let over: OverIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        OverIndicatorRule::new(close.clone(), r1);
+    let under: UnderIndicatorRule<_, _, _, _, _, _, Record<T>> = UnderIndicatorRule::new(close, s1);
+    let breakouts: Vec<usize> = (0..12).filter(|i| over.is_satisfied(*i)).collect();
+    assert_eq!(vec![9], breakouts);
+    let breakdowns: Vec<usize> = (0..12).filter(|i| under.is_satisfied(*i)).collect();
+    assert_eq!(vec![5, 6], breakdowns);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_weekly_pivot_points_double(#[case] factory: DoubleNumFactory) {
+    test_weekly_pivot_points::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_weekly_pivot_points_decimal(#[case] factory: DecimalNumFactory) {
+    test_weekly_pivot_points::<DecimalNum>(Arc::new(factory));
+}
+fn test_weekly_pivot_points<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = timed_ohlcv_series::<T>(
+        factory,
+        &BARS,
+        datetime!(2025-04-05 06:00 UTC),
+        Duration::hours(6),
+    );
+
+    // 周六、周日属于前一周，周一开始新的一周
+    let weekly = Arc::new(PivotPointIndicator::from_mut(
+        series.clone(),
+        AnchorPeriod::Week,
+        PivotMethod::Standard,
+    ));
+    assert_eq!(AnchorPeriod::Week, weekly.period());
+    assert!(matches!(
+        weekly.get_value(7),
+        Err(IndicatorError::NotAvailable {
+            index: 7,
+            available_from: 8
+        })
+    ));
+    for index in 8..12 {
+        assert_levels(
+            &[
+                101.83333, 106.66667, 111.83333, 116.66667, 96.66667, 91.83333, 86.66667,
+            ],
+            &weekly,
+            index,
+        );
+    }
+
+    // 整个序列都在同一个月内
+    let monthly = PivotPointIndicator::from_mut(series, AnchorPeriod::Month, PivotMethod::Standard);
+    assert!(matches!(
+        monthly.get_value(11),
+        Err(IndicatorError::NotAvailable {
+            index: 11,
+            available_from: 12
+        })
+    ));
+}
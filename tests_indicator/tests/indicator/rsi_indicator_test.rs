@@ -0,0 +1,201 @@
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::connors_rsi_indicator::ConnorsRsiIndicator;
+use ta4r::indicators::helpers::loss_indicator::LossIndicator;
+use ta4r::indicators::helpers::streak_indicator::StreakIndicator;
+use ta4r::indicators::rsi_indicator::RsiIndicator;
+use ta4r::indicators::stochastic_rsi_indicator::StochasticRsiIndicator;
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use tests_indicator::types::{
+    GENERAL_OFFSET, assert_num_eq, assert_num_eq_with_offset, close_price,
+};
+
+/// ta4j RSIIndicatorTest 使用的数据
+const CLOSES: [f64; 26] = [
+    50.45, 50.30, 50.20, 50.15, 50.05, 50.06, 50.10, 50.08, 50.03, 50.07, 50.01, 50.14, 50.22,
+    50.43, 50.50, 50.56, 50.52, 50.70, 50.55, 50.62, 50.90, 50.82, 50.86, 51.20, 51.30, 51.10,
+];
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_loss_and_streak_double(#[case] factory: DoubleNumFactory) {
+    test_loss_and_streak::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_loss_and_streak_decimal(#[case] factory: DecimalNumFactory) {
+    test_loss_and_streak::<DecimalNum>(Arc::new(factory));
+}
+fn test_loss_and_streak<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &CLOSES);
+
+    let loss = LossIndicator::new(close.clone());
+    assert_eq!(1, loss.count_of_unstable_bars());
+    assert_num_eq(0.0, loss.get_value(0).unwrap());
+    assert_num_eq(0.15, loss.get_value(1).unwrap());
+    assert_num_eq(0.0, loss.get_value(5).unwrap());
+    assert_num_eq(0.2, loss.get_value(25).unwrap());
+
+    let streak = StreakIndicator::new(close);
+    let expected = [
+        0., -1., -2., -3., -4., 1., 2., -1., -2., 1., -1., 1., 2., 3., 4., 5., -1., 1., -1., 1.,
+        2., -1., 1., 2., 3., -1.,
+    ];
+    for (index, value) in expected.iter().enumerate() {
+        assert_num_eq(*value, streak.get_value(index).unwrap());
+    }
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_rsi_double(#[case] factory: DoubleNumFactory) {
+    test_rsi::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_rsi_decimal(#[case] factory: DecimalNumFactory) {
+    test_rsi::<DecimalNum>(Arc::new(factory));
+}
+fn test_rsi<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &CLOSES);
+
+    let rsi = RsiIndicator::new(close.clone(), 14).unwrap();
+    assert_eq!(14, rsi.count_of_unstable_bars());
+    assert_num_eq(0.0, rsi.get_value(0).unwrap());
+
+    let expected = [
+        (15, 68.4746),
+        (16, 64.7836),
+        (17, 72.0776),
+        (18, 60.7800),
+        (19, 63.6439),
+        (20, 72.3433),
+        (21, 67.3822),
+        (22, 68.5438),
+        (23, 76.2770),
+        (24, 77.9908),
+        (25, 67.4895),
+    ];
+    for (index, value) in expected {
+        assert_num_eq_with_offset(value, rsi.get_value(index).unwrap(), GENERAL_OFFSET);
+    }
+
+    // 没有跌幅为 100，没有涨幅为 0
+    let rsi = RsiIndicator::new(close, 1).unwrap();
+    assert_num_eq(100.0, rsi.get_value(14).unwrap());
+    assert_num_eq(100.0, rsi.get_value(15).unwrap());
+    assert_num_eq(0.0, rsi.get_value(1).unwrap());
+    assert_num_eq(0.0, rsi.get_value(2).unwrap());
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_stochastic_rsi_double(#[case] factory: DoubleNumFactory) {
+    test_stochastic_rsi::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_stochastic_rsi_decimal(#[case] factory: DecimalNumFactory) {
+    test_stochastic_rsi::<DecimalNum>(Arc::new(factory));
+}
+fn test_stochastic_rsi<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &CLOSES);
+
+    let stoch_rsi = StochasticRsiIndicator::new(close, 14).unwrap();
+    assert_eq!(27, stoch_rsi.count_of_unstable_bars());
+
+    // RSI 在窗口内没有变化
+    assert_num_eq(0.0, stoch_rsi.get_value(0).unwrap());
+
+    let expected = [
+        (15, 1.0),
+        (16, 0.9461),
+        (17, 1.0),
+        (18, 0.8365),
+        (19, 0.8610),
+        (20, 1.0),
+        (21, 0.9186),
+        (22, 0.9306),
+        (23, 1.0),
+        (24, 1.0),
+        (25, 0.6608),
+    ];
+    for (index, value) in expected {
+        assert_num_eq_with_offset(value, stoch_rsi.get_value(index).unwrap(), GENERAL_OFFSET);
+    }
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_connors_rsi_double(#[case] factory: DoubleNumFactory) {
+    test_connors_rsi::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_connors_rsi_decimal(#[case] factory: DecimalNumFactory) {
+    test_connors_rsi::<DecimalNum>(Arc::new(factory));
+}
+fn test_connors_rsi<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &CLOSES);
+
+    let crsi = ConnorsRsiIndicator::new(close.clone(), 3, 2, 10).unwrap();
+    assert_eq!(11, crsi.count_of_unstable_bars());
+
+    let expected = [
+        (2, 33.3333),
+        (5, 63.5112),
+        (11, 78.8331),
+        (15, 83.6112),
+        (20, 85.6341),
+        (24, 81.6525),
+        (25, 25.3296),
+    ];
+    for (index, value) in expected {
+        assert_num_eq_with_offset(value, crsi.get_value(index).unwrap(), GENERAL_OFFSET);
+    }
+
+    let crsi = ConnorsRsiIndicator::with_default_periods(close).unwrap();
+    assert_eq!(101, crsi.count_of_unstable_bars());
+    assert_num_eq_with_offset(84.4061, crsi.get_value(24).unwrap(), GENERAL_OFFSET);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_connors_rsi_with_zero_price_double(#[case] factory: DoubleNumFactory) {
+    test_connors_rsi_with_zero_price::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_connors_rsi_with_zero_price_decimal(#[case] factory: DecimalNumFactory) {
+    test_connors_rsi_with_zero_price::<DecimalNum>(Arc::new(factory));
+}
+fn test_connors_rsi_with_zero_price<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &[2., 1., 0., 1., 2., 3., 2., 4., 5., 4.]);
+    let crsi = ConnorsRsiIndicator::new(close, 3, 2, 5).unwrap();
+
+    // 零价格之后的变化率按 0 计入百分位排名，不再返回错误
+    for index in 0..10 {
+        let value = crsi.get_value(index).unwrap().to_f64().unwrap();
+        assert!((0.0..=100.0).contains(&value), "index {index}: {value}");
+    }
+}
@@ -0,0 +1,208 @@
+use rstest::rstest;
+use std::cell::RefCell;
+use std::sync::Arc;
+use ta4r::bar::builder::mocks::mock_bar_series_builder::MockBarSeriesBuilder;
+use ta4r::bar::types::{BarBuilder, BarSeries};
+use ta4r::indicators::Indicator;
+use ta4r::indicators::helpers::close_price_indicator::ClosePriceIndicator;
+use ta4r::indicators::statistics::correlation_coefficient_indicator::CorrelationCoefficientIndicator;
+use ta4r::indicators::statistics::covariance_indicator::CovarianceIndicator;
+use ta4r::indicators::statistics::mean_deviation_indicator::MeanDeviationIndicator;
+use ta4r::indicators::statistics::standard_deviation_indicator::StandardDeviationIndicator;
+use ta4r::indicators::statistics::standard_error_indicator::StandardErrorIndicator;
+use ta4r::indicators::statistics::variance_indicator::VarianceIndicator;
+use ta4r::indicators::statistics::z_score_indicator::ZScoreIndicator;
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use tests_indicator::types::{
+    GENERAL_OFFSET, assert_num_eq_with_offset, assert_values, close_price,
+};
+
+const FIRST: [f64; 10] = [10., 12., 11., 14., 13., 15., 17., 16., 18., 17.];
+const SECOND: [f64; 10] = [5., 4., 6., 7., 6., 9., 8., 10., 11., 12.];
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_variance_and_standard_error_double(#[case] factory: DoubleNumFactory) {
+    test_variance_and_standard_error::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_variance_and_standard_error_decimal(#[case] factory: DecimalNumFactory) {
+    test_variance_and_standard_error::<DecimalNum>(Arc::new(factory));
+}
+fn test_variance_and_standard_error<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &FIRST);
+    assert!(VarianceIndicator::new(close.clone(), 0).is_err());
+
+    let variance = VarianceIndicator::new(close.clone(), 4).unwrap();
+    assert_eq!(3, variance.count_of_unstable_bars());
+    // 先访问末尾（slow path），再顺序访问（fast path）
+    assert_num_eq_with_offset(0.5, variance.get_value(9).unwrap(), GENERAL_OFFSET);
+    assert_values(
+        &[
+            0.0, 1.0, 0.6667, 2.1875, 1.25, 2.1875, 2.1875, 2.1875, 1.25, 0.5,
+        ],
+        &variance,
+    );
+
+    let deviation = StandardDeviationIndicator::new(close.clone(), 4).unwrap();
+    assert_num_eq_with_offset(1.4790, deviation.get_value(3).unwrap(), GENERAL_OFFSET);
+
+    let error = StandardErrorIndicator::new(close, 4).unwrap();
+    assert_eq!(3, error.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.0,
+            std::f64::consts::FRAC_1_SQRT_2,
+            0.4714,
+            0.7395,
+            0.5590,
+            0.7395,
+            0.7395,
+            0.7395,
+            0.5590,
+            0.3536,
+        ],
+        &error,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_covariance_and_correlation_double(#[case] factory: DoubleNumFactory) {
+    test_covariance_and_correlation::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_covariance_and_correlation_decimal(#[case] factory: DecimalNumFactory) {
+    test_covariance_and_correlation::<DecimalNum>(Arc::new(factory));
+}
+fn test_covariance_and_correlation<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let x = close_price::<T>(factory.clone(), &FIRST);
+    let y = close_price::<T>(factory, &SECOND);
+
+    let covariance = CovarianceIndicator::new(x.clone(), y.clone(), 4).unwrap();
+    assert_eq!(3, covariance.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.0, -0.5, -0.3333, 0.875, 0.625, 1.5, 1.125, 1.4375, 0.5, 0.25,
+        ],
+        &covariance,
+    );
+
+    let correlation = CorrelationCoefficientIndicator::new(x.clone(), y, 4).unwrap();
+    assert_eq!(3, correlation.count_of_unstable_bars());
+    // index 0 方差为 0，相关系数返回 0
+    assert_values(
+        &[
+            0.0, -1.0, -0.5, 0.5292, 0.5130, 0.8281, 0.6803, 0.6571, 0.4, 0.2390,
+        ],
+        &correlation,
+    );
+
+    // 与自身完全正相关
+    let self_correlation = CorrelationCoefficientIndicator::new(x.clone(), x, 4).unwrap();
+    for index in 1..10 {
+        assert_num_eq_with_offset(
+            1.0,
+            self_correlation.get_value(index).unwrap(),
+            GENERAL_OFFSET,
+        );
+    }
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_z_score_and_mean_deviation_double(#[case] factory: DoubleNumFactory) {
+    test_z_score_and_mean_deviation::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_z_score_and_mean_deviation_decimal(#[case] factory: DecimalNumFactory) {
+    test_z_score_and_mean_deviation::<DecimalNum>(Arc::new(factory));
+}
+fn test_z_score_and_mean_deviation<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let close = close_price::<T>(factory, &FIRST);
+
+    let z_score = ZScoreIndicator::new(close.clone(), 4).unwrap();
+    assert_eq!(3, z_score.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.0, 1.0, 0.0, 1.5213, 0.4472, 1.1832, 1.5213, 0.5071, 1.3416, 0.0,
+        ],
+        &z_score,
+    );
+
+    assert!(MeanDeviationIndicator::new(close.clone(), 0).is_err());
+    let mean_deviation = MeanDeviationIndicator::new(close, 4).unwrap();
+    assert_eq!(3, mean_deviation.count_of_unstable_bars());
+    assert_values(
+        &[0.0, 1.0, 0.6667, 1.25, 1.0, 1.25, 1.25, 1.25, 1.0, 0.5],
+        &mean_deviation,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_live_last_bar_double(#[case] factory: DoubleNumFactory) {
+    test_live_last_bar::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_live_last_bar_decimal(#[case] factory: DecimalNumFactory) {
+    test_live_last_bar::<DecimalNum>(Arc::new(factory));
+}
+fn test_live_last_bar<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = Arc::new(RefCell::new(
+        MockBarSeriesBuilder::<T>::default()
+            .with_num_factory(factory)
+            .with_data(vec![10., 12., 11., 14., 13., 15., 17., 16., 18., 17.])
+            .build(),
+    ));
+    let close = Arc::new(ClosePriceIndicator::from_mut(series.clone()));
+    let variance = VarianceIndicator::new(close.clone(), 4).unwrap();
+    let covariance = CovarianceIndicator::new(close.clone(), close.clone(), 4).unwrap();
+
+    // 读取最新 bar，之后它继续成交，再追加新 bar
+    for index in 0..10 {
+        variance.get_value(index).unwrap();
+        covariance.get_value(index).unwrap();
+    }
+    series.borrow_mut().add_price(T::from_f64(21.0).unwrap());
+    series
+        .borrow_mut()
+        .bar_builder()
+        .close_price(T::from_f64(19.0).unwrap())
+        .add()
+        .expect("Failed to add bar");
+
+    // 先读新 bar：不能沿用被修改前的最新 bar 滑动窗口和
+    let fresh_variance = VarianceIndicator::new(close.clone(), 4).unwrap();
+    let fresh_covariance = CovarianceIndicator::new(close.clone(), close, 4).unwrap();
+    for index in [10, 9, 8] {
+        let expected = fresh_variance.get_value(index).unwrap().to_f64().unwrap();
+        assert_num_eq_with_offset(expected, variance.get_value(index).unwrap(), GENERAL_OFFSET);
+        let expected = fresh_covariance.get_value(index).unwrap().to_f64().unwrap();
+        assert_num_eq_with_offset(
+            expected,
+            covariance.get_value(index).unwrap(),
+            GENERAL_OFFSET,
+        );
+    }
+}
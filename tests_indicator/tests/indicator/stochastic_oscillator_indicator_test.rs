@@ -0,0 +1,100 @@
+use rstest::rstest;
+use std::cell::RefCell;
+use std::sync::Arc;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::stochastic_oscillator_d_indicator::StochasticOscillatorDIndicator;
+use ta4r::indicators::stochastic_oscillator_k_indicator::StochasticOscillatorKIndicator;
+use ta4r::indicators::williams_r_indicator::WilliamsRIndicator;
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use tests_indicator::types::{assert_values, ohlcv_series, ta4j_series};
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_stochastic_oscillator_double(#[case] factory: DoubleNumFactory) {
+    test_stochastic_oscillator::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_stochastic_oscillator_decimal(#[case] factory: DecimalNumFactory) {
+    test_stochastic_oscillator::<DecimalNum>(Arc::new(factory));
+}
+fn test_stochastic_oscillator<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ta4j_series::<T>(factory);
+    assert!(StochasticOscillatorKIndicator::from_mut(series.clone(), 0).is_err());
+
+    let k = Arc::new(StochasticOscillatorKIndicator::from_mut(series, 14).unwrap());
+    assert_eq!(13, k.count_of_unstable_bars());
+    assert_values(
+        &[
+            89.4286, 19.0355, 22.5030, 15.1822, 25.4122, 8.0481, 40.4255, 76.3182, 67.0675, 100.0,
+            95.6284, 88.7588, 93.1304, 84.8556, 72.2092,
+        ],
+        k.as_ref(),
+    );
+
+    let d = StochasticOscillatorDIndicator::with_default_smoothing(k);
+    assert_eq!(3, d.smoothing());
+    assert_eq!(15, d.count_of_unstable_bars());
+    assert_values(
+        &[
+            89.4286, 54.2321, 43.6557, 18.9069, 21.0325, 16.2142, 24.6286, 41.5973, 61.2704,
+            81.1286, 87.5653, 94.7957, 92.5059, 88.9149, 83.3984,
+        ],
+        &d,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_williams_r_double(#[case] factory: DoubleNumFactory) {
+    test_williams_r::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_williams_r_decimal(#[case] factory: DecimalNumFactory) {
+    test_williams_r::<DecimalNum>(Arc::new(factory));
+}
+fn test_williams_r<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let williams_r = WilliamsRIndicator::from_mut(ta4j_series::<T>(factory), 14).unwrap();
+    assert_eq!(13, williams_r.count_of_unstable_bars());
+    assert_values(
+        &[
+            -10.5714, -80.9645, -77.4970, -84.8178, -74.5878, -91.9519, -59.5745, -23.6818,
+            -32.9325, 0.0, -4.3716, -11.2412, -6.8696, -15.1444, -27.7908,
+        ],
+        &williams_r,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_flat_range_is_neutral_double(#[case] factory: DoubleNumFactory) {
+    test_flat_range_is_neutral::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_flat_range_is_neutral_decimal(#[case] factory: DecimalNumFactory) {
+    test_flat_range_is_neutral::<DecimalNum>(Arc::new(factory));
+}
+fn test_flat_range_is_neutral<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let bars = [(10.0, 10.0, 10.0, 10.0, 0.0); 3];
+    let series = Arc::new(RefCell::new(ohlcv_series::<T>(factory, &bars)));
+
+    let k = StochasticOscillatorKIndicator::from_mut(series.clone(), 2).unwrap();
+    let williams_r = WilliamsRIndicator::from_mut(series, 2).unwrap();
+    assert_values(&[50.0, 50.0, 50.0], &k);
+    assert_values(&[-50.0, -50.0, -50.0], &williams_r);
+}
@@ -0,0 +1,288 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::atr_indicator::ATRIndicator;
+use ta4r::indicators::helpers::close_price_indicator::ClosePriceIndicator;
+use ta4r::indicators::swing::fibonacci_retracement_indicator::FibonacciRetracementIndicator;
+use ta4r::indicators::swing::swing_point_indicator::SwingPointIndicator;
+use ta4r::indicators::swing::zigzag_indicator::{ZigZagIndicator, ZigZagThreshold};
+use ta4r::indicators::swing::zigzag_swing_indicator::ZigZagSwingIndicator;
+use ta4r::indicators::swing::{SwingKind, SwingSource};
+use ta4r::indicators::types::IndicatorError;
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::rule::Rule;
+use ta4r::rule::over_indicator_rule::OverIndicatorRule;
+use ta4r::rule::under_indicator_rule::UnderIndicatorRule;
+use tests_indicator::types::{GENERAL_OFFSET, Ohlcv, assert_num_eq_with_offset, ohlcv_series_mut};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+/// (open, high, low, close, volume)
+const BARS: [Ohlcv; 12] = [
+    (99.0, 100.0, 98.0, 99.0, 0.0),
+    (102.0, 104.0, 101.0, 102.5, 0.0),
+    (107.0, 110.0, 106.0, 108.0, 0.0),
+    (106.0, 108.0, 103.0, 105.5, 0.0),
+    (106.0, 112.0, 105.0, 108.5, 0.0),
+    (104.0, 106.0, 100.0, 103.0, 0.0),
+    (99.0, 101.0, 95.0, 98.0, 0.0),
+    (100.0, 103.0, 99.0, 101.0, 0.0),
+    (103.0, 106.0, 102.0, 104.0, 0.0),
+    (106.0, 109.0, 104.0, 106.5, 0.0),
+    (105.0, 107.0, 103.0, 105.0, 0.0),
+    (102.0, 105.0, 97.0, 101.0, 0.0),
+];
+
+/// 逐根 bar 检查最近一个已确认的摆动点：(价格, 所在 bar, 确认 bar)
+fn assert_swings<I, T>(expected: &[Option<(f64, usize, usize)>], indicator: &I)
+where
+    T: TrNum + 'static,
+    I: SwingSource<Num = T>,
+{
+    for (index, swing) in expected.iter().enumerate() {
+        let actual = indicator.swing(index).unwrap();
+        match swing {
+            Some((price, at, confirmed)) => {
+                let actual = actual.unwrap();
+                assert_num_eq_with_offset(*price, actual.price, GENERAL_OFFSET);
+                assert_eq!((*at, *confirmed), (actual.index, actual.confirmed_index));
+                assert_num_eq_with_offset(
+                    *price,
+                    indicator.get_value(index).unwrap(),
+                    GENERAL_OFFSET,
+                );
+            }
+            None => {
+                assert!(actual.is_none());
+                assert!(matches!(
+                    indicator.get_value(index),
+                    Err(IndicatorError::NotAvailable { .. })
+                ));
+            }
+        }
+    }
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_swing_points_double(#[case] factory: DoubleNumFactory) {
+    test_swing_points::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_swing_points_decimal(#[case] factory: DecimalNumFactory) {
+    test_swing_points::<DecimalNum>(Arc::new(factory));
+}
+fn test_swing_points<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ohlcv_series_mut::<T>(factory, &BARS);
+
+    // 左右各 2 根：高点 110 被右侧的 112 超越，不构成摆动高点
+    let high = SwingPointIndicator::from_mut(series.clone(), SwingKind::High, 2, 2).unwrap();
+    assert_eq!(2, high.confirmation_delay());
+    assert_eq!(4, high.count_of_unstable_bars());
+    let mut expected = vec![None; 6];
+    expected.extend([Some((112.0, 4, 6)); 5]);
+    expected.push(Some((109.0, 9, 11)));
+    assert_swings(&expected, &high);
+    assert!(matches!(
+        high.get_value(3),
+        Err(IndicatorError::NotAvailable {
+            index: 3,
+            available_from: 4
+        })
+    ));
+    assert!(matches!(
+        high.get_value(5),
+        Err(IndicatorError::NotAvailable {
+            index: 5,
+            available_from: 6
+        })
+    ));
+    assert!(high.is_confirmed(6).unwrap());
+    assert!(!high.is_confirmed(7).unwrap());
+
+    let low = SwingPointIndicator::from_mut(series.clone(), SwingKind::Low, 2, 2).unwrap();
+    let mut expected = vec![None; 8];
+    expected.extend([Some((95.0, 6, 8)); 4]);
+    assert_swings(&expected, &low);
+
+    // 左右各 1 根：确认延迟缩短为 1 根 bar
+    let high = SwingPointIndicator::from_mut(series.clone(), SwingKind::High, 1, 1).unwrap();
+    let indexes: Vec<Option<usize>> = (0..12).map(|i| high.swing_index(i).unwrap()).collect();
+    assert_eq!(
+        vec![
+            None,
+            None,
+            None,
+            Some(2),
+            Some(2),
+            Some(4),
+            Some(4),
+            Some(4),
+            Some(4),
+            Some(4),
+            Some(9),
+            Some(9)
+        ],
+        indexes
+    );
+    let low = SwingPointIndicator::from_mut(series.clone(), SwingKind::Low, 1, 1).unwrap();
+    let indexes: Vec<Option<usize>> = (0..12).map(|i| low.swing_index(i).unwrap()).collect();
+    assert_eq!(
+        vec![
+            None,
+            None,
+            None,
+            None,
+            Some(3),
+            Some(3),
+            Some(3),
+            Some(6),
+            Some(6),
+            Some(6),
+            Some(6),
+            Some(6)
+        ],
+        indexes
+    );
+
+    // Bill Williams 分形即左右各 2 根 bar 的摆动点
+    let fractal = SwingPointIndicator::fractal(high.bar_series(), SwingKind::Low);
+    assert_eq!(2, fractal.left_bar_count());
+    assert_eq!(2, fractal.right_bar_count());
+    assert_swings(&expected, &fractal);
+
+    assert!(SwingPointIndicator::from_mut(series, SwingKind::High, 0, 2).is_err());
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_zigzag_double(#[case] factory: DoubleNumFactory) {
+    test_zigzag::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_zigzag_decimal(#[case] factory: DecimalNumFactory) {
+    test_zigzag::<DecimalNum>(Arc::new(factory));
+}
+fn test_zigzag<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ohlcv_series_mut::<T>(factory, &BARS);
+    let num = |value: f64| T::from_f64(value).unwrap();
+
+    // 10% 阈值
+    let zigzag = Arc::new(
+        ZigZagIndicator::from_mut(series.clone(), ZigZagThreshold::Percentage(num(10.0))).unwrap(),
+    );
+    let mut expected = vec![None; 2];
+    expected.extend([Some((98.0, 0, 2)); 3]);
+    expected.extend([Some((112.0, 4, 5)); 3]);
+    expected.extend([Some((95.0, 6, 8)); 3]);
+    expected.push(Some((109.0, 9, 11)));
+    assert_swings(&expected, zigzag.as_ref());
+    assert_eq!(Some(SwingKind::Low), zigzag.state(11).unwrap().direction);
+
+    let highs = ZigZagSwingIndicator::new(zigzag.clone(), SwingKind::High);
+    let lows = ZigZagSwingIndicator::new(zigzag.clone(), SwingKind::Low);
+    assert_num_eq_with_offset(112.0, highs.get_value(8).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(95.0, lows.get_value(11).unwrap(), GENERAL_OFFSET);
+    assert!(highs.get_value(4).is_err());
+
+    // 2 倍 ATR(1) 阈值：转折点确认得更晚，最后一次回落不足以确认高点 109
+    let atr = Arc::new(ATRIndicator::from_mut(series.clone(), 1));
+    let zigzag = ZigZagIndicator::from_atr(atr, num(2.0)).unwrap();
+    let mut expected = vec![None; 3];
+    expected.extend([Some((98.0, 0, 3)); 3]);
+    expected.extend([Some((112.0, 4, 6)); 2]);
+    expected.extend([Some((95.0, 6, 8)); 4]);
+    assert_swings(&expected, &zigzag);
+
+    assert!(ZigZagIndicator::from_mut(series, ZigZagThreshold::Percentage(T::zero())).is_err());
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_fibonacci_retracement_double(#[case] factory: DoubleNumFactory) {
+    test_fibonacci_retracement::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_fibonacci_retracement_decimal(#[case] factory: DecimalNumFactory) {
+    test_fibonacci_retracement::<DecimalNum>(Arc::new(factory));
+}
+fn test_fibonacci_retracement<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ohlcv_series_mut::<T>(factory, &BARS);
+    let num = |value: f64| T::from_f64(value).unwrap();
+
+    // 摆动点指标作为输入：先涨后跌再反弹
+    let high =
+        Arc::new(SwingPointIndicator::from_mut(series.clone(), SwingKind::High, 2, 2).unwrap());
+    let low =
+        Arc::new(SwingPointIndicator::from_mut(series.clone(), SwingKind::Low, 2, 2).unwrap());
+    let golden = FibonacciRetracementIndicator::new(high, low, num(0.618)).unwrap();
+    assert!(golden.get_value(7).is_err());
+    assert_num_eq_with_offset(105.506, golden.get_value(8).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(100.348, golden.get_value(11).unwrap(), GENERAL_OFFSET);
+
+    // 之字形高低点作为输入
+    let zigzag = Arc::new(
+        ZigZagIndicator::from_mut(series.clone(), ZigZagThreshold::Percentage(num(10.0))).unwrap(),
+    );
+    let highs = Arc::new(ZigZagSwingIndicator::new(zigzag.clone(), SwingKind::High));
+    let lows = Arc::new(ZigZagSwingIndicator::new(zigzag, SwingKind::Low));
+    let half = Arc::new(FibonacciRetracementIndicator::new(highs, lows, num(0.5)).unwrap());
+    assert_num_eq_with_offset(105.0, half.get_value(5).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(103.5, half.get_value(8).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(102.0, half.get_value(11).unwrap(), GENERAL_OFFSET);
+
+    // 收盘价相对 50% 回撤位的支撑 / 阻力
+    let close = Arc::new(ClosePriceIndicator::from_mut(series));
+    let over: OverIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        OverIndicatorRule::new(close.clone(), half.clone());
+    let under: UnderIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        UnderIndicatorRule::new(close, half);
+    let above: Vec<usize> = (0..12).filter(|i| over.is_satisfied(*i)).collect();
+    assert_eq!(vec![8, 9, 10], above);
+    let below: Vec<usize> = (0..12).filter(|i| under.is_satisfied(*i)).collect();
+    assert_eq!(vec![5, 6, 7, 11], below);
+}
@@ -0,0 +1,185 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::volatility::VolatilityEstimator;
+use ta4r::indicators::volatility::annualized_volatility_indicator::AnnualizedVolatilityIndicator;
+use ta4r::indicators::volatility::realized_volatility_indicator::RealizedVolatilityIndicator;
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use tests_indicator::types::{
+    GENERAL_OFFSET, Ohlcv, assert_num_eq_with_offset, assert_values, timed_ohlcv_series,
+};
+use time::Duration;
+use time_macros::datetime;
+
+const BARS: [Ohlcv; 8] = [
+    (100.0, 102.0, 99.0, 101.0, 0.0),
+    (101.0, 104.0, 100.0, 103.0, 0.0),
+    (103.0, 105.0, 101.0, 102.0, 0.0),
+    (102.0, 103.0, 98.0, 99.0, 0.0),
+    (99.0, 101.0, 97.0, 100.0, 0.0),
+    (100.0, 104.0, 99.0, 103.5, 0.0),
+    (103.5, 106.0, 102.0, 105.0, 0.0),
+    (105.0, 107.0, 103.0, 104.0, 0.0),
+];
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_realized_volatility_double(#[case] factory: DoubleNumFactory) {
+    test_realized_volatility::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_realized_volatility_decimal(#[case] factory: DecimalNumFactory) {
+    test_realized_volatility::<DecimalNum>(Arc::new(factory));
+}
+fn test_realized_volatility<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = timed_ohlcv_series::<T>(
+        factory,
+        &BARS,
+        datetime!(2025-03-03 00:00 UTC),
+        Duration::days(1),
+    );
+    let volatility =
+        |estimator| RealizedVolatilityIndicator::from_mut(series.clone(), estimator, 4).unwrap();
+
+    // 收盘价对数收益率需要前一根 bar，至少 2 个收益率才有样本标准差
+    let close_to_close = volatility(VolatilityEstimator::CloseToClose);
+    assert_eq!(4, close_to_close.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.0, 0.0, 0.020764, 0.024875, 0.021963, 0.027477, 0.026911, 0.018039,
+        ],
+        &close_to_close,
+    );
+
+    let parkinson = volatility(VolatilityEstimator::Parkinson);
+    assert_eq!(3, parkinson.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.017929, 0.020931, 0.021759, 0.024049, 0.025402, 0.026934, 0.026885, 0.025109,
+        ],
+        &parkinson,
+    );
+
+    assert_values(
+        &[
+            0.020183, 0.022671, 0.024121, 0.025687, 0.027429, 0.028042, 0.027784, 0.026853,
+        ],
+        &volatility(VolatilityEstimator::GarmanKlass),
+    );
+    assert_values(
+        &[
+            0.019903, 0.022057, 0.02397, 0.025081, 0.027115, 0.027385, 0.026918, 0.026591,
+        ],
+        &volatility(VolatilityEstimator::RogersSatchell),
+    );
+    assert_values(
+        &[
+            0.0, 0.0, 0.025408, 0.026415, 0.026582, 0.027395, 0.026918, 0.025766,
+        ],
+        &volatility(VolatilityEstimator::YangZhang),
+    );
+
+    assert!(
+        RealizedVolatilityIndicator::from_mut(series.clone(), VolatilityEstimator::YangZhang, 1)
+            .is_err()
+    );
+    assert!(
+        RealizedVolatilityIndicator::from_mut(series, VolatilityEstimator::Parkinson, 0).is_err()
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_annualized_volatility_double(#[case] factory: DoubleNumFactory) {
+    test_annualized_volatility::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_annualized_volatility_decimal(#[case] factory: DecimalNumFactory) {
+    test_annualized_volatility::<DecimalNum>(Arc::new(factory));
+}
+fn test_annualized_volatility<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    // 日线：按 365 天年化系数为 sqrt(365)
+    let daily = timed_ohlcv_series::<T>(
+        factory.clone(),
+        &BARS,
+        datetime!(2025-03-03 00:00 UTC),
+        Duration::days(1),
+    );
+    let parkinson = Arc::new(
+        RealizedVolatilityIndicator::from_mut(daily.clone(), VolatilityEstimator::Parkinson, 4)
+            .unwrap(),
+    );
+    let annualized = AnnualizedVolatilityIndicator::new(parkinson);
+    assert_num_eq_with_offset(
+        19.104973,
+        annualized.annualization_factor(7).unwrap(),
+        GENERAL_OFFSET,
+    );
+    assert_num_eq_with_offset(0.479699, annualized.get_value(7).unwrap(), GENERAL_OFFSET);
+    assert_eq!(3, annualized.count_of_unstable_bars());
+
+    // 只统计交易日：252 天
+    let close_to_close = Arc::new(
+        RealizedVolatilityIndicator::from_mut(daily, VolatilityEstimator::CloseToClose, 4).unwrap(),
+    );
+    let trading_days =
+        AnnualizedVolatilityIndicator::with_year(close_to_close.clone(), Duration::days(252))
+            .unwrap();
+    assert_num_eq_with_offset(0.286353, trading_days.get_value(7).unwrap(), GENERAL_OFFSET);
+    assert!(AnnualizedVolatilityIndicator::with_year(close_to_close, Duration::ZERO).is_err());
+
+    // 小时线：一年 365 * 24 根 bar
+    let hourly = timed_ohlcv_series::<T>(
+        factory,
+        &BARS,
+        datetime!(2025-03-03 00:00 UTC),
+        Duration::hours(1),
+    );
+    let garman_klass = Arc::new(
+        RealizedVolatilityIndicator::from_mut(hourly, VolatilityEstimator::GarmanKlass, 4).unwrap(),
+    );
+    let annualized = AnnualizedVolatilityIndicator::new(garman_klass);
+    assert_num_eq_with_offset(
+        93.594872,
+        annualized.annualization_factor(7).unwrap(),
+        GENERAL_OFFSET,
+    );
+    assert_num_eq_with_offset(2.513321, annualized.get_value(7).unwrap(), GENERAL_OFFSET);
+}
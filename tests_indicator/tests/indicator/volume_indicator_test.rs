@@ -0,0 +1,301 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::cell::RefCell;
+use std::sync::Arc;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::bar::builder::mocks::mock_bar_series_builder::MockBarSeriesBuilder;
+use ta4r::bar::builder::types::BarSeriesRef;
+use ta4r::bar::types::{BarBuilder, BarSeries};
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::helpers::constant_indicator::ConstantIndicator;
+use ta4r::indicators::volume::accumulation_distribution_indicator::AccumulationDistributionIndicator;
+use ta4r::indicators::volume::chaikin_money_flow_indicator::ChaikinMoneyFlowIndicator;
+use ta4r::indicators::volume::chaikin_oscillator_indicator::ChaikinOscillatorIndicator;
+use ta4r::indicators::volume::ease_of_movement_indicator::EaseOfMovementIndicator;
+use ta4r::indicators::volume::force_index_indicator::ForceIndexIndicator;
+use ta4r::indicators::volume::mfi_indicator::MfiIndicator;
+use ta4r::indicators::volume::nvi_indicator::NviIndicator;
+use ta4r::indicators::volume::on_balance_volume_indicator::OnBalanceVolumeIndicator;
+use ta4r::indicators::volume::pvi_indicator::PviIndicator;
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::rule::Rule;
+use ta4r::rule::crossed_up_indicator_rule::CrossedUpIndicatorRule;
+use tests_indicator::types::{Ohlcv, TA4J_BARS, assert_values, ohlcv_series_mut};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+/// TA4J_BARS 各根 bar 的成交量
+const VOLUMES: [f64; 15] = [
+    1000.0, 1500.0, 1200.0, 1800.0, 900.0, 1100.0, 2000.0, 2200.0, 1300.0, 2500.0, 1700.0, 1600.0,
+    1400.0, 1900.0, 2100.0,
+];
+
+/// (open, high, low, close, volume)
+fn bars() -> Vec<Ohlcv> {
+    TA4J_BARS
+        .iter()
+        .zip(VOLUMES)
+        .map(|(&(open, high, low, close, _), volume)| (open, high, low, close, volume))
+        .collect()
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_cumulative_volume_double(#[case] factory: DoubleNumFactory) {
+    test_cumulative_volume::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_cumulative_volume_decimal(#[case] factory: DecimalNumFactory) {
+    test_cumulative_volume::<DecimalNum>(Arc::new(factory));
+}
+fn test_cumulative_volume<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ohlcv_series_mut::<T>(factory, &bars());
+
+    let obv = OnBalanceVolumeIndicator::from_mut(series.clone());
+    assert_eq!(0, obv.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.0, -1500.0, -2700.0, -4500.0, -3600.0, -4700.0, -2700.0, -500.0, -1800.0, 700.0,
+            2400.0, 800.0, 2200.0, 300.0, -1800.0,
+        ],
+        &obv,
+    );
+
+    let adl = AccumulationDistributionIndicator::from_mut(series.clone());
+    assert_values(
+        &[
+            788.57143,
+            -140.36258,
+            -681.33174,
+            -1173.82569,
+            -590.80682,
+            -1246.7233,
+            -948.38076,
+            1098.13087,
+            922.64878,
+            3422.64878,
+            4398.69441,
+            4844.59605,
+            6063.71749,
+            5549.43178,
+            4680.46626,
+        ],
+        &adl,
+    );
+
+    let nvi = NviIndicator::from_mut(series.clone());
+    assert_values(
+        &[
+            1000.0, 1000.0, 972.16274, 972.16274, 978.17558, 978.17558, 978.17558, 978.17558,
+            969.84217, 969.84217, 973.85376, 966.79336, 971.28634, 971.28634, 971.28634,
+        ],
+        &nvi,
+    );
+
+    let pvi = PviIndicator::from_mut(series);
+    assert_values(
+        &[
+            1000.0, 980.02182, 980.02182, 963.27079, 963.27079, 943.96247, 973.99764, 1007.29377,
+            1007.29377, 1046.24223, 1046.24223, 1046.24223, 1046.24223, 1037.08134, 1023.08075,
+        ],
+        &pvi,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_chaikin_double(#[case] factory: DoubleNumFactory) {
+    test_chaikin::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_chaikin_decimal(#[case] factory: DecimalNumFactory) {
+    test_chaikin::<DecimalNum>(Arc::new(factory));
+}
+fn test_chaikin<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ohlcv_series_mut::<T>(factory, &bars());
+
+    let oscillator = Arc::new(ChaikinOscillatorIndicator::from_mut(series.clone()).unwrap());
+    assert_eq!(10, oscillator.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.0, -295.56991, -561.74144, -776.265, -607.94909, -692.52518, -569.24049, 184.10609,
+            419.7212, 1273.40727, 1817.43815, 2016.65239, 2302.71936, 2046.77227, 1479.50741,
+        ],
+        oscillator.as_ref(),
+    );
+
+    assert!(ChaikinMoneyFlowIndicator::from_mut(series.clone(), 0).is_err());
+    let cmf = Arc::new(ChaikinMoneyFlowIndicator::from_mut(series, 5).unwrap());
+    assert_eq!(4, cmf.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.78857, -0.05615, -0.18414, -0.21342, -0.09231, -0.31312, -0.11543, 0.22243, 0.27953,
+            0.44104, 0.582, 0.6229, 0.58419, 0.50844, 0.14458,
+        ],
+        cmf.as_ref(),
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_money_flow_index_double(#[case] factory: DoubleNumFactory) {
+    test_money_flow_index::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_money_flow_index_decimal(#[case] factory: DecimalNumFactory) {
+    test_money_flow_index::<DecimalNum>(Arc::new(factory));
+}
+fn test_money_flow_index<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ohlcv_series_mut::<T>(factory.clone(), &bars());
+
+    assert!(MfiIndicator::from_mut(series.clone(), 0).is_err());
+    // 未指定成交额时资金流为典型价格 * 成交量
+    let mfi = MfiIndicator::from_mut(series, 5).unwrap();
+    assert_eq!(5, mfi.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 28.74867, 53.22859, 74.03083, 88.43607, 100.0, 82.56958,
+            64.53448, 67.03133, 41.56299,
+        ],
+        &mfi,
+    );
+
+    // 显式指定的成交额直接作为资金流（这里为 volume * 100）
+    let mut amount_series = MockBarSeriesBuilder::<T>::default()
+        .with_num_factory(factory)
+        .build();
+    let num = |value: f64| T::from_f64(value).unwrap();
+    for &(open, high, low, close, volume) in bars().iter() {
+        amount_series
+            .bar_builder()
+            .open_price(num(open))
+            .high_price(num(high))
+            .low_price(num(low))
+            .close_price(num(close))
+            .volume(num(volume))
+            .amount(num(volume * 100.0))
+            .add()
+            .unwrap();
+    }
+    let mfi = MfiIndicator::from_mut(Arc::new(RefCell::new(amount_series)), 5).unwrap();
+    assert_values(
+        &[
+            0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 28.57143, 52.5, 73.33333, 87.91209, 100.0, 82.7957,
+            64.70588, 67.03297, 41.37931,
+        ],
+        &mfi,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_force_and_ease_of_movement_double(#[case] factory: DoubleNumFactory) {
+    test_force_and_ease_of_movement::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_force_and_ease_of_movement_decimal(#[case] factory: DecimalNumFactory) {
+    test_force_and_ease_of_movement::<DecimalNum>(Arc::new(factory));
+}
+fn test_force_and_ease_of_movement<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ohlcv_series_mut::<T>(factory, &bars());
+
+    assert!(ForceIndexIndicator::from_mut(series.clone(), 0).is_err());
+    let force_index = Arc::new(ForceIndexIndicator::from_mut(series.clone(), 3).unwrap());
+    assert_eq!(3, force_index.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.0,
+            -1785.0,
+            -2842.5,
+            -3167.25,
+            -1273.125,
+            -1874.0625,
+            2562.96875,
+            5549.48437,
+            2124.74219,
+            6687.37109,
+            3768.68555,
+            1180.34277,
+            982.17139,
+            -515.91431,
+            -1958.95715,
+        ],
+        force_index.as_ref(),
+    );
+
+    let zero = Arc::new(ConstantIndicator::new(force_index.bar_series(), T::zero()));
+    let rule: CrossedUpIndicatorRule<_, _, _, _, _, _, Record<T>> =
+        CrossedUpIndicatorRule::new(force_index, zero);
+    let crossed: Vec<usize> = (0..15).filter(|i| rule.is_satisfied(*i)).collect();
+    assert_eq!(vec![6], crossed);
+
+    let volume_divisor = T::from_usize(1000).unwrap();
+    assert!(
+        EaseOfMovementIndicator::with_volume_divisor(
+            BarSeriesRef::Mut(series.clone()),
+            5,
+            T::zero()
+        )
+        .is_err()
+    );
+    let emv = EaseOfMovementIndicator::with_volume_divisor(
+        BarSeriesRef::Mut(series.clone()),
+        5,
+        volume_divisor,
+    )
+    .unwrap();
+    assert_eq!(5, emv.count_of_unstable_bars());
+    assert_values(
+        &[
+            0.0, 0.28893, -5.35942, -5.68877, -5.19055, -5.13962, -4.27236, -0.10146, 1.70581,
+            3.06937, 3.58775, 2.4631, 1.18943, 0.99333, -0.1715,
+        ],
+        &emv,
+    );
+}
@@ -0,0 +1,296 @@
+/*!
+ * MIT License
+ *
+ * Copyright (c) 2025 Mountainsea
+ * Based on ta4j (c) 2017–2025 Ta4j Organization & respective authors (see AUTHORS)
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+use rstest::rstest;
+use std::cell::RefCell;
+use std::sync::Arc;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::bar::builder::mocks::mock_bar_series_builder::MockBarSeriesBuilder;
+use ta4r::bar::types::{BarBuilder, BarSeries};
+use ta4r::indicators::Indicator;
+use ta4r::indicators::types::IndicatorError;
+use ta4r::indicators::vwap::anchored_vwap_indicator::AnchoredVwapIndicator;
+use ta4r::indicators::vwap::vwap_band_indicator::VwapBandIndicator;
+use ta4r::indicators::vwap::vwap_indicator::VwapIndicator;
+use ta4r::indicators::vwap::{AnchorPeriod, VwapAnchor};
+use ta4r::num::TrNum;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use tests_indicator::types::{
+    GENERAL_OFFSET, Ohlcv, assert_num_eq_with_offset, assert_values, ohlcv_series_mut,
+};
+use time::Duration;
+use time_macros::{datetime, time};
+
+/// (high, low, close, volume, amount)，amount 为 0 时按典型价格 * 成交量计算
+const BARS: [(f64, f64, f64, f64, f64); 8] = [
+    (10.5, 9.8, 10.2, 1000.0, 10150.0),
+    (10.6, 10.0, 10.4, 1500.0, 15450.0),
+    (10.8, 10.3, 10.7, 1200.0, 0.0),
+    (10.9, 10.5, 10.6, 800.0, 8500.0),
+    (10.7, 10.1, 10.3, 2000.0, 20700.0),
+    (10.4, 9.9, 10.0, 1700.0, 0.0),
+    (10.2, 9.7, 9.9, 900.0, 8950.0),
+    (10.3, 9.8, 10.2, 1100.0, 11110.0),
+];
+
+/// 1 小时 bar，从 2025-03-31（周一）20:00 开始，跨越日与月的边界
+fn series<T>(factory: Arc<T::Factory>) -> Arc<RefCell<BaseBarSeries<T>>>
+where
+    T: TrNum + 'static,
+{
+    let mut series = MockBarSeriesBuilder::<T>::default()
+        .with_num_factory(factory)
+        .build();
+    let num = |value: f64| T::from_f64(value).unwrap_or_else(T::zero);
+    let mut end_time = datetime!(2025-03-31 21:00 UTC);
+    for &(high, low, close, volume, amount) in &BARS {
+        series
+            .bar_builder()
+            .time_period(Duration::hours(1))
+            .end_time(end_time)
+            .open_price(num(close))
+            .high_price(num(high))
+            .low_price(num(low))
+            .close_price(num(close))
+            .volume(num(volume))
+            .amount(num(amount))
+            .add()
+            .expect("Failed to add bar");
+        end_time += Duration::hours(1);
+    }
+    Arc::new(RefCell::new(series))
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_rolling_vwap_double(#[case] factory: DoubleNumFactory) {
+    test_rolling_vwap::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_rolling_vwap_decimal(#[case] factory: DecimalNumFactory) {
+    test_rolling_vwap::<DecimalNum>(Arc::new(factory));
+}
+fn test_rolling_vwap<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = series::<T>(factory);
+
+    assert!(VwapIndicator::from_mut(series.clone(), 0).is_err());
+    let vwap = Arc::new(VwapIndicator::from_mut(series, 3).unwrap());
+    assert_eq!(2, vwap.count_of_unstable_bars());
+    assert_values(
+        &[
+            10.15, 10.24, 10.35676, 10.47714, 10.48, 10.30444, 10.17826, 10.06216,
+        ],
+        vwap.as_ref(),
+    );
+
+    let two = T::from_usize(2).unwrap();
+    let upper = VwapBandIndicator::upper(vwap.clone(), two.clone());
+    let lower = VwapBandIndicator::lower(vwap, two);
+    assert_eq!(2, upper.count_of_unstable_bars());
+    assert_values(
+        &[
+            10.15, 10.38697, 10.7148, 10.78452, 10.74058, 10.6785, 10.49941, 10.19564,
+        ],
+        &upper,
+    );
+    assert_values(
+        &[
+            10.15, 10.09303, 9.99871, 10.16976, 10.21942, 9.93038, 9.85712, 9.92868,
+        ],
+        &lower,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_vwap_without_amount_double(#[case] factory: DoubleNumFactory) {
+    test_vwap_without_amount::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_vwap_without_amount_decimal(#[case] factory: DecimalNumFactory) {
+    test_vwap_without_amount::<DecimalNum>(Arc::new(factory));
+}
+fn test_vwap_without_amount<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    // 不指定成交额，bar 构建器填充的 close * volume 不作为成交额
+    let bars: Vec<Ohlcv> = BARS
+        .iter()
+        .map(|&(high, low, close, volume, _)| (close, high, low, close, volume))
+        .collect();
+    let series = ohlcv_series_mut::<T>(factory, &bars);
+
+    let vwap = VwapIndicator::from_mut(series, 3).unwrap();
+    assert_values(
+        &[
+            10.16667, 10.26667, 10.37477, 10.50095, 10.49667, 10.31926, 10.18333, 10.05946,
+        ],
+        &vwap,
+    );
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_vwap_zero_volume_double(#[case] factory: DoubleNumFactory) {
+    test_vwap_zero_volume::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_vwap_zero_volume_decimal(#[case] factory: DecimalNumFactory) {
+    test_vwap_zero_volume::<DecimalNum>(Arc::new(factory));
+}
+fn test_vwap_zero_volume<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = ohlcv_series_mut::<T>(
+        factory,
+        &[
+            (10.2, 10.5, 9.8, 10.2, 0.0),
+            (10.4, 10.6, 10.0, 10.4, 0.0),
+            (10.7, 10.8, 10.3, 10.7, 1200.0),
+            (10.6, 10.9, 10.5, 10.6, 800.0),
+        ],
+    );
+
+    // 成交量为 0 时沿用累计起点 bar 的典型价格
+    let anchored = Arc::new(AnchoredVwapIndicator::from_mut(
+        series.clone(),
+        VwapAnchor::Index(0),
+    ));
+    assert_values(&[10.16667, 10.16667, 10.6, 10.62667], anchored.as_ref());
+    let upper = VwapBandIndicator::upper(anchored, T::from_usize(2).unwrap());
+    assert_num_eq_with_offset(10.16667, upper.get_value(1).unwrap(), GENERAL_OFFSET);
+
+    let vwap = VwapIndicator::from_mut(series, 2).unwrap();
+    assert_values(&[10.16667, 10.16667, 10.6, 10.62667], &vwap);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_anchored_vwap_double(#[case] factory: DoubleNumFactory) {
+    test_anchored_vwap::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_anchored_vwap_decimal(#[case] factory: DecimalNumFactory) {
+    test_anchored_vwap::<DecimalNum>(Arc::new(factory));
+}
+fn test_anchored_vwap<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let series = series::<T>(factory);
+
+    // 04-01 00:00 开始新的一天（同时也是新的一月）
+    let daily =
+        AnchoredVwapIndicator::from_mut(series.clone(), VwapAnchor::Period(AnchorPeriod::Day));
+    assert_eq!(Some(0), daily.anchor_index(3).unwrap());
+    assert_eq!(Some(4), daily.anchor_index(7).unwrap());
+    assert_values(
+        &[
+            10.15, 10.24, 10.35676, 10.40444, 10.35, 10.23514, 10.17826, 10.16316,
+        ],
+        &daily,
+    );
+    let monthly =
+        AnchoredVwapIndicator::from_mut(series.clone(), VwapAnchor::Period(AnchorPeriod::Month));
+    assert_values(
+        &[
+            10.15, 10.24, 10.35676, 10.40444, 10.35, 10.23514, 10.17826, 10.16316,
+        ],
+        &monthly,
+    );
+
+    // 周一到周二属于同一周，不重置
+    let weekly =
+        AnchoredVwapIndicator::from_mut(series.clone(), VwapAnchor::Period(AnchorPeriod::Week));
+    assert_values(
+        &[
+            10.15, 10.24, 10.35676, 10.40444, 10.38769, 10.32805, 10.29011, 10.26961,
+        ],
+        &weekly,
+    );
+
+    // 交易时段从 22:00 开始
+    let session = Arc::new(AnchoredVwapIndicator::from_mut(
+        series.clone(),
+        VwapAnchor::Session(time!(22:00)),
+    ));
+    assert_eq!(Some(2), session.anchor_index(5).unwrap());
+    assert_values(
+        &[
+            10.15, 10.24, 10.6, 10.61, 10.48, 10.36667, 10.30909, 10.27922,
+        ],
+        session.as_ref(),
+    );
+    let one = T::from_usize(1).unwrap();
+    let upper = VwapBandIndicator::upper(session.clone(), one.clone());
+    let lower = VwapBandIndicator::lower(session, one);
+    assert_num_eq_with_offset(10.61 + 0.01225, upper.get_value(3).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(
+        10.36667 - 0.20527,
+        lower.get_value(5).unwrap(),
+        GENERAL_OFFSET,
+    );
+
+    // 锚定在指定的 bar，之前没有值
+    let from_index = AnchoredVwapIndicator::from_mut(series.clone(), VwapAnchor::Index(3));
+    assert_eq!(VwapAnchor::Index(3), from_index.anchor());
+    assert!(matches!(
+        from_index.get_value(1),
+        Err(IndicatorError::NotAvailable {
+            index: 1,
+            available_from: 3
+        })
+    ));
+    for (index, value) in [
+        (3, 10.625),
+        (4, 10.42857),
+        (5, 10.30444),
+        (6, 10.24444),
+        (7, 10.22),
+    ] {
+        assert_num_eq_with_offset(value, from_index.get_value(index).unwrap(), GENERAL_OFFSET);
+    }
+
+    // 锚定在指定时间：第一根开始时间不早于 01:30 的 bar 是 02:00
+    let from_time =
+        AnchoredVwapIndicator::from_mut(series, VwapAnchor::Time(datetime!(2025-04-01 01:30 UTC)));
+    assert_eq!(None, from_time.anchor_index(5).unwrap());
+    assert!(from_time.get_value(5).is_err());
+    assert_eq!(Some(6), from_time.anchor_index(7).unwrap());
+    assert_num_eq_with_offset(9.94444, from_time.get_value(6).unwrap(), GENERAL_OFFSET);
+    assert_num_eq_with_offset(10.03, from_time.get_value(7).unwrap(), GENERAL_OFFSET);
+}
@@ -0,0 +1,141 @@
+use parking_lot::RwLock;
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::TradingRecord;
+use ta4r::analysis::cash_flow::CashFlow;
+use ta4r::analysis::cost::fixed_transaction_cost_model::FixedTransactionCostModel;
+use ta4r::analysis::equity_curve::ReturnType;
+use ta4r::analysis::returns::Returns;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::bar::builder::mocks::mock_bar_series_builder::MockBarSeriesBuilder;
+use ta4r::bar::builder::types::BarSeriesRef;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::num::{NumFactory, TrNum};
+
+type Record<T> = BaseTradingRecord<
+    T,
+    FixedTransactionCostModel<T>,
+    FixedTransactionCostModel<T>,
+    BaseBarSeries<T>,
+>;
+
+fn assert_num_eq<T: TrNum>(expected: f64, actual: T) {
+    let actual = actual.to_f64().unwrap();
+    assert!(
+        (expected - actual).abs() < 1e-6,
+        "expected: {}, actual: {}",
+        expected,
+        actual
+    );
+}
+
+/// 每笔交易费用 0.5，持仓成本 2（平摊到持仓的每根 bar）
+/// 已关闭持仓 [1, 4]，未平仓持仓自 index 6 起
+fn build_record<T>(factory: Arc<T::Factory>) -> (BarSeriesRef<BaseBarSeries<T>>, Record<T>)
+where
+    T: TrNum + 'static,
+{
+    let series = MockBarSeriesBuilder::<T>::default()
+        .with_num_factory(factory.clone())
+        .with_data(vec![10., 10., 12., 9., 11., 11., 10., 8., 12.])
+        .build();
+
+    let mut record: Record<T> = BaseTradingRecord::new_default(
+        FixedTransactionCostModel::new(factory.num_of_f64(0.5)),
+        FixedTransactionCostModel::new(factory.one().as_ref().clone()),
+    );
+    let one = factory.one().as_ref().clone();
+    for (index, price) in [(1, 10), (4, 11), (6, 10)] {
+        record.operate_with_price_amount(index, factory.num_of_i64(price), one.clone());
+    }
+    (BarSeriesRef::Shared(Arc::new(RwLock::new(series))), record)
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_cash_flow_with_costs_double(#[case] factory: DoubleNumFactory) {
+    test_cash_flow_with_costs::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_cash_flow_with_costs_decimal(#[case] factory: DecimalNumFactory) {
+    test_cash_flow_with_costs::<DecimalNum>(Arc::new(factory));
+}
+fn test_cash_flow_with_costs<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let (series, record) = build_record::<T>(factory);
+    let cash_flow = CashFlow::new(series.clone(), &record).unwrap();
+
+    let expected = [
+        1.0,
+        1.0,
+        1.079365079,
+        0.793650794,
+        0.936507937,
+        0.936507937,
+        0.936507937,
+        0.624338624,
+        0.981103553,
+    ];
+    assert_eq!(cash_flow.size(), expected.len());
+    for (index, value) in expected.iter().enumerate() {
+        assert_num_eq(*value, cash_flow.get_value(index).unwrap());
+    }
+    assert!(cash_flow.get_value(expected.len()).is_err());
+
+    // 只计算到 index 5：未平仓持仓不计入
+    let cash_flow = CashFlow::with_final_index(series, &record, 5).unwrap();
+    assert_eq!(cash_flow.size(), 6);
+    assert_num_eq(0.936507937, cash_flow.get_value(5).unwrap());
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_returns_double(#[case] factory: DoubleNumFactory) {
+    test_returns::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_returns_decimal(#[case] factory: DecimalNumFactory) {
+    test_returns::<DecimalNum>(Arc::new(factory));
+}
+fn test_returns<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let (series, record) = build_record::<T>(factory);
+
+    let arithmetic = Returns::new(series.clone(), &record, ReturnType::Arithmetic).unwrap();
+    let expected = [
+        0.0,
+        0.0,
+        0.079365079,
+        -0.264705882,
+        0.18,
+        0.0,
+        0.0,
+        -0.333333333,
+        0.571428571,
+    ];
+    assert_eq!(arithmetic.size(), expected.len());
+    for (index, value) in expected.iter().enumerate() {
+        assert_num_eq(*value, arithmetic.get_value(index).unwrap());
+    }
+
+    let log = Returns::new(series.clone(), &record, ReturnType::Log).unwrap();
+    assert_num_eq(0.0, log.get_value(0).unwrap());
+    assert_num_eq((11.0f64 / 7.0).ln(), log.get_value(8).unwrap());
+
+    // 单个持仓：持仓结束后收益率为 0
+    let position = record.positions()[0].as_ref();
+    let returns = Returns::from_position(series, position, ReturnType::Arithmetic).unwrap();
+    assert_num_eq(0.18, returns.get_value(4).unwrap());
+    assert_num_eq(0.0, returns.get_value(8).unwrap());
+}
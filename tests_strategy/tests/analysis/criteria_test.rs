@@ -0,0 +1,164 @@
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::TradingRecord;
+use ta4r::analysis::AnalysisCriterion;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::analysis::criteria::average_profit_criterion::AverageProfitCriterion;
+use ta4r::analysis::criteria::expectancy_criterion::ExpectancyCriterion;
+use ta4r::analysis::criteria::gross_return_criterion::GrossReturnCriterion;
+use ta4r::analysis::criteria::number_of_positions_criterion::NumberOfPositionsCriterion;
+use ta4r::analysis::criteria::positions_ratio_criterion::PositionsRatioCriterion;
+use ta4r::analysis::criteria::profit_factor_criterion::ProfitFactorCriterion;
+use ta4r::analysis::criteria::profit_loss_criterion::ProfitLossCriterion;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::bar::builder::mocks::mock_bar_series_builder::MockBarSeriesBuilder;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::num::{NumFactory, TrNum};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+fn assert_num_eq<T: TrNum>(expected: f64, actual: T) {
+    let actual = actual.to_f64().unwrap();
+    assert!(
+        (expected - actual).abs() < 1e-6,
+        "expected: {}, actual: {}",
+        expected,
+        actual
+    );
+}
+
+/// 三个已关闭持仓 (+2, -1, +4) 和一个未平仓持仓
+fn build_record<T>(factory: Arc<T::Factory>) -> (BaseBarSeries<T>, Record<T>)
+where
+    T: TrNum + 'static,
+{
+    let series = MockBarSeriesBuilder::<T>::default()
+        .with_num_factory(factory.clone())
+        .with_data(vec![10., 12., 10., 9., 8., 12., 11., 13.])
+        .build();
+
+    let mut record: Record<T> =
+        BaseTradingRecord::new_default(ZeroCostModel::new(), ZeroCostModel::new());
+    let one = factory.one().as_ref().clone();
+    for (index, price) in [(0, 10), (1, 12), (2, 10), (3, 9), (4, 8), (5, 12), (6, 11)] {
+        record.operate_with_price_amount(index, factory.num_of_i64(price), one.clone());
+    }
+    (series, record)
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_profitability_criteria_double(#[case] factory: DoubleNumFactory) {
+    test_profitability_criteria::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_profitability_criteria_decimal(#[case] factory: DecimalNumFactory) {
+    test_profitability_criteria::<DecimalNum>(Arc::new(factory));
+}
+fn test_profitability_criteria<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let (series, record) = build_record::<T>(factory);
+    assert_eq!(record.position_count(), 3);
+    assert!(record.current_position().is_opened());
+
+    assert_num_eq(5.0, ProfitLossCriterion.calculate_record(&series, &record));
+    assert_num_eq(
+        1.62,
+        GrossReturnCriterion.calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        3.0,
+        NumberOfPositionsCriterion.calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        2.0 / 3.0,
+        PositionsRatioCriterion::winning().calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        1.0 / 3.0,
+        PositionsRatioCriterion::losing().calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        3.0,
+        AverageProfitCriterion.calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        6.0,
+        ProfitFactorCriterion.calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        5.0 / 3.0,
+        ExpectancyCriterion.calculate_record(&series, &record),
+    );
+
+    // 单个持仓
+    let losing = record.positions()[1].as_ref();
+    assert_num_eq(
+        -1.0,
+        ProfitLossCriterion.calculate_position(&series, losing),
+    );
+    assert_num_eq(
+        0.9,
+        GrossReturnCriterion.calculate_position(&series, losing),
+    );
+    assert_num_eq(
+        0.0,
+        AverageProfitCriterion.calculate_position(&series, losing),
+    );
+    assert_num_eq(
+        -1.0,
+        ExpectancyCriterion.calculate_position(&series, losing),
+    );
+
+    // 未平仓持仓不计入
+    let open = record.current_position().as_ref();
+    assert_num_eq(0.0, ProfitLossCriterion.calculate_position(&series, open));
+    assert_num_eq(1.0, GrossReturnCriterion.calculate_position(&series, open));
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_better_than_double(#[case] factory: DoubleNumFactory) {
+    test_better_than::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_better_than_decimal(#[case] factory: DecimalNumFactory) {
+    test_better_than::<DecimalNum>(Arc::new(factory));
+}
+fn test_better_than<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    type Criterion<'a, T> =
+        &'a dyn AnalysisCriterion<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+    let low = factory.num_of_i64(1);
+    let high = factory.num_of_i64(2);
+
+    let higher_is_better: [Criterion<T>; 5] = [
+        &ProfitLossCriterion,
+        &GrossReturnCriterion,
+        &AverageProfitCriterion,
+        &ProfitFactorCriterion,
+        &ExpectancyCriterion,
+    ];
+    for criterion in higher_is_better {
+        assert!(criterion.better_than(&high, &low));
+        assert!(!criterion.better_than(&low, &high));
+    }
+
+    let winning: Criterion<T> = &PositionsRatioCriterion::winning();
+    assert!(winning.better_than(&high, &low));
+    let losing: Criterion<T> = &PositionsRatioCriterion::losing();
+    assert!(losing.better_than(&low, &high));
+    let positions: Criterion<T> = &NumberOfPositionsCriterion;
+    assert!(positions.better_than(&low, &high));
+}
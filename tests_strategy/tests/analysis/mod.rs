@@ -0,0 +1,3 @@
+mod cash_flow_test;
+mod criteria_test;
+mod risk_criteria_test;
@@ -0,0 +1,200 @@
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::TradingRecord;
+use ta4r::analysis::AnalysisCriterion;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::analysis::criteria::calmar_ratio_criterion::CalmarRatioCriterion;
+use ta4r::analysis::criteria::expected_shortfall_criterion::ExpectedShortfallCriterion;
+use ta4r::analysis::criteria::maximum_drawdown_criterion::MaximumDrawdownCriterion;
+use ta4r::analysis::criteria::maximum_drawdown_duration_criterion::MaximumDrawdownDurationCriterion;
+use ta4r::analysis::criteria::return_over_max_drawdown_criterion::ReturnOverMaxDrawdownCriterion;
+use ta4r::analysis::criteria::sharpe_ratio_criterion::SharpeRatioCriterion;
+use ta4r::analysis::criteria::sortino_ratio_criterion::SortinoRatioCriterion;
+use ta4r::analysis::criteria::ulcer_index_criterion::UlcerIndexCriterion;
+use ta4r::analysis::criteria::value_at_risk_criterion::ValueAtRiskCriterion;
+use ta4r::analysis::equity_curve::EquityCurve;
+use ta4r::bar::base_bar::BaseBar;
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::bar::base_bar_series_builder::BaseBarSeriesBuilder;
+use ta4r::bar::types::{BarSeries, BarSeriesBuilder};
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::num::{NumFactory, TrNum};
+use time::{Duration, OffsetDateTime};
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+
+fn assert_num_eq<T: TrNum>(expected: f64, actual: T) {
+    let actual = actual.to_f64().unwrap();
+    assert!(
+        (expected - actual).abs() < 1e-6,
+        "expected: {}, actual: {}",
+        expected,
+        actual
+    );
+}
+
+/// 30 天周期的 bar，收盘价为 closes
+fn build_series<T>(factory: Arc<T::Factory>, closes: &[f64]) -> BaseBarSeries<T>
+where
+    T: TrNum + 'static,
+{
+    let mut series = BaseBarSeriesBuilder::<T>::default()
+        .with_num_factory(factory.clone())
+        .build()
+        .expect("Failed to build BaseBarSeries");
+    let period = Duration::days(30);
+    let start = OffsetDateTime::UNIX_EPOCH;
+    for (i, close) in closes.iter().enumerate() {
+        let close = factory.num_of_f64(*close);
+        series.add_bar(BaseBar {
+            time_period: period,
+            begin_time: start + period * i as i32,
+            end_time: start + period * (i as i32 + 1),
+            open_price: Some(close.clone()),
+            high_price: Some(close.clone()),
+            low_price: Some(close.clone()),
+            close_price: Some(close),
+            volume: factory.num_of_i64(1),
+            amount: None,
+            trades: 0,
+        });
+    }
+    series
+}
+
+/// 已关闭持仓 [1, 4]，未平仓持仓自 index 6 起
+/// 资金曲线：1, 1, 1.2, 0.9, 1.1, 1.1, 1.1, 0.88, 1.32
+fn build_record<T>(factory: Arc<T::Factory>) -> (BaseBarSeries<T>, Record<T>)
+where
+    T: TrNum + 'static,
+{
+    let series = build_series::<T>(
+        factory.clone(),
+        &[10., 10., 12., 9., 11., 11., 10., 8., 12.],
+    );
+    let mut record: Record<T> =
+        BaseTradingRecord::new_default(ZeroCostModel::new(), ZeroCostModel::new());
+    let one = factory.one().as_ref().clone();
+    for (index, price) in [(1, 10), (4, 11), (6, 10)] {
+        record.operate_with_price_amount(index, factory.num_of_i64(price), one.clone());
+    }
+    (series, record)
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_equity_curve_double(#[case] factory: DoubleNumFactory) {
+    test_equity_curve::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_equity_curve_decimal(#[case] factory: DecimalNumFactory) {
+    test_equity_curve::<DecimalNum>(Arc::new(factory));
+}
+fn test_equity_curve<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let (series, record) = build_record::<T>(factory);
+    let curve = EquityCurve::new(&series, &record);
+
+    let expected = [1.0, 1.0, 1.2, 0.9, 1.1, 1.1, 1.1, 0.88, 1.32];
+    assert_eq!(curve.values().len(), expected.len());
+    for (index, value) in expected.iter().enumerate() {
+        assert_num_eq(*value, curve.value(index).unwrap().clone());
+    }
+    assert_num_eq(1.32, curve.total_return());
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_risk_criteria_double(#[case] factory: DoubleNumFactory) {
+    test_risk_criteria::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_risk_criteria_decimal(#[case] factory: DecimalNumFactory) {
+    test_risk_criteria::<DecimalNum>(Arc::new(factory));
+}
+fn test_risk_criteria<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let (series, record) = build_record::<T>(factory);
+
+    assert_num_eq(
+        0.32 / 1.2,
+        MaximumDrawdownCriterion.calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        5.0,
+        MaximumDrawdownDurationCriterion.calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        0.375,
+        ReturnOverMaxDrawdownCriterion.calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        0.242717355,
+        SharpeRatioCriterion::default().calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        3.853018565,
+        SharpeRatioCriterion::default()
+            .with_bars_per_year(252.0)
+            .calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        0.521482206,
+        SortinoRatioCriterion::default().calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        1.709350811,
+        CalmarRatioCriterion.calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        13.099806802,
+        UlcerIndexCriterion.calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        (0.75f64).ln(),
+        ValueAtRiskCriterion::new(0.95)
+            .unwrap()
+            .calculate_record(&series, &record),
+    );
+    assert_num_eq(
+        (0.75f64).ln(),
+        ExpectedShortfallCriterion::new(0.95)
+            .unwrap()
+            .calculate_record(&series, &record),
+    );
+    // 8 个收益率，0.5 分位取最小的 4 个：ln(0.75), ln(0.8), 0, 0
+    assert_num_eq(
+        ((0.75f64).ln() + (0.8f64).ln()) / 4.0,
+        ExpectedShortfallCriterion::new(0.5)
+            .unwrap()
+            .calculate_record(&series, &record),
+    );
+
+    // 单个持仓：资金曲线 1, 1, 1.2, 0.9, 1.1，之后保持不变
+    let position = record.positions()[0].as_ref();
+    assert_num_eq(
+        0.25,
+        MaximumDrawdownCriterion.calculate_position(&series, position),
+    );
+    assert_num_eq(
+        0.1 / 0.25,
+        ReturnOverMaxDrawdownCriterion.calculate_position(&series, position),
+    );
+
+    // 置信度超出 (0, 1) 时构造失败而不是 panic
+    for confidence in [0.0, 1.0, -0.5, f64::NAN] {
+        assert!(ValueAtRiskCriterion::new(confidence).is_err());
+        assert!(ExpectedShortfallCriterion::new(confidence).is_err());
+    }
+    assert_eq!(0.95, ValueAtRiskCriterion::default().confidence());
+}
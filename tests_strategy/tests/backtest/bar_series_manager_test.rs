@@ -0,0 +1,216 @@
+use parking_lot::RwLock;
+use rstest::rstest;
+use std::sync::Arc;
+use ta4r::TradingRecord;
+use ta4r::analysis::cost::zero_cost_model::ZeroCostModel;
+use ta4r::backtest::bar_series_manager::{BarSeriesManager, OpenPositionHandling};
+use ta4r::bar::base_bar_series::BaseBarSeries;
+use ta4r::bar::builder::mocks::mock_bar_series_builder::MockBarSeriesBuilder;
+use ta4r::base_trading_record::BaseTradingRecord;
+use ta4r::indicators::Indicator;
+use ta4r::indicators::helpers::close_price_indicator::ClosePriceIndicator;
+use ta4r::indicators::helpers::constant_indicator::ConstantIndicator;
+use ta4r::num::decimal_num::DecimalNum;
+use ta4r::num::decimal_num_factory::DecimalNumFactory;
+use ta4r::num::double_num::DoubleNum;
+use ta4r::num::double_num_factory::DoubleNumFactory;
+use ta4r::num::{NumFactory, TrNum};
+use ta4r::rule::over_indicator_rule::OverIndicatorRule;
+use ta4r::rule::under_indicator_rule::UnderIndicatorRule;
+use ta4r::strategy::base_strategy::BaseStrategy;
+use ta4r::trade::TradeType;
+
+type Record<T> = BaseTradingRecord<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+type Manager<T> = BarSeriesManager<T, ZeroCostModel<T>, ZeroCostModel<T>, BaseBarSeries<T>>;
+type Close<T> = ClosePriceIndicator<T, BaseBarSeries<T>>;
+type Constant<T> = ConstantIndicator<T, BaseBarSeries<T>>;
+type TestStrategy<T> = BaseStrategy<
+    T,
+    ZeroCostModel<T>,
+    ZeroCostModel<T>,
+    BaseBarSeries<T>,
+    Record<T>,
+    OverIndicatorRule<
+        T,
+        ZeroCostModel<T>,
+        ZeroCostModel<T>,
+        BaseBarSeries<T>,
+        Close<T>,
+        Constant<T>,
+        Record<T>,
+    >,
+    UnderIndicatorRule<
+        T,
+        ZeroCostModel<T>,
+        ZeroCostModel<T>,
+        BaseBarSeries<T>,
+        Close<T>,
+        Constant<T>,
+        Record<T>,
+    >,
+>;
+
+/// 收盘价高于 2.5 入场，低于 2.5 出场
+fn build_strategy<T>(
+    series: Arc<RwLock<BaseBarSeries<T>>>,
+    factory: Arc<T::Factory>,
+    unstable_bars: usize,
+) -> TestStrategy<T>
+where
+    T: TrNum + 'static,
+{
+    let close = Arc::new(ClosePriceIndicator::from_shared(series.clone()));
+    let threshold = Arc::new(ConstantIndicator::new(
+        close.bar_series(),
+        factory.num_of_f64(2.5),
+    ));
+    let entry_rule = Arc::new(OverIndicatorRule::new(close.clone(), threshold.clone()));
+    let exit_rule = Arc::new(UnderIndicatorRule::new(close, threshold));
+    BaseStrategy::new("threshold", entry_rule, exit_rule, unstable_bars)
+}
+
+fn build_manager<T>(factory: Arc<T::Factory>) -> (Arc<RwLock<BaseBarSeries<T>>>, Manager<T>)
+where
+    T: TrNum + 'static,
+{
+    let data = vec![1., 2., 3., 4., 3., 2., 1., 2., 3., 4., 5.];
+    let series = Arc::new(RwLock::new(
+        MockBarSeriesBuilder::<T>::default()
+            .with_num_factory(factory)
+            .with_data(data)
+            .build(),
+    ));
+    let manager =
+        BarSeriesManager::from_shared(series.clone(), ZeroCostModel::new(), ZeroCostModel::new());
+    (series, manager)
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_run_keeps_open_position_double(#[case] factory: DoubleNumFactory) {
+    test_run_keeps_open_position::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_run_keeps_open_position_decimal(#[case] factory: DecimalNumFactory) {
+    test_run_keeps_open_position::<DecimalNum>(Arc::new(factory));
+}
+fn test_run_keeps_open_position<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let (series, manager) = build_manager::<T>(factory.clone());
+    let strategy = build_strategy(series, factory, 0);
+
+    let record = manager.run(&strategy).unwrap();
+
+    assert_eq!(record.start_index(), Some(0));
+    assert_eq!(record.end_index(), Some(10));
+    assert_eq!(record.position_count(), 1);
+    let position = &record.positions()[0];
+    assert_eq!(position.entry().unwrap().index, 2);
+    assert_eq!(position.exit().unwrap().index, 5);
+    assert_eq!(position.entry().unwrap().net_price.to_f64(), Some(3.0));
+    assert_eq!(position.exit().unwrap().net_price.to_f64(), Some(2.0));
+
+    // 最后一个持仓保持未平仓
+    assert!(record.current_position().is_opened());
+    assert_eq!(record.current_position().entry().unwrap().index, 8);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_run_closes_open_position_double(#[case] factory: DoubleNumFactory) {
+    test_run_closes_open_position::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_run_closes_open_position_decimal(#[case] factory: DecimalNumFactory) {
+    test_run_closes_open_position::<DecimalNum>(Arc::new(factory));
+}
+fn test_run_closes_open_position<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let (series, manager) = build_manager::<T>(factory.clone());
+    let manager = manager.with_open_position_handling(OpenPositionHandling::CloseAtEnd);
+    let strategy = build_strategy(series, factory, 0);
+
+    let record = manager.run(&strategy).unwrap();
+
+    assert_eq!(record.position_count(), 2);
+    assert!(record.current_position().is_new());
+    let last = &record.positions()[1];
+    assert_eq!(last.entry().unwrap().index, 8);
+    assert_eq!(last.exit().unwrap().index, 10);
+    assert_eq!(last.exit().unwrap().net_price.to_f64(), Some(5.0));
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_run_close_at_end_skips_entry_on_last_bar_double(#[case] factory: DoubleNumFactory) {
+    test_run_close_at_end_skips_entry_on_last_bar::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_run_close_at_end_skips_entry_on_last_bar_decimal(#[case] factory: DecimalNumFactory) {
+    test_run_close_at_end_skips_entry_on_last_bar::<DecimalNum>(Arc::new(factory));
+}
+fn test_run_close_at_end_skips_entry_on_last_bar<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let (series, manager) = build_manager::<T>(factory.clone());
+    let manager = manager.with_open_position_handling(OpenPositionHandling::CloseAtEnd);
+    let strategy = build_strategy(series, factory, 0);
+
+    // 区间 [0, 2]：入场信号出现在最后一根 bar，不应产生零长度持仓
+    let record = manager
+        .run_with_range(&strategy, TradeType::Buy, None, Some(2))
+        .unwrap();
+    assert_eq!(record.position_count(), 0);
+    assert!(record.current_position().is_new());
+
+    // 区间 [0, 3]：index 2 入场，最后一根 bar 平仓
+    let record = manager
+        .run_with_range(&strategy, TradeType::Buy, None, Some(3))
+        .unwrap();
+    assert_eq!(record.position_count(), 1);
+    let position = &record.positions()[0];
+    assert_eq!(position.entry().unwrap().index, 2);
+    assert_eq!(position.exit().unwrap().index, 3);
+}
+
+#[rstest]
+#[case(DoubleNumFactory)]
+fn test_run_respects_unstable_bars_and_range_double(#[case] factory: DoubleNumFactory) {
+    test_run_respects_unstable_bars_and_range::<DoubleNum>(Arc::new(factory));
+}
+#[rstest]
+#[case(DecimalNumFactory::default())]
+fn test_run_respects_unstable_bars_and_range_decimal(#[case] factory: DecimalNumFactory) {
+    test_run_respects_unstable_bars_and_range::<DecimalNum>(Arc::new(factory));
+}
+fn test_run_respects_unstable_bars_and_range<T>(factory: Arc<T::Factory>)
+where
+    T: TrNum + 'static,
+{
+    let (series, manager) = build_manager::<T>(factory.clone());
+
+    // 前 3 根 bar 不稳定：第一次入场推迟到 index 3
+    let strategy = build_strategy(series.clone(), factory.clone(), 3);
+    let record = manager.run(&strategy).unwrap();
+    assert_eq!(record.positions()[0].entry().unwrap().index, 3);
+
+    // 指定区间 [6, 9]，以卖出起始
+    let strategy = build_strategy(series, factory, 0);
+    let record = manager
+        .run_with_range(&strategy, TradeType::Sell, Some(6), Some(9))
+        .unwrap();
+    assert_eq!(record.start_index(), Some(6));
+    assert_eq!(record.end_index(), Some(9));
+    assert_eq!(record.position_count(), 0);
+    let entry = record.current_position().entry().unwrap();
+    assert_eq!(entry.index, 8);
+    assert!(entry.is_sell());
+}
@@ -0,0 +1 @@
+mod bar_series_manager_test;
@@ -0,0 +1,2 @@
+mod analysis;
+mod backtest;